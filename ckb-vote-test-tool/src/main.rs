@@ -1,19 +1,19 @@
 use std::{
     collections::{HashMap, HashSet},
-    io::Write,
     str::FromStr,
     sync::atomic::AtomicUsize,
     time::Duration,
 };
 
 use anyhow::{anyhow, bail, Context};
+use ckb_hash::new_blake2b;
 use ckb_jsonrpc_types::Status;
 use ckb_sdk::{
-    constants::{ONE_CKB, SIGHASH_TYPE_HASH},
+    constants::{ONE_CKB, SIGHASH_TYPE_HASH, TYPE_ID_CODE_HASH},
     core::TransactionBuilder,
     traits::{
-        CellCollector, DefaultCellCollector, DefaultCellDepResolver, DefaultHeaderDepResolver,
-        DefaultTransactionDependencyProvider, SecpCkbRawKeySigner,
+        CellCollector, CellQueryOptions, DefaultCellCollector, DefaultCellDepResolver,
+        DefaultHeaderDepResolver, DefaultTransactionDependencyProvider, SecpCkbRawKeySigner,
     },
     tx_builder::{CapacityBalancer, TxBuilder},
     unlock::{ScriptUnlocker, SecpSighashUnlocker},
@@ -22,8 +22,10 @@ use ckb_sdk::{
 use ckb_types::{
     bytes::Bytes,
     core::{BlockView, DepType, ScriptHashType},
-    packed::{Byte32, CellDep, CellOutput, OutPoint, Script, WitnessArgs, WitnessArgsBuilder},
-    prelude::{Entity, Pack},
+    packed::{
+        Byte32, CellDep, CellInput, CellOutput, OutPoint, Script, WitnessArgs, WitnessArgsBuilder,
+    },
+    prelude::{hex_string, Entity, Pack, Unpack},
     H256,
 };
 use ckb_types::{core::TransactionView, prelude::Builder};
@@ -33,17 +35,19 @@ use rayon::iter::{
     IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator, ParallelBridge,
     ParallelIterator,
 };
-use rsa::RsaPrivateKey;
 use secp256k1::Secp256k1;
 use signature_tools::{
-    candidate::{encode_candidate_cell, Candidate},
-    check_size_and_write,
-    rsa_tools::{
-        create_signature,
-        merkle_tree::{
-            create_merkle_tree_with_proof_rsa, create_merkle_tree_with_root_hash_rsa,
-            MerkleProofResult,
-        },
+    candidate::Candidate,
+    ec_tools::LsagScheme,
+    key_image_registry::KeyImageRegistry,
+    merkle_tree::create_merkle_tree_with_root_hash,
+    public_key_registry::{public_key_registry_cells, rebuild_rings},
+    ring_assignment::{key_set_root, ring_order, ring_seed_block},
+    ring_signature::RingSignatureScheme,
+    vote::{ballot_message, RingSignature, VoteCell, VoteWitness},
+    vote_config::{
+        key_image_registry_script_args, public_key_registry_script_args, vote_script_args,
+        VoteConfig,
     },
 };
 
@@ -67,9 +71,31 @@ struct Args {
     #[arg(long,default_value_t=String::from("0x2f2e4802e64c29593da5d073a77424bc5ecdcad17f3b27fc17e05c0a82c89e06"))]
     /// Outpoint of the typescript, index defaults to 0
     typescript_out_point_tx: String,
+    #[arg(long,default_value_t=String::from("0xbb4469004225b39e983929db71fe2253cba1d49a76223e9e1d212cdca1f79f28"))]
+    /// Code hash (type) of the always success script, which locks the key image registry
+    always_success_code_hash: String,
+    #[arg(long,default_value_t=String::from("0x1dbed8dcfe0f18359c65c5e9546fd15cd69de73ea0a502345be30180649c9467"))]
+    /// Outpoint of the always success script
+    always_success_out_point_tx: String,
+    #[arg(long, default_value_t = 9)]
+    /// Outpoint index of the always success script
+    always_success_out_point_index: u32,
+    #[arg(long, default_value_t = 100000)]
+    /// How many blocks the voting period lasts, starting from current tip
+    voting_period_blocks: u64,
+    #[arg(long, default_value_t = 1)]
+    /// How many votes are sent in one transaction
+    batch_size: usize,
+    #[arg(long, default_value_t = 10)]
+    /// How many rings are published in one public key registry cell
+    rings_per_registry_cell: usize,
+    #[arg(long)]
+    /// Put the keys of rings in vote witnesses, instead of referencing their registry cells
+    inline_ring_keys: bool,
 }
 
 struct SimpleTransferBuilderWithWitness {
+    pub inputs: Vec<CellInput>,
     pub outputs: Vec<(CellOutput, Bytes, Bytes)>,
     pub extra_cell_dep: Vec<CellDep>,
 }
@@ -83,7 +109,7 @@ impl TxBuilder for SimpleTransferBuilderWithWitness {
         _tx_dep_provider: &dyn ckb_sdk::traits::TransactionDependencyProvider,
     ) -> Result<TransactionView, ckb_sdk::tx_builder::TxBuilderError> {
         let mut cell_deps = Vec::new();
-        cell_deps.extend(self.extra_cell_dep.iter().map(|x| x.clone()));
+        cell_deps.extend(self.extra_cell_dep.iter().cloned());
         let mut outputs = Vec::new();
         let mut outputs_data = Vec::new();
         let mut witnesses = Vec::new();
@@ -94,6 +120,7 @@ impl TxBuilder for SimpleTransferBuilderWithWitness {
         }
         Ok(TransactionBuilder::default()
             .set_cell_deps(cell_deps.into_iter().collect())
+            .set_inputs(self.inputs.clone())
             .set_outputs(outputs)
             .set_outputs_data(outputs_data)
             .set_witnesses(witnesses)
@@ -135,15 +162,63 @@ impl CellPublisher {
         extra_cell_dep: Vec<CellDep>,
         custom_capacity: Option<u64>,
     ) -> anyhow::Result<(H256, u32)> {
-        let tx = self
-            .build_transaction(
-                receiver.clone(),
-                data,
-                output_type_witness,
-                output_type_script,
-                extra_cell_dep,
-                custom_capacity,
+        let capacity = custom_capacity.unwrap_or((61 + 100 + data.len()) as u64 * ONE_CKB);
+        log::debug!("capacity={}", capacity);
+        let output = CellOutput::new_builder()
+            .lock(Script::from(receiver))
+            .capacity(capacity.pack())
+            .type_(
+                output_type_script
+                    .map(|(hash, hash_type)| {
+                        Script::new_builder()
+                            .code_hash(Byte32::from_slice(hash.as_bytes()).unwrap())
+                            .hash_type(hash_type.into())
+                            .build()
+                    })
+                    .pack(),
             )
+            .build();
+        let builder = SimpleTransferBuilderWithWitness {
+            inputs: vec![],
+            outputs: vec![(
+                output,
+                Bytes::copy_from_slice(data),
+                output_type_witness
+                    .map(|x| {
+                        WitnessArgsBuilder::default()
+                            .output_type(Some(Bytes::copy_from_slice(x)).pack())
+                            .build()
+                            .as_bytes()
+                    })
+                    .unwrap_or_default(),
+            )],
+            extra_cell_dep,
+        };
+        Ok((self.publish_transaction(&builder)?, 0))
+    }
+
+    /// Collect a live cell of the sender, which could be used as the first input of a transaction
+    fn collect_first_input(&mut self) -> anyhow::Result<CellInput> {
+        let query = CellQueryOptions::new_lock(Script::from(&self.sender_address));
+        let (cells, _) = self
+            .cell_collector
+            .collect_live_cells(&query, true)
+            .with_context(|| anyhow!("Failed to collect live cells"))?;
+        let cell = cells
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow!("No live cell found for {}", self.sender_address))?;
+        Ok(CellInput::new_builder()
+            .previous_output(cell.out_point)
+            .build())
+    }
+
+    fn publish_transaction(
+        &mut self,
+        builder: &SimpleTransferBuilderWithWitness,
+    ) -> anyhow::Result<H256> {
+        let tx = self
+            .build_transaction(builder)
             .with_context(|| anyhow!("Failed to call build_transaction"))?;
         let tip_num = self
             .client
@@ -191,17 +266,41 @@ impl CellPublisher {
             }
             std::thread::sleep(Duration::from_millis(100));
         }
-        Ok((tx_hash, 0))
+        Ok(tx_hash)
+    }
+
+    /// Block number of a transaction, once it's committed
+    fn wait_committed(&self, tx_hash: &H256) -> anyhow::Result<u64> {
+        loop {
+            let status = self
+                .client
+                .get_transaction_status(tx_hash.clone())
+                .with_context(|| anyhow!("Failed to query transaction status"))?;
+            match (&status.tx_status.status, status.tx_status.block_number) {
+                (Status::Committed, Some(block_number)) => return Ok(block_number.value()),
+                (Status::Rejected, _) => bail!("Transacton rejected: {:?}", status.tx_status),
+                _ => std::thread::sleep(Duration::from_millis(500)),
+            }
+        }
+    }
+
+    /// Hash of the block `number`, once it's mined
+    fn wait_block_hash(&self, number: u64) -> anyhow::Result<[u8; 32]> {
+        loop {
+            if let Some(hash) = self
+                .client
+                .get_block_hash(number.into())
+                .with_context(|| anyhow!("Failed to get hash of block {}", number))?
+            {
+                return Ok(hash.into());
+            }
+            std::thread::sleep(Duration::from_millis(500));
+        }
     }
 
     fn build_transaction(
         &mut self,
-        receiver: Address,
-        data: &[u8],
-        output_type_witness: Option<&[u8]>,
-        output_type_script: Option<(H256, ScriptHashType)>,
-        extra_cell_dep: Vec<CellDep>,
-        custom_capacity: Option<u64>,
+        builder: &SimpleTransferBuilderWithWitness,
     ) -> anyhow::Result<TransactionView> {
         let sighash_unlocker = SecpSighashUnlocker::from(Box::new(self.signer.clone()) as Box<_>);
         let sighash_script_id = ScriptId::new_type(SIGHASH_TYPE_HASH.clone());
@@ -222,45 +321,12 @@ impl CellPublisher {
             DefaultCellDepResolver::from_genesis(&BlockView::from(genesis_block))?
         };
         let header_dep_resolver = DefaultHeaderDepResolver::new(&self.rpc_url);
-        let capacity = custom_capacity.unwrap_or((61 + 100 + data.len()) as u64 * ONE_CKB);
-        log::debug!("capacity={}", capacity);
-        let output = CellOutput::new_builder()
-            .lock(Script::from(&receiver))
-            .capacity(capacity.pack())
-            .type_(
-                output_type_script
-                    .map(|(hash, hash_type)| {
-                        Script::new_builder()
-                            .code_hash(Byte32::from_slice(hash.as_bytes()).unwrap())
-                            .hash_type(hash_type.into())
-                            .build()
-                            .into()
-                    })
-                    .pack(),
-            )
-            .build();
-
-        let builder = SimpleTransferBuilderWithWitness {
-            outputs: vec![(
-                output,
-                Bytes::copy_from_slice(data),
-                output_type_witness
-                    .map(|x| {
-                        WitnessArgsBuilder::default()
-                            .output_type(Some(Bytes::copy_from_slice(x)).pack())
-                            .build()
-                            .as_bytes()
-                    })
-                    .unwrap_or_else(|| Default::default()),
-            )],
-            extra_cell_dep,
-        };
 
         let (tx, _) = builder.build_unlocked(
             &mut self.cell_collector,
             &cell_dep_resolver,
             &header_dep_resolver,
-            &mut self.tx_dep_provider,
+            &self.tx_dep_provider,
             &balancer,
             &unlockers,
         )?;
@@ -268,11 +334,32 @@ impl CellPublisher {
         Ok(tx)
     }
 }
+
+/// The key image registry of the election, tracked locally since every vote updates it
+struct KeyImageRegistryCell {
+    out_point: OutPoint,
+    output: CellOutput,
+    registry: KeyImageRegistry,
+}
+
+fn calculate_type_id(first_cell_input: &CellInput, output_index: u64) -> [u8; 32] {
+    let mut blake2b = new_blake2b();
+    blake2b.update(first_cell_input.as_slice());
+    blake2b.update(&output_index.to_le_bytes());
+    let mut ret = [0; 32];
+    blake2b.finalize(&mut ret);
+    ret
+}
+
 #[derive(Clone, Debug)]
 struct VoteData {
     candidate_id: [u8; 4],
+    /// Key image, as inserted into the key image registry
+    image: Vec<u8>,
     vote_cell_data: Vec<u8>,
-    witness_data: Vec<u8>,
+    witness: VoteWitness,
+    /// Index of the registry cell of the ring, see `Ring::registry_cell`
+    registry_cell: Option<usize>,
 }
 fn main() -> anyhow::Result<()> {
     flexi_logger::Logger::try_with_env_or_str("info")
@@ -300,13 +387,15 @@ fn main() -> anyhow::Result<()> {
         .with_context(|| anyhow!("Failed to parse typescript code hash"))?;
     let ts_outpoint = H256::from_str(&args.typescript_out_point_tx[2..])
         .with_context(|| anyhow!("Failed to parse typescript outpoint"))?;
+    // Users have LSAG keys, since the contract refuses RSA elections
+    let scheme = LsagScheme;
     let keys = {
         let done_count = AtomicUsize::new(0);
         (0..args.test_user_count)
             .into_par_iter()
             .map(|_| {
                 let mut rng = rand::thread_rng();
-                let priv_key = RsaPrivateKey::new(&mut rng, 2048).unwrap();
+                let priv_key = scheme.generate_key(&mut rng).unwrap();
 
                 log::info!(
                     "private key {} generation done",
@@ -316,39 +405,225 @@ fn main() -> anyhow::Result<()> {
             })
             .collect::<Vec<_>>()
     };
+    let public_keys = keys
+        .iter()
+        .map(|key| scheme.public_key(key))
+        .collect::<Vec<_>>();
+    let always_success_code_hash = H256::from_str(&args.always_success_code_hash[2..])
+        .with_context(|| anyhow!("Failed to parse always success code hash"))?;
+    let always_success_outpoint = H256::from_str(&args.always_success_out_point_tx[2..])
+        .with_context(|| anyhow!("Failed to parse always success outpoint"))?;
+    let typescript_cell_dep = CellDep::new_builder()
+        .out_point(OutPoint::new(
+            Byte32::from_slice(ts_outpoint.as_bytes()).unwrap(),
+            0,
+        ))
+        .dep_type(DepType::Code.into())
+        .build();
+    let always_success_cell_dep = CellDep::new_builder()
+        .out_point(OutPoint::new(
+            Byte32::from_slice(always_success_outpoint.as_bytes()).unwrap(),
+            args.always_success_out_point_index,
+        ))
+        .dep_type(DepType::Code.into())
+        .build();
     let mut publisher = CellPublisher::new(&admin_addr, admin_private_key, &args.rpc_url);
-    let merkle_root_cell = {
-        let mut data = create_merkle_tree_with_root_hash_rsa(&keys, args.chunk_size)
-            .with_context(|| anyhow!("Failed to create merkle tree root"))?;
-        data.write_all(&(keys.len() as u32).to_le_bytes()).unwrap();
-        data.write_all(
-            &(keys.len() as u32)
-                .div_ceil(args.chunk_size as _)
-                .to_le_bytes(),
-        )
-        .unwrap();
-
-        publisher
-            .publish_bytes_cell(&data, &admin_addr, None, None, vec![], None)
-            .with_context(|| anyhow!("Failed to publish merkle root cell"))?
+    let voting_start = publisher
+        .client
+        .get_tip_block_number()
+        .with_context(|| anyhow!("Failed to get tip block number"))?
+        .value();
+    let voting_end = voting_start + args.voting_period_blocks;
+    // Votes sign the genesis block hash, so they can't be replayed on another chain
+    let network: [u8; 32] = publisher
+        .client
+        .get_block_hash(0.into())
+        .with_context(|| anyhow!("Failed to get genesis block hash"))?
+        .ok_or_else(|| anyhow!("Genesis block not found"))?
+        .into();
+    let mut key_image_registry_cell = {
+        let first_input = publisher.collect_first_input()?;
+        let output = CellOutput::new_builder()
+            .lock(
+                Script::new_builder()
+                    .code_hash(Byte32::from_slice(always_success_code_hash.as_bytes()).unwrap())
+                    .hash_type(ScriptHashType::Type.into())
+                    .build(),
+            )
+            .type_(
+                Some(
+                    Script::new_builder()
+                        .code_hash(Byte32::from_slice(ts_code_hash.as_bytes()).unwrap())
+                        .hash_type(ScriptHashType::Data1.into())
+                        .args(
+                            Bytes::from(key_image_registry_script_args(&calculate_type_id(
+                                &first_input,
+                                0,
+                            )))
+                            .pack(),
+                        )
+                        .build(),
+                )
+                .pack(),
+            )
+            .capacity((200 * ONE_CKB).pack())
+            .build();
+        let tx_hash = publisher
+            .publish_transaction(&SimpleTransferBuilderWithWitness {
+                inputs: vec![first_input],
+                outputs: vec![(output.clone(), Bytes::from(vec![0u8; 32]), Bytes::new())],
+                extra_cell_dep: vec![typescript_cell_dep.clone()],
+            })
+            .with_context(|| anyhow!("Failed to publish key image registry cell"))?;
+        KeyImageRegistryCell {
+            out_point: OutPoint::new(tx_hash.pack(), 0),
+            output,
+            registry: KeyImageRegistry::default(),
+        }
     };
-
-    let (candidates, candidate_cell) = {
+    let candidates = {
         let mut rng = thread_rng();
-        let candidates = (0..100)
+        (0..100)
             .map(|x| Candidate {
                 description: format!("Candidate {}", x + 1),
                 id: rng.gen(),
             })
+            .collect::<Vec<_>>()
+    };
+    let (config, config_cell, config_type_hash, keys, public_keys) = {
+        let admin_lock = Script::from(&admin_addr);
+        // Config cell is identified by the built-in type id script
+        let first_input = publisher.collect_first_input()?;
+        let type_script = Script::new_builder()
+            .code_hash(TYPE_ID_CODE_HASH.pack())
+            .hash_type(ScriptHashType::Type.into())
+            .args(Bytes::copy_from_slice(&calculate_type_id(&first_input, 0)).pack())
+            .build();
+        let config_type_hash: [u8; 32] = type_script.calc_script_hash().unpack();
+        let first_config = VoteConfig {
+            network,
+            // Commits to the keys before the seed of the shuffle is known
+            merkle_root: key_set_root(&scheme, &public_keys, args.chunk_size)
+                .with_context(|| anyhow!("Failed to create merkle tree root"))?,
+            user_count: keys.len() as u32,
+            leaf_count: (keys.len() as u32).div_ceil(args.chunk_size as _),
+            group_size: args.chunk_size as u32,
+            signature_scheme: scheme.signature_scheme(),
+            voting_start,
+            voting_end,
+            admin_lock_hash: admin_lock.calc_script_hash().unpack(),
+            key_image_registry_type_hash: key_image_registry_cell
+                .output
+                .type_()
+                .to_opt()
+                .unwrap()
+                .calc_script_hash()
+                .unpack(),
+            candidates: candidates.clone(),
+        };
+        let data = first_config.encode();
+        let output = CellOutput::new_builder()
+            .lock(admin_lock)
+            .type_(Some(type_script.clone()).pack())
+            .capacity(((61 + 65 + 100 + data.len()) as u64 * ONE_CKB).pack())
+            .build();
+        let tx_hash = publisher
+            .publish_transaction(&SimpleTransferBuilderWithWitness {
+                inputs: vec![first_input],
+                outputs: vec![(output.clone(), Bytes::from(data), Bytes::new())],
+                extra_cell_dep: vec![],
+            })
+            .with_context(|| anyhow!("Failed to publish config cell"))?;
+        let seed_block = ring_seed_block(publisher.wait_committed(&tx_hash)?);
+        log::info!("waiting for block {} to seed the rings", seed_block);
+        let seed = publisher.wait_block_hash(seed_block)?;
+
+        // Rings are assigned by the shuffle seeded after the keys were committed to, not by the
+        // order keys were made in. The config is replaced with the root of the shuffled keys,
+        // keeping its type id
+        let order = ring_order(&scheme, &public_keys, &seed)?;
+        let keys = order
+            .iter()
+            .map(|&index| keys[index].clone())
+            .collect::<Vec<_>>();
+        let public_keys = order
+            .iter()
+            .map(|&index| public_keys[index])
             .collect::<Vec<_>>();
-        let encoded = encode_candidate_cell(&candidates);
+        let config = VoteConfig {
+            merkle_root: create_merkle_tree_with_root_hash(&scheme, &public_keys, args.chunk_size)
+                .with_context(|| anyhow!("Failed to create merkle tree root"))?
+                .try_into()
+                .map_err(|_| anyhow!("Bad merkle tree root length"))?,
+            ..first_config
+        };
+        let tx_hash = publisher
+            .publish_transaction(&SimpleTransferBuilderWithWitness {
+                inputs: vec![CellInput::new_builder()
+                    .previous_output(OutPoint::new(tx_hash.pack(), 0))
+                    .build()],
+                outputs: vec![(output, Bytes::from(config.encode()), Bytes::new())],
+                extra_cell_dep: vec![],
+            })
+            .with_context(|| anyhow!("Failed to replace config cell"))?;
         (
-            candidates,
-            publisher
-                .publish_bytes_cell(&encoded, &admin_addr, None, None, vec![], None)
-                .with_context(|| anyhow!("Failed to publish candidate cell"))?,
+            config,
+            OutPoint::new(tx_hash.pack(), 0),
+            config_type_hash,
+            keys,
+            public_keys,
         )
     };
+    let config_cell_dep = CellDep::new_builder()
+        .out_point(config_cell.clone())
+        .dep_type(DepType::Code.into())
+        .build();
+
+    // Publish all public keys, so voters rebuild their rings from the chain
+    let registry_cells =
+        public_key_registry_cells(&public_keys, args.chunk_size, args.rings_per_registry_cell)?
+            .iter()
+            .map(|cell| cell.encode(&scheme))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+    let mut registry_cell_deps = vec![];
+    for (idx, data) in registry_cells.iter().enumerate() {
+        let output = CellOutput::new_builder()
+            .lock(Script::from(&admin_addr))
+            .type_(
+                Some(
+                    Script::new_builder()
+                        .code_hash(Byte32::from_slice(ts_code_hash.as_bytes()).unwrap())
+                        .hash_type(ScriptHashType::Data1.into())
+                        .args(
+                            Bytes::from(public_key_registry_script_args(&config_type_hash)).pack(),
+                        )
+                        .build(),
+                )
+                .pack(),
+            )
+            .capacity(((61 + 33 + 100 + data.len()) as u64 * ONE_CKB).pack())
+            .build();
+        let tx_hash = publisher
+            .publish_transaction(&SimpleTransferBuilderWithWitness {
+                inputs: vec![],
+                outputs: vec![(output, Bytes::from(data.clone()), Bytes::new())],
+                extra_cell_dep: vec![typescript_cell_dep.clone(), config_cell_dep.clone()],
+            })
+            .with_context(|| anyhow!("Failed to publish public key registry cell"))?;
+        registry_cell_deps.push(
+            CellDep::new_builder()
+                .out_point(OutPoint::new(tx_hash.pack(), 0))
+                .dep_type(DepType::Code.into())
+                .build(),
+        );
+        log::info!(
+            "public key registry cell {}/{} published",
+            idx + 1,
+            registry_cells.len()
+        );
+    }
+    let rings = rebuild_rings(&scheme, &config, registry_cells.iter().map(Vec::as_slice))
+        .with_context(|| anyhow!("Failed to rebuild rings"))?;
 
     let done_count = AtomicUsize::new(0);
     let voted_target = keys
@@ -358,54 +633,36 @@ fn main() -> anyhow::Result<()> {
             let mut rng = thread_rng();
             let candidate_target = &candidates[rng.gen_range(0..candidates.len())];
 
-            let belonging_block = idx / args.chunk_size;
+            let ring = &rings[idx / args.chunk_size];
             let block_index = idx % args.chunk_size;
-            let ring_keys = &keys[belonging_block * args.chunk_size
-                ..((belonging_block + 1) * args.chunk_size).min(keys.len())];
-            let signature =
-                create_signature(&ring_keys, private_key, block_index, &candidate_target.id)
-                    .unwrap();
-            let MerkleProofResult {
-                proof,
-                leaf_hash: _,
-            } = create_merkle_tree_with_proof_rsa(&keys, args.chunk_size, belonging_block)
-                .with_context(|| anyhow!("Failed to create merkle proof"))
-                .unwrap();
-
-            let vote_cell_data = {
-                let mut buf = vec![0u8; 0];
-                buf.write_all(&candidate_target.id).unwrap();
-                check_size_and_write(&mut buf, &signature.i, 256).unwrap();
-                buf
-            };
-            let witness_data = {
-                let mut buf = vec![0u8; 0];
-                check_size_and_write(&mut buf, &signature.c, 256).unwrap();
-                buf.write_all(&(signature.r_and_pubkey.len() as u32).to_le_bytes())
-                    .unwrap();
-                for item in signature.r_and_pubkey.iter() {
-                    check_size_and_write(&mut buf, &item.r, 256).unwrap();
-                }
-                for item in signature.r_and_pubkey.iter() {
-                    check_size_and_write(&mut buf, &item.n, 256).unwrap();
-                }
-                for item in signature.r_and_pubkey.iter() {
-                    check_size_and_write(&mut buf, &item.e, 4).unwrap();
-                }
-                buf.write_all(&(belonging_block as u32).to_le_bytes())
-                    .unwrap();
-                buf.write_all(&(proof.len() as u32).to_le_bytes()).unwrap();
-                buf.write_all(&proof).unwrap();
-                buf
-            };
+            let signature: RingSignature = scheme
+                .sign(
+                    &ring.public_keys,
+                    private_key,
+                    block_index,
+                    &config_type_hash,
+                    &ballot_message(
+                        &config_type_hash,
+                        &network,
+                        &ts_code_hash.0,
+                        &candidate_target.id,
+                    ),
+                )
+                .unwrap()
+                .into();
+            let vote_cell = VoteCell::new(candidate_target.id, &signature).unwrap();
+            let vote_cell_data = vote_cell.encode();
+            let witness = VoteWitness::new(&signature, ring.leaf_index, ring.merkle_proof.clone());
             log::info!(
                 "{} sign done",
                 done_count.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1
             );
             VoteData {
-                candidate_id: candidate_target.id.clone(),
+                candidate_id: candidate_target.id,
+                image: vote_cell.image,
                 vote_cell_data,
-                witness_data,
+                witness,
+                registry_cell: ring.registry_cell.filter(|_| !args.inline_ring_keys),
             }
         })
         .collect::<Vec<_>>();
@@ -509,76 +766,125 @@ fn main() -> anyhow::Result<()> {
             }
         }
     }
-    let total_count = AtomicUsize::new(0);
-    chunked_vote_target_with_account
-        .into_par_iter()
-        .enumerate()
-        .try_for_each(
-            |(
-                index,
-                ChunkedVoteTarget {
-                    vote_targets,
-                    address: _,
-                    required_ckb: _,
-                    mut publisher,
-                },
-            )|
-             -> anyhow::Result<()> {
-                let total_len = vote_targets.len();
-                for (vote_idx, target) in vote_targets.into_iter().enumerate() {
-                    publisher
-                        .publish_bytes_cell(
-                            &target.vote_cell_data,
-                            &admin_addr,
-                            Some(&target.witness_data),
-                            Some((ts_code_hash.clone(), ScriptHashType::Data1)),
-                            vec![
-                                CellDep::new_builder()
-                                    .out_point(OutPoint::new(
-                                        Byte32::from_slice(candidate_cell.0.as_bytes()).unwrap(),
-                                        candidate_cell.1,
-                                    ))
-                                    .build(),
-                                CellDep::new_builder()
-                                    .out_point(OutPoint::new(
-                                        Byte32::from_slice(merkle_root_cell.0.as_bytes()).unwrap(),
-                                        merkle_root_cell.1,
-                                    ))
-                                    .build(),
-                                CellDep::new_builder()
-                                    .out_point(OutPoint::new(
-                                        Byte32::from_slice(ts_outpoint.as_bytes()).unwrap(),
-                                        0,
-                                    ))
-                                    .dep_type(DepType::Code.into())
-                                    .build(),
-                            ],
-                            None,
+    let vote_type_script = Script::new_builder()
+        .code_hash(Byte32::from_slice(ts_code_hash.as_bytes()).unwrap())
+        .hash_type(ScriptHashType::Data1.into())
+        .args(Bytes::from(vote_script_args(&config_type_hash)).pack())
+        .build();
+    let vote_cell_deps = vec![
+        config_cell_dep,
+        typescript_cell_dep,
+        always_success_cell_dep,
+    ];
+    // Every vote transaction consumes the key image registry created by the previous one, so
+    // transactions are sent one by one
+    let mut total_count = 0;
+    for (
+        index,
+        ChunkedVoteTarget {
+            vote_targets,
+            address: _,
+            required_ckb: _,
+            mut publisher,
+        },
+    ) in chunked_vote_target_with_account.into_iter().enumerate()
+    {
+        let total_len = vote_targets.len();
+        for (batch_idx, batch) in vote_targets.chunks(args.batch_size.max(1)).enumerate() {
+            let registry_update = key_image_registry_cell
+                .registry
+                .insert_images(
+                    &batch
+                        .iter()
+                        .map(|target| &target.image[..])
+                        .collect::<Vec<_>>(),
+                )
+                .with_context(|| anyhow!("Failed to insert images into key image registry"))?;
+            // Each vote cell is followed by its witness at the same index, and the registry
+            // proof goes with the first one since the registry is the first input. Registry
+            // cells of the rings of the batch follow the cell deps of all votes
+            let mut cell_deps = vote_cell_deps.clone();
+            let mut outputs = batch
+                .iter()
+                .enumerate()
+                .map(|(idx, target)| {
+                    let mut vote_witness = target.witness.clone();
+                    if let Some(cell) = target.registry_cell {
+                        let cell_dep = &registry_cell_deps[cell];
+                        let dep_index = match cell_deps.iter().position(|dep| dep == cell_dep) {
+                            Some(dep_index) => dep_index,
+                            None => {
+                                cell_deps.push(cell_dep.clone());
+                                cell_deps.len() - 1
+                            }
+                        };
+                        vote_witness.ring_cell_dep = Some(dep_index as u32);
+                    }
+                    let witness_data = vote_witness.encode(scheme.signature_scheme())?;
+                    let vote_output = CellOutput::new_builder()
+                        .lock(Script::from(&admin_addr))
+                        .capacity(
+                            ((61 + 100 + target.vote_cell_data.len()) as u64 * ONE_CKB).pack(),
                         )
-                        .with_context(|| anyhow!("Failed to send transaction"))?;
-                    log::info!(
-                        "Worker {} sended transaction {}/{} (total {}/{})",
-                        index,
-                        vote_idx,
-                        total_len,
-                        total_count.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1,
-                        keys.len()
-                    );
-                }
-                Ok(())
-            },
-        )?;
+                        .type_(Some(vote_type_script.clone()).pack())
+                        .build();
+                    let mut witness = WitnessArgsBuilder::default()
+                        .output_type(Some(Bytes::from(witness_data)).pack());
+                    if idx == 0 {
+                        witness = witness
+                            .input_type(Some(Bytes::from(registry_update.proof.clone())).pack());
+                    }
+                    Ok((
+                        vote_output,
+                        Bytes::from(target.vote_cell_data.clone()),
+                        witness.build().as_bytes(),
+                    ))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            outputs.push((
+                key_image_registry_cell.output.clone(),
+                Bytes::copy_from_slice(&registry_update.new_root),
+                Bytes::new(),
+            ));
+            let tx_hash = publisher
+                .publish_transaction(&SimpleTransferBuilderWithWitness {
+                    // The since proves that voting period has started
+                    inputs: vec![CellInput::new_builder()
+                        .previous_output(key_image_registry_cell.out_point.clone())
+                        .since(voting_start.pack())
+                        .build()],
+                    outputs,
+                    extra_cell_dep: cell_deps,
+                })
+                .with_context(|| anyhow!("Failed to send transaction"))?;
+            key_image_registry_cell.out_point = OutPoint::new(tx_hash.pack(), batch.len() as u32);
+            total_count += batch.len();
+            log::info!(
+                "Worker {} sended batch {} of {} votes (worker has {}, total {}/{})",
+                index,
+                batch_idx,
+                batch.len(),
+                total_len,
+                total_count,
+                keys.len()
+            );
+        }
+    }
     let vote_result_string_as_key = expected_vote_result
         .into_iter()
         .map(|(key, val)| (format!("{:08X}", u32::from_le_bytes(key)), val))
         .collect::<HashMap<_, _>>();
     println!(
-        "Merkle tree root cell: 0x{}:{}",
-        merkle_root_cell.0, merkle_root_cell.1
+        "Config cell: 0x{}:{}",
+        config_cell.tx_hash(),
+        config_cell.index()
     );
+    println!("Config type hash: 0x{}", hex_string(&config_type_hash));
+    println!("Voting period: [{}, {})", voting_start, voting_end);
     println!(
-        "Candidate cell: 0x{}:{}",
-        candidate_cell.0, candidate_cell.1
+        "Key image registry cell: 0x{}:{}",
+        key_image_registry_cell.out_point.tx_hash(),
+        key_image_registry_cell.out_point.index()
     );
     println!(
         "{}",
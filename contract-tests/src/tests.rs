@@ -1,54 +1,216 @@
-use std::io::Write;
 use std::sync::atomic::AtomicUsize;
 
 use crate::Loader;
 use ckb_testtool::builtin::ALWAYS_SUCCESS;
 use ckb_testtool::bytes::Bytes;
-use ckb_testtool::ckb_types::core::TransactionBuilder;
-use ckb_testtool::ckb_types::packed::{CellDep, CellInput, CellOutput, ScriptOpt, WitnessArgs};
+use ckb_testtool::ckb_chain_spec::consensus::TYPE_ID_CODE_HASH;
+use ckb_testtool::ckb_hash::new_blake2b;
+use ckb_testtool::ckb_types::core::{
+    HeaderBuilder, ScriptHashType, TransactionBuilder, TransactionView,
+};
+use ckb_testtool::ckb_types::packed::{
+    CellDep, CellInput, CellOutput, Script, ScriptOpt, WitnessArgs,
+};
 use ckb_testtool::ckb_types::prelude::Builder;
-use ckb_testtool::ckb_types::prelude::{Entity, Pack};
+use ckb_testtool::ckb_types::prelude::{Entity, Pack, Unpack};
 use ckb_testtool::{ckb_types::packed::OutPoint, context::Context};
 use rand::seq::SliceRandom;
-use rand::Rng;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
-use rsa::RsaPrivateKey;
-use signature_tools::candidate::{encode_candidate_cell, Candidate};
-use signature_tools::check_size_and_write;
-use signature_tools::rsa_tools::create_signature;
-use signature_tools::rsa_tools::merkle_tree::{
-    create_merkle_tree_with_proof_rsa, create_merkle_tree_with_root_hash_rsa, MerkleProofResult,
+use rsa::{RsaPrivateKey, RsaPublicKey};
+use signature_tools::candidate::Candidate;
+use signature_tools::ec_tools::{LsagPrivateKey, LsagScheme, LsagSignature};
+use signature_tools::key_image_registry::{KeyImageRegistry, KeyImageRegistryUpdate};
+use signature_tools::merkle_tree::{
+    create_merkle_tree_with_proof, create_merkle_tree_with_root_hash, ring_range, MerkleProofResult,
+};
+use signature_tools::public_key_registry::{
+    public_key_registry_cells, rebuild_rings, PublicKeyRegistryCell,
+};
+use signature_tools::ring_signature::RingSignatureScheme;
+use signature_tools::rsa_tools::{
+    create_signature_with_image, verify_signature, PrivateKeyParts, RSASignature, RsaKeySize,
+    RsaScheme,
 };
+use signature_tools::vote::{
+    ballot_message, RingSignature, VoteCell, VoteError, VoteWitness, WitnessSignature,
+};
+use signature_tools::vote_config::{
+    key_image_registry_script_args, public_key_registry_script_args, vote_script_args,
+    SignatureScheme, VoteConfig,
+};
+use vote_types::molecule::prelude::{Builder as _, Entity as _};
+use vote_types::schema;
 
 const KEY_COUNT: usize = 1000;
 const CHUNK_SIZE: usize = 15;
 const CANDIDATE_COUNT: usize = 100;
+/// Cycle limit of a block
 const MAX_CYCLES: u64 = 35_0000_0000;
+const VOTING_START: u64 = 100;
+const VOTING_END: u64 = 200;
+const SCHEME: SignatureScheme = SignatureScheme::Lsag;
+/// Seed of known answer vectors
+const KAT_SEED: [u8; 32] = *b"ckb-vote known answer test seed!";
+/// Message signed by the vector of the contract unit tests
+const KAT_MESSAGE: &[u8] = b"hello, world!";
+/// Election of known answer signatures
+const KAT_ELECTION_ID: [u8; 32] = *b"ckb-vote known answer election!!";
+/// Genesis block hash in configs of elections of the tests
+const NETWORK: [u8; 32] = *b"ckb-vote contract tests network!";
 
-#[derive(Debug)]
-struct PreparedState {
-    #[allow(unused)]
-    candidate_cell: OutPoint,
-    keys: Vec<RsaPrivateKey>,
+struct PreparedState<S: RingSignatureScheme = LsagScheme> {
+    scheme: S,
+    keys: Vec<S::PrivateKey>,
+    public_keys: Vec<S::PublicKey>,
+    group_size: usize,
     candidates: Vec<Candidate>,
-    merkle_root_cell: OutPoint,
+    config_cell: OutPoint,
+    config_type_hash: [u8; 32],
+    /// Code hash of the type script of vote cells
+    contract_code_hash: [u8; 32],
+    key_image_registry_cell: OutPoint,
+}
+
+impl<S: RingSignatureScheme> PreparedState<S> {
+    /// Message a vote for `candidate_id` in the election signs
+    fn ballot(&self, candidate_id: &[u8; 4]) -> Vec<u8> {
+        ballot_message(
+            &self.config_type_hash,
+            &NETWORK,
+            &self.contract_code_hash,
+            candidate_id,
+        )
+        .to_vec()
+    }
+}
+
+fn calculate_type_id(first_input: &CellInput, output_index: u64) -> [u8; 32] {
+    let mut hasher = new_blake2b();
+    hasher.update(first_input.as_slice());
+    hasher.update(&output_index.to_le_bytes());
+    let mut result = [0u8; 32];
+    hasher.finalize(&mut result);
+    result
+}
+
+/// Deploy `data` in a cell typed by the built-in type id script of `type_id`, so the code hash of
+/// scripts of hash type `type` using it is known beforehand
+fn deploy_cell_with_type_id(ctx: &mut Context, data: Bytes, type_id: [u8; 32]) -> OutPoint {
+    let type_script = Script::new_builder()
+        .code_hash(TYPE_ID_CODE_HASH.pack())
+        .hash_type(ScriptHashType::Type.into())
+        .args(Bytes::copy_from_slice(&type_id).pack())
+        .build();
+    ctx.create_cell(
+        CellOutput::new_builder()
+            .capacity((data.len() as u64).pack())
+            .type_(Some(type_script).pack())
+            .build(),
+        data,
+    )
+}
+
+/// Create an empty key image registry, which could be spent by anyone
+fn create_key_image_registry(ctx: &mut Context, script_out_point: &OutPoint) -> OutPoint {
+    let always_success_script_op = ctx.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_script = ctx
+        .build_script(&always_success_script_op, Default::default())
+        .unwrap();
+    let input = CellInput::new_builder()
+        .previous_output(
+            ctx.create_cell(
+                CellOutput::new_builder()
+                    .capacity(1000u64.pack())
+                    .lock(always_success_script.clone())
+                    .build(),
+                Bytes::new(),
+            ),
+        )
+        .build();
+    let type_script = ctx
+        .build_script(
+            script_out_point,
+            key_image_registry_script_args(&calculate_type_id(&input, 0)).into(),
+        )
+        .unwrap();
+    let output = CellOutput::new_builder()
+        .capacity(1000u64.pack())
+        .lock(always_success_script)
+        .type_(ScriptOpt::new_builder().set(Some(type_script)).build())
+        .build();
+    let data = Bytes::from(KeyImageRegistry::default().root().to_vec());
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .output(output.clone())
+        .output_data(data.pack())
+        .build();
+    let tx = ctx.complete_tx(tx);
+    ctx.verify_tx(&tx, MAX_CYCLES).unwrap();
+    ctx.create_cell(output, data)
 }
 
-fn prepare(ctx: &mut Context) -> PreparedState {
+fn generate_keys(key_size: RsaKeySize, key_count: usize) -> Vec<RsaPrivateKey> {
     let generated_count = AtomicUsize::new(0);
-    let keys = (0..KEY_COUNT)
+    (0..key_count)
         .into_par_iter()
         .map(|_| {
             let mut rng = rand::thread_rng();
-            let priv_key = RsaPrivateKey::new(&mut rng, 2048).unwrap();
+            let priv_key = RsaScheme(key_size).generate_key(&mut rng).unwrap();
             println!(
                 "Key generating done {}",
                 generated_count.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1
             );
             priv_key
         })
+        .collect()
+}
+
+/// Ring of `ring_size` keys and a signature of `message` by the key at `signer`, which are the
+/// same for the same seed. Key `i` is generated from stream `i` of a rng seeded with `seed`, and
+/// the signature from stream `ring_size`
+fn known_answer_signature(
+    seed: [u8; 32],
+    key_size: RsaKeySize,
+    ring_size: usize,
+    signer: usize,
+    message: &[u8],
+) -> (Vec<RsaPublicKey>, RSASignature) {
+    let rng = |stream: usize| {
+        let mut rng = ChaCha20Rng::from_seed(seed);
+        rng.set_stream(stream as u64);
+        rng
+    };
+    let scheme = RsaScheme(key_size);
+    let keys = (0..ring_size)
+        .into_par_iter()
+        .map(|i| scheme.generate_key(&mut rng(i)).unwrap())
+        .collect::<Vec<_>>();
+    let ring = keys
+        .iter()
+        .map(|key| scheme.public_key(key))
         .collect::<Vec<_>>();
+    let signature = scheme
+        .sign_with_rng(
+            &ring,
+            &keys[signer],
+            signer,
+            &KAT_ELECTION_ID,
+            message,
+            &mut rng(ring_size),
+        )
+        .unwrap();
+    (ring, signature)
+}
 
+fn prepare<S: RingSignatureScheme>(
+    ctx: &mut Context,
+    script_out_point: &OutPoint,
+    scheme: S,
+    keys: Vec<S::PrivateKey>,
+    group_size: usize,
+) -> PreparedState<S> {
     let mut rng = rand::thread_rng();
     let candidates = (0..CANDIDATE_COUNT)
         .map(|idx| Candidate {
@@ -56,52 +218,171 @@ fn prepare(ctx: &mut Context) -> PreparedState {
             id: rng.gen(),
         })
         .collect::<Vec<_>>();
-    let candidate_cell = { ctx.deploy_cell(encode_candidate_cell(&candidates).into()) };
-    let merkle_root = {
-        let mut data = create_merkle_tree_with_root_hash_rsa(&keys, CHUNK_SIZE).unwrap();
-        data.write_all(&(keys.len() as u32).to_le_bytes()).unwrap();
-        data.write_all(&(keys.len().div_ceil(CHUNK_SIZE) as u32).to_le_bytes())
-            .unwrap();
-
-        data
+    let public_keys = keys
+        .iter()
+        .map(|key| scheme.public_key(key))
+        .collect::<Vec<_>>();
+    let config = VoteConfig {
+        network: NETWORK,
+        merkle_root: create_merkle_tree_with_root_hash(&scheme, &public_keys, group_size)
+            .unwrap()
+            .try_into()
+            .unwrap(),
+        user_count: keys.len() as u32,
+        leaf_count: keys.len().div_ceil(group_size) as u32,
+        group_size: group_size as u32,
+        signature_scheme: scheme.signature_scheme(),
+        voting_start: VOTING_START,
+        voting_end: VOTING_END,
+        // Both are set when deploying
+        admin_lock_hash: [0; 32],
+        key_image_registry_type_hash: [0; 32],
+        candidates: candidates.clone(),
     };
+    let (config_cell, config_type_hash, key_image_registry_cell) =
+        deploy_election(ctx, script_out_point, config);
+    let contract_code_hash = ctx
+        .build_script(script_out_point, Bytes::new())
+        .unwrap()
+        .code_hash()
+        .unpack();
 
     PreparedState {
-        candidate_cell,
         candidates,
+        scheme,
         keys,
-        merkle_root_cell: ctx.deploy_cell(merkle_root.into()),
+        public_keys,
+        group_size,
+        config_cell,
+        config_type_hash,
+        contract_code_hash,
+        key_image_registry_cell,
     }
 }
 
-#[test]
-fn test_verify_signature() {
-    let mut rng = rand::thread_rng();
-    let mut ctx = Context::default();
-    let loader = Loader::default();
-    let verifier_bin = loader.load_binary("ring-signature-verify");
-    let script_out_point = ctx.deploy_cell(verifier_bin);
-    let state = prepare(&mut ctx);
-    let signer = rng.gen_range(0usize..state.keys.len());
-    let signer_block = signer / CHUNK_SIZE;
-    let signer_index = signer % CHUNK_SIZE;
-    let selected_candidate = state.candidates.choose(&mut rng).unwrap();
-    let signature = create_signature(
-        &state.keys
-            [CHUNK_SIZE * signer_block..(CHUNK_SIZE * (signer_block + 1)).min(state.keys.len())]
-            .iter()
-            .map(|s| s.to_public_key())
-            .collect::<Vec<_>>(),
-        &state.keys[signer],
-        signer_index,
-        &selected_candidate.id,
+/// Deploy the config cell of an election, owned by an always success lock, with a new key
+/// image registry. Returns the config cell, its type hash and the key image registry cell
+fn deploy_election(
+    ctx: &mut Context,
+    script_out_point: &OutPoint,
+    config: VoteConfig,
+) -> (OutPoint, [u8; 32], OutPoint) {
+    deploy_election_with(ctx, script_out_point, config, VoteConfig::encode)
+}
+
+/// Same as [`deploy_election`], with the data of the config cell given by `encode`, so a config
+/// could be deployed in any shape
+fn deploy_election_with(
+    ctx: &mut Context,
+    script_out_point: &OutPoint,
+    config: VoteConfig,
+    encode: impl FnOnce(&VoteConfig) -> Vec<u8>,
+) -> (OutPoint, [u8; 32], OutPoint) {
+    let type_id = rand::thread_rng().gen();
+    deploy_election_with_type_id(ctx, script_out_point, config, encode, type_id)
+}
+
+/// Same as [`deploy_election_with`], with the config cell of type id `type_id`, so its type hash
+/// is known beforehand
+fn deploy_election_with_type_id(
+    ctx: &mut Context,
+    script_out_point: &OutPoint,
+    config: VoteConfig,
+    encode: impl FnOnce(&VoteConfig) -> Vec<u8>,
+    type_id: [u8; 32],
+) -> (OutPoint, [u8; 32], OutPoint) {
+    let key_image_registry_cell = create_key_image_registry(ctx, script_out_point);
+    let (registry_output, _) = ctx.get_cell(&key_image_registry_cell).unwrap();
+    let admin_lock = {
+        let always_success_script_op = ctx.deploy_cell(ALWAYS_SUCCESS.clone());
+        ctx.build_script(&always_success_script_op, Default::default())
+            .unwrap()
+    };
+    let config = VoteConfig {
+        admin_lock_hash: admin_lock.calc_script_hash().unpack(),
+        key_image_registry_type_hash: registry_output
+            .type_()
+            .to_opt()
+            .unwrap()
+            .calc_script_hash()
+            .unpack(),
+        ..config
+    };
+    // Config cell is typed by the built-in type id script, it's only used as a dep so its type
+    // id is not verified here
+    let config_type_script = Script::new_builder()
+        .code_hash(TYPE_ID_CODE_HASH.pack())
+        .hash_type(ScriptHashType::Type.into())
+        .args(Bytes::copy_from_slice(&type_id).pack())
+        .build();
+    let config_type_hash = config_type_script.calc_script_hash().unpack();
+    let config_cell = ctx.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(admin_lock)
+            .type_(
+                ScriptOpt::new_builder()
+                    .set(Some(config_type_script))
+                    .build(),
+            )
+            .build(),
+        encode(&config).into(),
+    );
+    (config_cell, config_type_hash, key_image_registry_cell)
+}
+
+fn encode_vote_cell(candidate_id: &[u8; 4], signature: &RingSignature) -> Vec<u8> {
+    VoteCell::new(*candidate_id, signature).unwrap().encode()
+}
+
+fn encode_vote_witness(signature: &RingSignature, signer_block: usize, proof: &[u8]) -> Bytes {
+    VoteWitness::new(signature, signer_block as u32, proof.to_vec())
+        .encode(SCHEME)
+        .unwrap()
+        .into()
+}
+
+/// Key image of an encoded vote cell, as inserted into the key image registry
+fn image_of(vote_cell_data: &[u8]) -> Vec<u8> {
+    VoteCell::decode(vote_cell_data, SCHEME).unwrap().image
+}
+
+/// Vote of the key at `signer`, signed over its ring, with the merkle proof of the ring
+fn sign_vote<S: RingSignatureScheme>(
+    state: &PreparedState<S>,
+    signer: usize,
+    candidate_id: &[u8; 4],
+) -> (VoteCell, VoteWitness) {
+    let group_size = state.group_size;
+    let block = signer / group_size;
+    let ring = &state.public_keys[ring_range(state.public_keys.len(), group_size, block)];
+    let signature: RingSignature = state
+        .scheme
+        .sign(
+            ring,
+            &state.keys[signer],
+            signer % group_size,
+            &state.config_type_hash,
+            &state.ballot(candidate_id),
+        )
+        .unwrap()
+        .into();
+    let proof = create_merkle_tree_with_proof(&state.scheme, &state.public_keys, group_size, block)
+        .unwrap()
+        .proof;
+    (
+        VoteCell::new(*candidate_id, &signature).unwrap(),
+        VoteWitness::new(&signature, block as u32, proof),
     )
-    .unwrap();
-    let MerkleProofResult {
-        proof,
-        leaf_hash: _,
-    } = create_merkle_tree_with_proof_rsa(&state.keys, CHUNK_SIZE, signer_block).unwrap();
+}
 
+/// Cell deps, a funding input since the voting start, and the vote type script of an election
+fn prepare_vote_tx(
+    ctx: &mut Context,
+    config_cell: &OutPoint,
+    config_type_hash: &[u8; 32],
+    script_out_point: &OutPoint,
+) -> (Vec<CellDep>, CellInput, Script) {
     let always_success_script_op = ctx.deploy_cell(ALWAYS_SUCCESS.clone());
     let always_success_script = ctx
         .build_script(&always_success_script_op, Default::default())
@@ -109,10 +390,7 @@ fn test_verify_signature() {
 
     let cell_deps: Vec<CellDep> = vec![
         CellDep::new_builder()
-            .out_point(state.candidate_cell)
-            .build(),
-        CellDep::new_builder()
-            .out_point(state.merkle_root_cell.clone())
+            .out_point(config_cell.clone())
             .build(),
         CellDep::new_builder()
             .out_point(always_success_script_op)
@@ -130,92 +408,1694 @@ fn test_verify_signature() {
                 .build(),
             Bytes::new(),
         );
-        vec![CellInput::new_builder()
+        CellInput::new_builder()
             .previous_output(input_out_point)
-            .build()]
+            .since(VOTING_START.pack())
+            .build()
     };
 
-    let (tx_output, tx_output_data) = {
-        let mut cell_data = vec![0u8; 0];
-        cell_data.write_all(&selected_candidate.id).unwrap();
-        check_size_and_write(&mut cell_data, &signature.i, 256).unwrap();
-        let type_script = ctx.build_script(&script_out_point, Bytes::new()).unwrap();
+    let type_script = ctx
+        .build_script(script_out_point, vote_script_args(config_type_hash).into())
+        .unwrap();
+    (cell_deps, tx_input, type_script)
+}
+
+/// Build a vote transaction, with the key image registry as the first input, and the vote cell
+/// as the first output
+fn build_vote_tx(
+    ctx: &mut Context,
+    cell_deps: &[CellDep],
+    tx_input: &CellInput,
+    key_image_registry_cell: &OutPoint,
+    registry_update: &KeyImageRegistryUpdate,
+    (vote_output, vote_cell_data): (CellOutput, Bytes),
+    vote_witness_data: Bytes,
+) -> TransactionView {
+    let (registry_output, _) = ctx.get_cell(key_image_registry_cell).unwrap();
+    TransactionBuilder::default()
+        .cell_deps(cell_deps.to_vec())
+        .input(
+            CellInput::new_builder()
+                .previous_output(key_image_registry_cell.clone())
+                .build(),
+        )
+        .input(tx_input.clone())
+        .outputs(vec![vote_output, registry_output])
+        .outputs_data(
+            [
+                vote_cell_data,
+                Bytes::copy_from_slice(&registry_update.new_root),
+            ]
+            .pack(),
+        )
+        .witness(
+            WitnessArgs::new_builder()
+                .input_type(Some(Bytes::from(registry_update.proof.clone())).pack())
+                .output_type(Some(vote_witness_data).pack())
+                .lock(Option::<Bytes>::None.pack())
+                .build()
+                .as_bytes()
+                .pack(),
+        )
+        .build()
+}
+
+/// Verify a transaction which must be rejected by a script exiting with `code`
+fn assert_exit_code(ctx: &Context, tx: &TransactionView, code: i8) {
+    let err = ctx.verify_tx(tx, MAX_CYCLES).unwrap_err().to_string();
+    assert!(
+        err.contains(&format!("see error code {} on page", code)),
+        "Expected error code {}, got {}",
+        code,
+        err
+    );
+}
+
+/// Verify a transaction which must be rejected by a script failing with `error`
+fn assert_vote_error(ctx: &Context, tx: &TransactionView, error: VoteError) {
+    assert_exit_code(ctx, tx, error.code());
+}
+
+/// Append another vote to a vote transaction, with its witness at the same index as the vote cell
+fn append_vote(
+    tx: TransactionView,
+    (vote_output, vote_cell_data): (CellOutput, Bytes),
+    vote_witness_data: Bytes,
+) -> TransactionView {
+    let mut witnesses = tx.witnesses().into_iter().collect::<Vec<_>>();
+    witnesses.resize(tx.outputs().len(), Bytes::new().pack());
+    witnesses.push(
+        WitnessArgs::new_builder()
+            .output_type(Some(vote_witness_data).pack())
+            .build()
+            .as_bytes()
+            .pack(),
+    );
+    tx.as_advanced_builder()
+        .output(vote_output)
+        .output_data(vote_cell_data.pack())
+        .set_witnesses(witnesses)
+        .build()
+}
+
+/// Build a transaction creating public key registry cells of `outputs_data` for the election of
+/// `state`, paid by a cell locked by `lock`
+fn build_public_key_registry_tx<S: RingSignatureScheme>(
+    ctx: &mut Context,
+    state: &PreparedState<S>,
+    script_out_point: &OutPoint,
+    lock: Script,
+    outputs_data: Vec<Bytes>,
+) -> TransactionView {
+    let input = ctx.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let type_script = ctx
+        .build_script(
+            script_out_point,
+            public_key_registry_script_args(&state.config_type_hash).into(),
+        )
+        .unwrap();
+    let outputs = outputs_data
+        .iter()
+        .map(|data| {
+            CellOutput::new_builder()
+                .capacity((data.len() as u64).pack())
+                .lock(lock.clone())
+                .type_(Some(type_script.clone()).pack())
+                .build()
+        })
+        .collect::<Vec<_>>();
+    let tx = TransactionBuilder::default()
+        .cell_dep(
+            CellDep::new_builder()
+                .out_point(state.config_cell.clone())
+                .build(),
+        )
+        .input(CellInput::new_builder().previous_output(input).build())
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .build();
+    ctx.complete_tx(tx)
+}
+
+#[test]
+fn test_verify_signature() {
+    let mut rng = rand::thread_rng();
+    let mut ctx = Context::default();
+    let loader = Loader::default();
+    let verifier_bin = loader.load_binary("ring-signature-verify");
+    let script_out_point = ctx.deploy_cell(verifier_bin);
+    let keys = (0..KEY_COUNT)
+        .map(|_| LsagPrivateKey::random(&mut rng))
+        .collect::<Vec<_>>();
+    let state = prepare(&mut ctx, &script_out_point, LsagScheme, keys, CHUNK_SIZE);
+    let signer = rng.gen_range(0usize..state.keys.len());
+    let signer_block = signer / CHUNK_SIZE;
+    let signer_index = signer % CHUNK_SIZE;
+    let ring_keys = &state.public_keys[ring_range(state.keys.len(), CHUNK_SIZE, signer_block)];
+    let selected_candidate = state.candidates.choose(&mut rng).unwrap();
+    let signature: RingSignature = state
+        .scheme
+        .sign(
+            ring_keys,
+            &state.keys[signer],
+            signer_index,
+            &state.config_type_hash,
+            &state.ballot(&selected_candidate.id),
+        )
+        .unwrap()
+        .into();
+    let MerkleProofResult {
+        proof,
+        leaf_hash: _,
+    } = create_merkle_tree_with_proof(&state.scheme, &state.public_keys, CHUNK_SIZE, signer_block)
+        .unwrap();
+
+    let (cell_deps, tx_input, type_script) = prepare_vote_tx(
+        &mut ctx,
+        &state.config_cell,
+        &state.config_type_hash,
+        &script_out_point,
+    );
+    let vote_output = |cell_data: &[u8]| {
+        CellOutput::new_builder()
+            .capacity((cell_data.len() as u64).pack())
+            .type_(
+                ScriptOpt::new_builder()
+                    .set(Some(type_script.clone()))
+                    .build(),
+            )
+            .build()
+    };
+    let vote_cell_data = encode_vote_cell(&selected_candidate.id, &signature);
+    let witness_data = encode_vote_witness(&signature, signer_block, &proof);
+    let mut registry = KeyImageRegistry::default();
+    let registry_update = registry
+        .insert_images(&[&image_of(&vote_cell_data)])
+        .unwrap();
+    let tx = build_vote_tx(
+        &mut ctx,
+        &cell_deps,
+        &tx_input,
+        &state.key_image_registry_cell,
+        &registry_update,
         (
-            vec![CellOutput::new_builder()
-                .capacity((cell_data.len() as u64).pack())
-                .type_(ScriptOpt::new_builder().set(Some(type_script)).build())
-                .build()],
-            vec![Bytes::from(cell_data)],
+            vote_output(&vote_cell_data),
+            Bytes::from(vote_cell_data.clone()),
+        ),
+        witness_data.clone(),
+    );
+    let cycles = ctx.verify_tx(&tx, MAX_CYCLES).unwrap();
+    println!("Cycles: {}", cycles);
+    // Test batching votes of two users into one transaction
+    let (other_vote_cell_data, other_witness_data) = {
+        let other_signer = (signer + CHUNK_SIZE) % state.keys.len();
+        let other_block = other_signer / CHUNK_SIZE;
+        let other_ring_keys =
+            &state.public_keys[ring_range(state.keys.len(), CHUNK_SIZE, other_block)];
+        let signature: RingSignature = state
+            .scheme
+            .sign(
+                other_ring_keys,
+                &state.keys[other_signer],
+                other_signer % CHUNK_SIZE,
+                &state.config_type_hash,
+                &state.ballot(&selected_candidate.id),
+            )
+            .unwrap()
+            .into();
+        let MerkleProofResult {
+            proof,
+            leaf_hash: _,
+        } = create_merkle_tree_with_proof(
+            &state.scheme,
+            &state.public_keys,
+            CHUNK_SIZE,
+            other_block,
+        )
+        .unwrap();
+        (
+            encode_vote_cell(&selected_candidate.id, &signature),
+            encode_vote_witness(&signature, other_block, &proof),
         )
     };
-    let witness = {
-        let mut witness_data = vec![0u8; 0];
-        check_size_and_write(&mut witness_data, &signature.c, 256).unwrap();
-        witness_data
-            .write_all(&(signature.r_and_pubkey.len() as u32).to_le_bytes())
-            .unwrap();
-        for item in signature.r_and_pubkey.iter() {
-            check_size_and_write(&mut witness_data, &item.r, 256).unwrap();
-        }
-        for item in signature.r_and_pubkey.iter() {
-            check_size_and_write(&mut witness_data, &item.n, 256).unwrap();
-        }
-        for item in signature.r_and_pubkey.iter() {
-            check_size_and_write(&mut witness_data, &item.e, 4).unwrap();
-        }
-        witness_data
-            .write_all(&(signer_block as u32).to_le_bytes())
-            .unwrap();
-        witness_data
-            .write_all(&(proof.len() as u32).to_le_bytes())
-            .unwrap();
-        witness_data.write_all(&proof).unwrap();
-        vec![WitnessArgs::new_builder()
-            .output_type(Some(Bytes::from(witness_data)).pack())
-            .lock(Option::<Bytes>::None.pack())
-            .input_type(Option::<Bytes>::None.pack())
+    let batch_registry_update = KeyImageRegistry::default()
+        .insert_images(&[&image_of(&vote_cell_data), &image_of(&other_vote_cell_data)])
+        .unwrap();
+    let tx = {
+        let tx = build_vote_tx(
+            &mut ctx,
+            &cell_deps,
+            &tx_input,
+            &state.key_image_registry_cell,
+            &batch_registry_update,
+            (
+                vote_output(&vote_cell_data),
+                Bytes::from(vote_cell_data.clone()),
+            ),
+            witness_data.clone(),
+        );
+        append_vote(
+            tx,
+            (
+                vote_output(&other_vote_cell_data),
+                Bytes::from(other_vote_cell_data.clone()),
+            ),
+            other_witness_data.clone(),
+        )
+    };
+    let cycles = ctx.verify_tx(&tx, MAX_CYCLES).unwrap();
+    println!("Cycles of two votes: {}", cycles);
+    // Every vote in the batch is verified, not only the first one. The second carries the image
+    // of another key
+    let bad_other_vote_cell_data = {
+        let mut vote_cell = VoteCell::decode(&other_vote_cell_data, SCHEME).unwrap();
+        vote_cell.image = state.keys[(signer + 1) % state.keys.len()]
+            .key_image(&state.config_type_hash)
+            .to_vec();
+        vote_cell.encode()
+    };
+    let bad_batch_registry_update = KeyImageRegistry::default()
+        .insert_images(&[
+            &image_of(&vote_cell_data),
+            &image_of(&bad_other_vote_cell_data),
+        ])
+        .unwrap();
+    let tx = {
+        let tx = build_vote_tx(
+            &mut ctx,
+            &cell_deps,
+            &tx_input,
+            &state.key_image_registry_cell,
+            &bad_batch_registry_update,
+            (
+                vote_output(&vote_cell_data),
+                Bytes::from(vote_cell_data.clone()),
+            ),
+            witness_data.clone(),
+        );
+        append_vote(
+            tx,
+            (
+                vote_output(&bad_other_vote_cell_data),
+                Bytes::from(bad_other_vote_cell_data),
+            ),
+            other_witness_data.clone(),
+        )
+    };
+    assert_vote_error(&ctx, &tx, VoteError::BadSignature);
+    // Test a candidate which is not of the election
+    let bad_vote_cell_data = {
+        // Create an invalid signature
+        let mut candidate_id = selected_candidate.id;
+        candidate_id[0] ^= 1;
+        encode_vote_cell(&candidate_id, &signature)
+    };
+    let tx = build_vote_tx(
+        &mut ctx,
+        &cell_deps,
+        &tx_input,
+        &state.key_image_registry_cell,
+        &registry_update,
+        (
+            vote_output(&bad_vote_cell_data),
+            Bytes::from(bad_vote_cell_data),
+        ),
+        witness_data.clone(),
+    );
+    assert_vote_error(&ctx, &tx, VoteError::BadCandidateId);
+    // Test voting before and after the voting period
+    for (since, error) in [
+        (0, VoteError::VotingNotStarted),
+        (VOTING_START - 1, VoteError::VotingNotStarted),
+        (VOTING_END, VoteError::VotingEnded),
+    ] {
+        let tx = build_vote_tx(
+            &mut ctx,
+            &cell_deps,
+            &tx_input.clone().as_builder().since(since.pack()).build(),
+            &state.key_image_registry_cell,
+            &registry_update,
+            (
+                vote_output(&vote_cell_data),
+                Bytes::from(vote_cell_data.clone()),
+            ),
+            witness_data.clone(),
+        );
+        assert_vote_error(&ctx, &tx, error);
+    }
+    // Header deps also prove that the voting period has started
+    let header = HeaderBuilder::default()
+        .number(((VOTING_START + VOTING_END) / 2).pack())
+        .build();
+    ctx.insert_header(header.clone());
+    let tx = build_vote_tx(
+        &mut ctx,
+        &cell_deps,
+        &tx_input.clone().as_builder().since(0u64.pack()).build(),
+        &state.key_image_registry_cell,
+        &registry_update,
+        (
+            vote_output(&vote_cell_data),
+            Bytes::from(vote_cell_data.clone()),
+        ),
+        witness_data.clone(),
+    )
+    .as_advanced_builder()
+    .header_dep(header.hash())
+    .build();
+    ctx.verify_tx(&tx, MAX_CYCLES).unwrap();
+    // Test with a copy of the config cell, which is not the one committed to by args
+    let forged_config_cell = {
+        let (config_output, config_data) = ctx.get_cell(&state.config_cell).unwrap();
+        let forged_type_script = config_output
+            .type_()
+            .to_opt()
+            .unwrap()
+            .as_builder()
+            .args(Bytes::copy_from_slice(&rng.gen::<[u8; 32]>()).pack())
+            .build();
+        ctx.create_cell(
+            config_output
+                .as_builder()
+                .type_(Some(forged_type_script).pack())
+                .build(),
+            config_data,
+        )
+    };
+    let forged_cell_deps = cell_deps
+        .iter()
+        .map(|cell_dep| {
+            if cell_dep.out_point() == state.config_cell {
+                cell_dep
+                    .clone()
+                    .as_builder()
+                    .out_point(forged_config_cell.clone())
+                    .build()
+            } else {
+                cell_dep.clone()
+            }
+        })
+        .collect::<Vec<_>>();
+    let tx = build_vote_tx(
+        &mut ctx,
+        &forged_cell_deps,
+        &tx_input,
+        &state.key_image_registry_cell,
+        &registry_update,
+        (
+            vote_output(&vote_cell_data),
+            Bytes::from(vote_cell_data.clone()),
+        ),
+        witness_data.clone(),
+    );
+    assert_vote_error(&ctx, &tx, VoteError::MissingVoteConfig);
+    // Test with args of another election, whose config is also in cell deps
+    // Copy of the election with the type id `type_id`, and another merkle root if any
+    let copy_election = |ctx: &mut Context, type_id: [u8; 32], merkle_root: Option<[u8; 32]>| {
+        let (config_output, config_data) = ctx.get_cell(&state.config_cell).unwrap();
+        let mut config = VoteConfig::decode(&config_data).unwrap();
+        config.merkle_root = merkle_root.unwrap_or(config.merkle_root);
+        let type_script = config_output
+            .type_()
+            .to_opt()
+            .unwrap()
+            .as_builder()
+            .args(Bytes::copy_from_slice(&type_id).pack())
+            .build();
+        let type_hash: [u8; 32] = type_script.calc_script_hash().unpack();
+        let out_point = ctx.create_cell(
+            config_output
+                .as_builder()
+                .type_(Some(type_script).pack())
+                .build(),
+            config.encode().into(),
+        );
+        (out_point, type_hash)
+    };
+    let another_election = copy_election(&mut ctx, rng.gen(), Some(rng.gen()));
+    // Or of the same keys, where the key image of the voter is another one
+    let same_keys_election = copy_election(&mut ctx, rng.gen(), None);
+    let with_cell_dep = |out_point: &OutPoint| {
+        let mut cell_deps = cell_deps.clone();
+        cell_deps.push(CellDep::new_builder().out_point(out_point.clone()).build());
+        cell_deps
+    };
+    let both_cell_deps = with_cell_dep(&another_election.0);
+    let vote_output_of = |config_type_hash: &[u8; 32]| {
+        let type_script = type_script
+            .clone()
+            .as_builder()
+            .args(Bytes::from(vote_script_args(config_type_hash)).pack())
+            .build();
+        vote_output(&vote_cell_data)
+            .as_builder()
+            .type_(Some(type_script).pack())
             .build()
-            .as_bytes()
-            .pack()]
     };
+    for (config_type_hash, cell_deps, error) in [
+        // Keys of this election are not in the merkle tree of the other one
+        (
+            another_election.1,
+            &both_cell_deps,
+            VoteError::BadMerkleProof,
+        ),
+        // The election of the args is not in cell deps at all
+        (another_election.1, &cell_deps, VoteError::MissingVoteConfig),
+        (
+            same_keys_election.1,
+            &with_cell_dep(&same_keys_election.0),
+            VoteError::BadSignature,
+        ),
+    ] {
+        let tx = build_vote_tx(
+            &mut ctx,
+            cell_deps,
+            &tx_input,
+            &state.key_image_registry_cell,
+            &registry_update,
+            (
+                vote_output_of(&config_type_hash),
+                Bytes::from(vote_cell_data.clone()),
+            ),
+            witness_data.clone(),
+        );
+        assert_vote_error(&ctx, &tx, error);
+    }
+    // The extra config doesn't affect the vote of its own election
+    let tx = build_vote_tx(
+        &mut ctx,
+        &both_cell_deps,
+        &tx_input,
+        &state.key_image_registry_cell,
+        &registry_update,
+        (
+            vote_output_of(&state.config_type_hash),
+            Bytes::from(vote_cell_data.clone()),
+        ),
+        witness_data.clone(),
+    );
+    ctx.verify_tx(&tx, MAX_CYCLES).unwrap();
+    // Test skipping the key image registry
     let tx = {
         let tx = TransactionBuilder::default()
             .cell_deps(cell_deps.clone())
-            .inputs(tx_input.clone())
-            .outputs(tx_output.clone())
-            .outputs_data(tx_output_data.pack())
-            .witnesses(witness.clone())
+            .input(tx_input.clone())
+            .output(vote_output(&vote_cell_data))
+            .output_data(Bytes::from(vote_cell_data.clone()).pack())
+            .witness(
+                WitnessArgs::new_builder()
+                    .output_type(Some(witness_data.clone()).pack())
+                    .build()
+                    .as_bytes()
+                    .pack(),
+            )
             .build();
         tx.as_advanced_builder().build()
     };
-    let cycles = ctx.verify_tx(&tx, MAX_CYCLES).unwrap();
-    println!("Cycles: {}", cycles);
-    // Test bad signature
-    let (tx_output, tx_output_data) = {
-        let mut cell_data = vec![0u8; 0];
-        cell_data.write_all(&selected_candidate.id).unwrap();
-        check_size_and_write(&mut cell_data, &signature.i, 256).unwrap();
-        // Create an invalid signature
-        cell_data[0] ^= 1;
+    assert_vote_error(&ctx, &tx, VoteError::MissingKeyImageRegistry);
+
+    // Test voting twice with the same key, after the first vote was accepted
+    let updated_registry_cell = {
+        let (registry_output, _) = ctx.get_cell(&state.key_image_registry_cell).unwrap();
+        ctx.create_cell(
+            registry_output,
+            Bytes::copy_from_slice(&registry_update.new_root),
+        )
+    };
+    let another_candidate = state
+        .candidates
+        .iter()
+        .find(|c| c.id != selected_candidate.id)
+        .unwrap();
+    let signature: RingSignature = state
+        .scheme
+        .sign(
+            ring_keys,
+            &state.keys[signer],
+            signer_index,
+            &state.config_type_hash,
+            &state.ballot(&another_candidate.id),
+        )
+        .unwrap()
+        .into();
+    let second_vote_cell_data = encode_vote_cell(&another_candidate.id, &signature);
+    let second_witness_data = encode_vote_witness(&signature, signer_block, &proof);
+    // The image is the same, so the registry can't prove it was unused
+    assert!(registry
+        .insert_images(&[&image_of(&second_vote_cell_data)])
+        .is_err());
+    let forged_registry_update = KeyImageRegistry::default()
+        .insert_images(&[&image_of(&second_vote_cell_data)])
+        .unwrap();
+    // It would be accepted if it were the first vote
+    let tx = build_vote_tx(
+        &mut ctx,
+        &cell_deps,
+        &tx_input,
+        &state.key_image_registry_cell,
+        &forged_registry_update,
+        (
+            vote_output(&second_vote_cell_data),
+            Bytes::from(second_vote_cell_data.clone()),
+        ),
+        second_witness_data.clone(),
+    );
+    ctx.verify_tx(&tx, MAX_CYCLES).unwrap();
+    let tx = build_vote_tx(
+        &mut ctx,
+        &cell_deps,
+        &tx_input,
+        &updated_registry_cell,
+        &forged_registry_update,
+        (
+            vote_output(&second_vote_cell_data),
+            Bytes::from(second_vote_cell_data),
+        ),
+        second_witness_data,
+    );
+    assert_vote_error(&ctx, &tx, VoteError::KeyImageAlreadyUsed);
+}
+
+/// Votes of RSA elections are refused. The ring signature only checks that the key image is a
+/// multiple of a prime `p` of the signer, so `p` and `2p` both verify, and the registry takes
+/// them for two keys
+#[test]
+fn test_rsa_election_refused() {
+    let mut rng = rand::thread_rng();
+    let mut ctx = Context::default();
+    let loader = Loader::default();
+    let verifier_bin = loader.load_binary("ring-signature-verify");
+    let script_out_point = ctx.deploy_cell(verifier_bin);
+    let key_size = RsaKeySize::Rsa1024;
+    let state = prepare(
+        &mut ctx,
+        &script_out_point,
+        RsaScheme(key_size),
+        generate_keys(key_size, 3),
+        3,
+    );
+    let (cell_deps, tx_input, type_script) = prepare_vote_tx(
+        &mut ctx,
+        &state.config_cell,
+        &state.config_type_hash,
+        &script_out_point,
+    );
+    let signer = 1;
+    let candidate_id = state.candidates[0].id;
+    let (_, witness) = sign_vote(&state, signer, &candidate_id);
+    let p = &state.keys[signer].primes()[0];
+    let [first_vote, second_vote] = [p.clone(), p.clone() * 2u32].map(|image| {
+        let signature = create_signature_with_image(
+            &state.public_keys,
+            &state.keys[signer],
+            signer,
+            &state.config_type_hash,
+            &state.ballot(&candidate_id),
+            image,
+            &mut rng,
+        )
+        .unwrap();
+        assert!(verify_signature(
+            &state.public_keys,
+            &state.config_type_hash,
+            &state.ballot(&candidate_id),
+            &signature
+        )
+        .unwrap());
+        let signature = RingSignature::Rsa(signature);
+        (
+            VoteCell::new(candidate_id, &signature).unwrap(),
+            VoteWitness::new(&signature, 0, witness.merkle_proof.clone()),
+        )
+    });
+    assert_ne!(first_vote.0.image, second_vote.0.image);
+    let vote_tx = |ctx: &mut Context,
+                   registry_cell: &OutPoint,
+                   registry_update: &KeyImageRegistryUpdate,
+                   (vote_cell, witness): &(VoteCell, VoteWitness)| {
+        let vote_cell_data = Bytes::from(vote_cell.encode());
+        let vote_output = CellOutput::new_builder()
+            .capacity((vote_cell_data.len() as u64).pack())
+            .type_(Some(type_script.clone()).pack())
+            .build();
+        build_vote_tx(
+            ctx,
+            &cell_deps,
+            &tx_input,
+            registry_cell,
+            registry_update,
+            (vote_output, vote_cell_data),
+            witness
+                .encode(SignatureScheme::Rsa(key_size))
+                .unwrap()
+                .into(),
+        )
+    };
+    let mut registry = KeyImageRegistry::default();
+    let registry_update = registry.insert_images(&[&first_vote.0.image]).unwrap();
+    let tx = vote_tx(
+        &mut ctx,
+        &state.key_image_registry_cell,
+        &registry_update,
+        &first_vote,
+    );
+    assert_vote_error(&ctx, &tx, VoteError::UnsupportedSignatureScheme);
+    // Even after the first vote, the registry has no image `2p`, so only the vote script
+    // rejects the second one
+    let updated_registry_cell = {
+        let (registry_output, _) = ctx.get_cell(&state.key_image_registry_cell).unwrap();
+        ctx.create_cell(
+            registry_output,
+            Bytes::copy_from_slice(&registry_update.new_root),
+        )
+    };
+    let registry_update = registry.insert_images(&[&second_vote.0.image]).unwrap();
+    let tx = vote_tx(
+        &mut ctx,
+        &updated_registry_cell,
+        &registry_update,
+        &second_vote,
+    );
+    assert_vote_error(&ctx, &tx, VoteError::UnsupportedSignatureScheme);
+}
+
+#[test]
+fn test_verify_lsag_signature() {
+    let mut rng = rand::thread_rng();
+    let mut ctx = Context::default();
+    let loader = Loader::default();
+    let verifier_bin = loader.load_binary("ring-signature-verify");
+    let script_out_point = ctx.deploy_cell(verifier_bin);
+    let keys = (0..CHUNK_SIZE * 3 + 2)
+        .map(|_| LsagPrivateKey::random(&mut rng))
+        .collect::<Vec<_>>();
+    let state = prepare(&mut ctx, &script_out_point, LsagScheme, keys, CHUNK_SIZE);
+    let (cell_deps, tx_input, type_script) = prepare_vote_tx(
+        &mut ctx,
+        &state.config_cell,
+        &state.config_type_hash,
+        &script_out_point,
+    );
+    let build_tx = |ctx: &mut Context, vote_cell: &VoteCell, witness_data: Vec<u8>| {
+        let registry_update = KeyImageRegistry::default()
+            .insert_images(&[&vote_cell.image])
+            .unwrap();
+        let vote_cell_data = Bytes::from(vote_cell.encode());
+        let vote_output = CellOutput::new_builder()
+            .capacity((vote_cell_data.len() as u64).pack())
+            .type_(Some(type_script.clone()).pack())
+            .build();
+        build_vote_tx(
+            ctx,
+            &cell_deps,
+            &tx_input,
+            &state.key_image_registry_cell,
+            &registry_update,
+            (vote_output, vote_cell_data),
+            witness_data.into(),
+        )
+    };
+    let selected_candidate = state.candidates.choose(&mut rng).unwrap();
+    // A full ring, and the last ring which has 2 members
+    for signer in [rng.gen_range(0..CHUNK_SIZE * 3), state.keys.len() - 1] {
+        let (vote_cell, witness) = sign_vote(&state, signer, &selected_candidate.id);
+        assert_eq!(
+            vote_cell.image,
+            state.keys[signer].key_image(&state.config_type_hash)
+        );
+        let witness_data = witness.encode(SignatureScheme::Lsag).unwrap();
+        let tx = build_tx(&mut ctx, &vote_cell, witness_data.clone());
+        let cycles = ctx.verify_tx(&tx, MAX_CYCLES).unwrap();
+        println!(
+            "Cycles of LSAG over {} keys: {}",
+            witness.signature.ring_size(),
+            cycles
+        );
+
+        // The image of another key doesn't verify
+        let mut other_image = vote_cell.clone();
+        other_image.image = state.keys[(signer + 1) % state.keys.len()]
+            .key_image(&state.config_type_hash)
+            .to_vec();
+        let tx = build_tx(&mut ctx, &other_image, witness_data.clone());
+        assert_vote_error(&ctx, &tx, VoteError::BadSignature);
+        // Nor an image which is not a valid point
+        let mut bad_point = vote_cell.clone();
+        bad_point.image = vec![0xff; 32];
+        let tx = build_tx(&mut ctx, &bad_point, witness_data.clone());
+        assert_vote_error(&ctx, &tx, VoteError::BadPoint);
+        let mut other_candidate = vote_cell.clone();
+        other_candidate.candidate_id = state
+            .candidates
+            .iter()
+            .find(|c| c.id != selected_candidate.id)
+            .unwrap()
+            .id;
+        let tx = build_tx(&mut ctx, &other_candidate, witness_data);
+        assert_vote_error(&ctx, &tx, VoteError::BadSignature);
+    }
+    // RSA witnesses are not accepted by an LSAG election
+    let rsa_state = prepare(
+        &mut ctx,
+        &script_out_point,
+        RsaScheme(RsaKeySize::Rsa1024),
+        generate_keys(RsaKeySize::Rsa1024, 2),
+        2,
+    );
+    let (_, rsa_witness) = sign_vote(&rsa_state, 0, &selected_candidate.id);
+    let (vote_cell, _) = sign_vote(&state, 0, &selected_candidate.id);
+    let rsa_witness_data = rsa_witness
+        .encode(rsa_state.scheme.signature_scheme())
+        .unwrap();
+    let tx = build_tx(&mut ctx, &vote_cell, rsa_witness_data);
+    assert_vote_error(&ctx, &tx, VoteError::SignatureSchemeMismatch);
+}
+
+/// The key image registry only inserts images of votes typed by its own code hash and hash type,
+/// so a vote of the same binary under another hash type must be rejected by the vote script, or a
+/// key could vote again next to a vote of another key
+#[test]
+fn test_vote_with_other_hash_type() {
+    let mut rng = rand::thread_rng();
+    let mut ctx = Context::default();
+    let loader = Loader::default();
+    let verifier_bin = loader.load_binary("ring-signature-verify");
+    let script_out_point = ctx.deploy_cell(verifier_bin);
+    let keys = (0..3)
+        .map(|_| LsagPrivateKey::random(&mut rng))
+        .collect::<Vec<_>>();
+    let mut state = prepare(&mut ctx, &script_out_point, LsagScheme, keys, 3);
+    let (cell_deps, tx_input, type_script) = prepare_vote_tx(
+        &mut ctx,
+        &state.config_cell,
+        &state.config_type_hash,
+        &script_out_point,
+    );
+    let vote = |state: &PreparedState, type_script: &Script, signer: usize| {
+        let (vote_cell, witness) = sign_vote(state, signer, &state.candidates[0].id);
+        let vote_cell_data = Bytes::from(vote_cell.encode());
+        let vote_output = CellOutput::new_builder()
+            .capacity((vote_cell_data.len() as u64).pack())
+            .type_(Some(type_script.clone()).pack())
+            .build();
+        let witness_data = Bytes::from(witness.encode(SignatureScheme::Lsag).unwrap());
+        (vote_cell.image, (vote_output, vote_cell_data), witness_data)
+    };
+
+    let mut registry = KeyImageRegistry::default();
+    let (first_image, first_vote, first_witness_data) = vote(&state, &type_script, 0);
+    let registry_update = registry.insert_images(&[&first_image]).unwrap();
+    let tx = build_vote_tx(
+        &mut ctx,
+        &cell_deps,
+        &tx_input,
+        &state.key_image_registry_cell,
+        &registry_update,
+        first_vote,
+        first_witness_data,
+    );
+    ctx.verify_tx(&tx, MAX_CYCLES).unwrap();
+    let updated_registry_cell = {
+        let (registry_output, _) = ctx.get_cell(&state.key_image_registry_cell).unwrap();
+        ctx.create_cell(
+            registry_output,
+            Bytes::copy_from_slice(&registry_update.new_root),
+        )
+    };
+
+    let (second_image, second_vote, second_witness_data) = vote(&state, &type_script, 1);
+    let registry_update = registry.insert_images(&[&second_image]).unwrap();
+    let tx = build_vote_tx(
+        &mut ctx,
+        &cell_deps,
+        &tx_input,
+        &updated_registry_cell,
+        &registry_update,
+        second_vote,
+        second_witness_data,
+    );
+    ctx.verify_tx(&tx, MAX_CYCLES).unwrap();
+    // The registry is typed by the type hash of the contract cell, the same binary is also
+    // reachable by its data hash
+    let data2_script = ctx
+        .build_script_with_hash_type(
+            &script_out_point,
+            ScriptHashType::Data2,
+            vote_script_args(&state.config_type_hash).into(),
+        )
+        .unwrap();
+    state.contract_code_hash = data2_script.code_hash().unpack();
+    let (_, duplicate_vote, duplicate_witness_data) = vote(&state, &data2_script, 0);
+    let tx = append_vote(tx, duplicate_vote, duplicate_witness_data);
+    assert_vote_error(&ctx, &tx, VoteError::KeyImageRegistryScriptMismatch);
+}
+
+#[test]
+fn test_public_key_registry() {
+    let mut rng = rand::thread_rng();
+    let mut ctx = Context::default();
+    let loader = Loader::default();
+    let verifier_bin = loader.load_binary("ring-signature-verify");
+    let script_out_point = ctx.deploy_cell(verifier_bin);
+    let keys = (0..CHUNK_SIZE * 3 + 2)
+        .map(|_| LsagPrivateKey::random(&mut rng))
+        .collect::<Vec<_>>();
+    let state = prepare(&mut ctx, &script_out_point, LsagScheme, keys, CHUNK_SIZE);
+    let always_success_script_op = ctx.deploy_cell(ALWAYS_SUCCESS.clone());
+    let admin_lock = ctx
+        .build_script(&always_success_script_op, Default::default())
+        .unwrap();
+    let cells = public_key_registry_cells(&state.public_keys, CHUNK_SIZE, 2)
+        .unwrap()
+        .iter()
+        .map(|cell| Bytes::from(cell.encode(&state.scheme).unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(cells.len(), 2);
+    let tx = build_public_key_registry_tx(
+        &mut ctx,
+        &state,
+        &script_out_point,
+        admin_lock.clone(),
+        cells.clone(),
+    );
+    ctx.verify_tx(&tx, MAX_CYCLES).unwrap();
+    // Rings are rebuilt from the published cells alone
+    let config = VoteConfig::decode(&ctx.get_cell(&state.config_cell).unwrap().1).unwrap();
+    let published = tx
+        .outputs_data()
+        .into_iter()
+        .map(|data| data.raw_data())
+        .collect::<Vec<_>>();
+    let rings = rebuild_rings(
+        &state.scheme,
+        &config,
+        published.iter().map(|data| &data[..]),
+    )
+    .unwrap();
+    assert_eq!(rings.len(), 4);
+    assert_eq!(rings[3].public_keys, state.public_keys[CHUNK_SIZE * 3..]);
+
+    // Only the administrator publishes keys
+    let other_lock = ctx
+        .build_script(&always_success_script_op, Bytes::from_static(b"voter"))
+        .unwrap();
+    let tx = build_public_key_registry_tx(
+        &mut ctx,
+        &state,
+        &script_out_point,
+        other_lock,
+        cells.clone(),
+    );
+    assert_vote_error(&ctx, &tx, VoteError::UnauthorizedPublicKeyRegistry);
+
+    // Keys must be of the scheme of the election
+    let empty = PublicKeyRegistryCell {
+        start: 0,
+        public_keys: vec![],
+    }
+    .encode(&state.scheme)
+    .unwrap();
+    let rsa_scheme = RsaScheme(RsaKeySize::Rsa1024);
+    let rsa_key = rsa_scheme.public_key(&generate_keys(RsaKeySize::Rsa1024, 1)[0]);
+    let rsa_cell = PublicKeyRegistryCell {
+        start: 0,
+        public_keys: vec![rsa_key],
+    }
+    .encode(&rsa_scheme)
+    .unwrap();
+    for data in [empty, rsa_cell, cells[0][1..].to_vec()] {
+        let tx = build_public_key_registry_tx(
+            &mut ctx,
+            &state,
+            &script_out_point,
+            admin_lock.clone(),
+            vec![cells[1].clone(), data.into()],
+        );
+        assert_vote_error(&ctx, &tx, VoteError::BadPublicKeyRegistry);
+    }
+}
+
+/// Votes which reference the registry cell of their ring as a cell dep, instead of carrying the
+/// keys in the witness
+#[test]
+fn test_ring_in_registry_cell() {
+    let mut ctx = Context::default();
+    let loader = Loader::default();
+    let verifier_bin = loader.load_binary("ring-signature-verify");
+    let script_out_point = ctx.deploy_cell(verifier_bin);
+    let keys = (0..CHUNK_SIZE * 2 + 1)
+        .map(|_| LsagPrivateKey::random(&mut rand::thread_rng()))
+        .collect::<Vec<_>>();
+    let state = prepare(&mut ctx, &script_out_point, LsagScheme, keys, CHUNK_SIZE);
+    // A ring in each cell, the keys don't need to be typed by the contract
+    let registry_cells = public_key_registry_cells(&state.public_keys, CHUNK_SIZE, 1)
+        .unwrap()
+        .iter()
+        .map(|cell| ctx.deploy_cell(cell.encode(&state.scheme).unwrap().into()))
+        .collect::<Vec<_>>();
+    let (vote_cell, witness) = sign_vote(&state, CHUNK_SIZE + 3, &state.candidates[0].id);
+    let vote_tx = |ctx: &mut Context, ring_cell: &OutPoint, witness_data: Vec<u8>| {
+        let (mut cell_deps, tx_input, type_script) = prepare_vote_tx(
+            ctx,
+            &state.config_cell,
+            &state.config_type_hash,
+            &script_out_point,
+        );
+        cell_deps.push(CellDep::new_builder().out_point(ring_cell.clone()).build());
+        let registry_update = KeyImageRegistry::default()
+            .insert_images(&[&vote_cell.image])
+            .unwrap();
+        let vote_cell_data = Bytes::from(vote_cell.encode());
+        let vote_output = CellOutput::new_builder()
+            .capacity((vote_cell_data.len() as u64).pack())
+            .type_(Some(type_script).pack())
+            .build();
+        build_vote_tx(
+            ctx,
+            &cell_deps,
+            &tx_input,
+            &state.key_image_registry_cell,
+            &registry_update,
+            (vote_output, vote_cell_data),
+            witness_data.into(),
+        )
+    };
+    // The cell dep after the ones of `prepare_vote_tx`
+    let with_ring_cell_dep = |index: u32| {
+        VoteWitness {
+            ring_cell_dep: Some(index),
+            ..witness.clone()
+        }
+        .encode(SCHEME)
+        .unwrap()
+    };
+    let inline_data = witness.encode(SCHEME).unwrap();
+    let referenced_data = with_ring_cell_dep(3);
+    let tx = vote_tx(&mut ctx, &registry_cells[1], inline_data.clone());
+    let inline_cycles = ctx.verify_tx(&tx, MAX_CYCLES).unwrap();
+    let tx = vote_tx(&mut ctx, &registry_cells[1], referenced_data.clone());
+    let referenced_cycles = ctx.verify_tx(&tx, MAX_CYCLES).unwrap();
+    println!(
+        "witness of {} bytes and {} cycles with keys, {} bytes and {} cycles with a registry cell",
+        inline_data.len(),
+        inline_cycles,
+        referenced_data.len(),
+        referenced_cycles
+    );
+    // Every byte of the keys is saved, less the index of the cell dep
+    assert!(referenced_data.len() + CHUNK_SIZE * SCHEME.public_key_size() <= inline_data.len() + 4);
+    // The witness is decoded with the cell dep off-chain as well
+    let ring_cell_data = ctx.get_cell(&registry_cells[1]).unwrap().1;
+    let config = VoteConfig::decode(&ctx.get_cell(&state.config_cell).unwrap().1).unwrap();
+    assert_eq!(
+        VoteWitness::decode_with_cell_deps(&referenced_data, &config, |index| {
+            assert_eq!(index, 3);
+            Ok(ring_cell_data.to_vec())
+        })
+        .unwrap()
+        .signature,
+        witness.signature
+    );
+
+    // Other keys at the offset of the ring
+    let mut forged_cell = PublicKeyRegistryCell::decode(&ring_cell_data, &state.scheme).unwrap();
+    forged_cell.public_keys.swap(0, 1);
+    let forged_cell = ctx.deploy_cell(forged_cell.encode(&state.scheme).unwrap().into());
+    let keys_in_both = schema::VoteWitness::from_slice(&inline_data)
+        .unwrap()
+        .as_builder()
+        .ring_cell_dep(
+            schema::Uint32Opt::new_builder()
+                .set(Some(3u32.into()))
+                .build(),
+        )
+        .build()
+        .as_slice()
+        .to_vec();
+    for (ring_cell, witness_data, error) in [
+        (
+            &registry_cells[1],
+            with_ring_cell_dep(4),
+            VoteError::IndexOutOfBound,
+        ),
+        // The config cell
+        (
+            &registry_cells[1],
+            with_ring_cell_dep(0),
+            VoteError::BadPublicKeyRegistry,
+        ),
+        (
+            &registry_cells[0],
+            referenced_data.clone(),
+            VoteError::RingNotInRegistry,
+        ),
+        (
+            &registry_cells[2],
+            referenced_data.clone(),
+            VoteError::RingNotInRegistry,
+        ),
+        (
+            &forged_cell,
+            referenced_data.clone(),
+            VoteError::BadMerkleProof,
+        ),
+        (
+            &registry_cells[1],
+            keys_in_both,
+            VoteError::RingKeysInWitness,
+        ),
+    ] {
+        let tx = vote_tx(&mut ctx, ring_cell, witness_data);
+        assert_vote_error(&ctx, &tx, error);
+    }
+}
+
+/// Errors of the contract, each caused by a transaction which only a single script rejects, so
+/// the exit code doesn't depend on the order scripts are run in.
+/// `ItemMissing`, `LengthNotEnough` and `Unknown` are never returned, nor `UnsupportedKeySize`,
+/// `BadIntegerSize` and `BadModulus` since RSA elections are refused
+#[test]
+fn test_vote_errors() {
+    let mut rng = rand::thread_rng();
+    let mut ctx = Context::default();
+    let loader = Loader::default();
+    let verifier_bin = loader.load_binary("ring-signature-verify");
+    let script_out_point = ctx.deploy_cell(verifier_bin);
+    let keys = (0..7)
+        .map(|_| LsagPrivateKey::random(&mut rng))
+        .collect::<Vec<_>>();
+    let state = prepare(&mut ctx, &script_out_point, LsagScheme, keys, 3);
+    let config = VoteConfig::decode(&ctx.get_cell(&state.config_cell).unwrap().1).unwrap();
+    let election = (
+        state.config_cell.clone(),
+        state.config_type_hash,
+        state.key_image_registry_cell.clone(),
+    );
+    // The signer is the second of the second ring
+    let signer = 4;
+    let candidate_id = state.candidates[0].id;
+    let (vote_cell, witness) = sign_vote(&state, signer, &candidate_id);
+    let witness_data = witness.encode(SCHEME).unwrap();
+    // A single vote of `election`, whose image is inserted into the key image registry
+    let vote_tx =
+        |ctx: &mut Context,
+         (config_cell, config_type_hash, registry_cell): &(OutPoint, [u8; 32], OutPoint),
+         vote_cell: &VoteCell,
+         witness_data: Vec<u8>| {
+            let (cell_deps, tx_input, type_script) =
+                prepare_vote_tx(ctx, config_cell, config_type_hash, &script_out_point);
+            let registry_update = KeyImageRegistry::default()
+                .insert_images(&[&vote_cell.image])
+                .unwrap();
+            let vote_cell_data = Bytes::from(vote_cell.encode());
+            let vote_output = CellOutput::new_builder()
+                .capacity((vote_cell_data.len() as u64).pack())
+                .type_(Some(type_script).pack())
+                .build();
+            build_vote_tx(
+                ctx,
+                &cell_deps,
+                &tx_input,
+                registry_cell,
+                &registry_update,
+                (vote_output, vote_cell_data),
+                witness_data.into(),
+            )
+        };
+    let tx = vote_tx(&mut ctx, &election, &vote_cell, witness_data.clone());
+    ctx.verify_tx(&tx, MAX_CYCLES).unwrap();
 
-        let type_script = ctx.build_script(&script_out_point, Bytes::new()).unwrap();
+    // Vote cells
+    let unknown_candidate_id = (0u32..)
+        .map(u32::to_le_bytes)
+        .find(|id| state.candidates.iter().all(|c| &c.id != id))
+        .unwrap();
+    let mut unknown_candidate = vote_cell.clone();
+    unknown_candidate.candidate_id = unknown_candidate_id;
+    let mut image_of_another_size = vote_cell.clone();
+    image_of_another_size
+        .image
+        .resize(SCHEME.image_size() * 2, 0);
+    for (cell, error) in [
+        (unknown_candidate, VoteError::BadCandidateId),
+        (image_of_another_size, VoteError::BadImageSize),
+    ] {
+        let tx = vote_tx(&mut ctx, &election, &cell, witness_data.clone());
+        assert_vote_error(&ctx, &tx, error);
+    }
+    // Data which isn't a vote cell, which the key image registry rejects with the same error
+    let tx = vote_tx(&mut ctx, &election, &vote_cell, witness_data.clone());
+    let mut outputs_data = tx.outputs_data().into_iter().collect::<Vec<_>>();
+    outputs_data[0] = Bytes::from(vote_cell.encode()[..8].to_vec()).pack();
+    let tx = tx
+        .as_advanced_builder()
+        .set_outputs_data(outputs_data)
+        .build();
+    assert_vote_error(&ctx, &tx, VoteError::BadVoteCell);
+
+    // Vote witnesses
+    let with_witness = |change: &dyn Fn(&mut VoteWitness)| {
+        let mut witness = witness.clone();
+        change(&mut witness);
+        witness.encode(SCHEME).unwrap()
+    };
+    let ring_of = |block: usize| state.public_keys[block * 3..(block + 1) * 3].to_vec();
+    let lsag_vote = |signature: LsagSignature| {
+        let signature = RingSignature::Lsag(signature);
         (
-            vec![CellOutput::new_builder()
-                .capacity((cell_data.len() as u64).pack())
-                .type_(ScriptOpt::new_builder().set(Some(type_script)).build())
-                .build()],
-            vec![Bytes::from(cell_data)],
+            VoteCell::new(candidate_id, &signature).unwrap(),
+            VoteWitness::new(&signature, 1, witness.merkle_proof.clone())
+                .encode(SCHEME)
+                .unwrap(),
         )
     };
-    let tx = {
-        let tx = TransactionBuilder::default()
-            .cell_deps(cell_deps)
-            .inputs(tx_input)
-            .outputs(tx_output)
-            .outputs_data(tx_output_data.pack())
-            .witnesses(witness)
+    // A key of the election signing over the ring with its own key swapped in
+    let mut swapped_ring = ring_of(1);
+    swapped_ring[signer % 3] = state.public_keys[0];
+    let swapped_signature = LsagScheme
+        .sign(
+            &swapped_ring,
+            &state.keys[0],
+            signer % 3,
+            &state.config_type_hash,
+            &state.ballot(&candidate_id),
+        )
+        .unwrap();
+    // Or claiming to be over the ring which it isn't in
+    let outsider_vote = lsag_vote(LsagSignature {
+        public_keys: ring_of(1),
+        ..swapped_signature.clone()
+    });
+    let swapped_vote = lsag_vote(swapped_signature);
+    // Or over part of its ring alone
+    let subset_vote = lsag_vote(
+        LsagScheme
+            .sign(
+                &ring_of(1)[..2],
+                &state.keys[signer],
+                signer % 3,
+                &state.config_type_hash,
+                &state.ballot(&candidate_id),
+            )
+            .unwrap(),
+    );
+    // More keys than `r`
+    let mismatched_witness = with_witness(&|witness| {
+        if let WitnessSignature::Lsag { public_keys, .. } = &mut witness.signature {
+            public_keys.push(public_keys[0]);
+        }
+    });
+    let rsa_key = generate_keys(RsaKeySize::Rsa1024, 1).remove(0);
+    let rsa_scheme = RsaScheme(RsaKeySize::Rsa1024);
+    let rsa_signature = rsa_scheme
+        .sign(
+            &[rsa_scheme.public_key(&rsa_key)],
+            &rsa_key,
+            0,
+            &election.1,
+            &state.ballot(&candidate_id),
+        )
+        .unwrap();
+    for ((cell, witness_data), error) in [
+        (
+            (
+                vote_cell.clone(),
+                witness_data[..witness_data.len() - 1].to_vec(),
+            ),
+            VoteError::BadVoteWitness,
+        ),
+        (
+            (
+                vote_cell.clone(),
+                VoteWitness::new(&rsa_signature.into(), 0, vec![])
+                    .encode(rsa_scheme.signature_scheme())
+                    .unwrap(),
+            ),
+            VoteError::SignatureSchemeMismatch,
+        ),
+        (
+            (
+                vote_cell.clone(),
+                with_witness(&|witness| {
+                    if let WitnessSignature::Lsag { r, public_keys, .. } = &mut witness.signature {
+                        r.clear();
+                        public_keys.clear();
+                    }
+                }),
+            ),
+            VoteError::EmptyRing,
+        ),
+        (
+            (
+                vote_cell.clone(),
+                with_witness(&|witness| {
+                    if let WitnessSignature::Lsag { r, public_keys, .. } = &mut witness.signature {
+                        r.push(r[0]);
+                        public_keys.push(public_keys[0]);
+                    }
+                }),
+            ),
+            VoteError::RingTooLarge,
+        ),
+        (
+            (vote_cell.clone(), mismatched_witness),
+            VoteError::RingSizeMismatch,
+        ),
+        (subset_vote, VoteError::UnexpectedRingSize),
+        (
+            // The last ring is of a single key
+            (
+                vote_cell.clone(),
+                with_witness(&|witness| witness.leaf_index = 2),
+            ),
+            VoteError::UnexpectedRingSize,
+        ),
+        (
+            // Proofs are made of 32 byte hashes
+            (
+                vote_cell.clone(),
+                with_witness(&|witness| witness.merkle_proof.push(0)),
+            ),
+            VoteError::BadMerkleProofLength,
+        ),
+        (
+            (
+                vote_cell.clone(),
+                with_witness(&|witness| witness.merkle_proof[0] ^= 1),
+            ),
+            VoteError::BadMerkleProof,
+        ),
+        (
+            (
+                vote_cell.clone(),
+                with_witness(&|witness| witness.leaf_index = 0),
+            ),
+            VoteError::BadMerkleProof,
+        ),
+        (swapped_vote, VoteError::BadMerkleProof),
+        (outsider_vote, VoteError::BadSignature),
+        (
+            (
+                vote_cell.clone(),
+                with_witness(&|witness| {
+                    if let WitnessSignature::Lsag { c, .. } = &mut witness.signature {
+                        c[0] ^= 1;
+                    }
+                }),
+            ),
+            VoteError::BadSignature,
+        ),
+    ] {
+        let tx = vote_tx(&mut ctx, &election, &cell, witness_data);
+        assert_vote_error(&ctx, &tx, error);
+    }
+
+    // Witnesses of the transaction, where the first one has the proof of the key image registry
+    // in `input_type`, and the vote witness in `output_type`
+    let registry_proof = KeyImageRegistry::default()
+        .insert_images(&[&vote_cell.image])
+        .unwrap()
+        .proof;
+    for (witnesses, error) in [
+        (
+            vec![WitnessArgs::new_builder()
+                .input_type(Some(Bytes::from(registry_proof.clone())).pack())
+                .build()
+                .as_bytes()],
+            VoteError::MissingVoteWitness,
+        ),
+        (vec![], VoteError::IndexOutOfBound),
+    ] {
+        let tx = vote_tx(&mut ctx, &election, &vote_cell, witness_data.clone())
+            .as_advanced_builder()
+            .set_witnesses(witnesses.into_iter().map(|w| w.pack()).collect())
             .build();
-        tx.as_advanced_builder().build()
+        assert_vote_error(&ctx, &tx, error);
+    }
+
+    // A vote witness which isn't a `WitnessArgs`. The vote cell is moved after the registry, so
+    // its witness isn't the one with the proof of the registry
+    let tx = vote_tx(&mut ctx, &election, &vote_cell, witness_data.clone());
+    let tx = tx
+        .as_advanced_builder()
+        .set_outputs(vec![tx.output(1).unwrap(), tx.output(0).unwrap()])
+        .set_outputs_data(vec![
+            tx.outputs_data().get(1).unwrap(),
+            tx.outputs_data().get(0).unwrap(),
+        ])
+        .set_witnesses(vec![
+            WitnessArgs::new_builder()
+                .input_type(Some(Bytes::from(registry_proof.clone())).pack())
+                .build()
+                .as_bytes()
+                .pack(),
+            Bytes::from(witness_data.clone()).pack(),
+        ])
+        .build();
+    assert_vote_error(&ctx, &tx, VoteError::BadWitnessArgs);
+
+    // Script args, of a cell next to a valid vote which updates the registry
+    let mut role_args = vote_script_args(&state.config_type_hash);
+    role_args[0] = 3;
+    for args in [
+        vote_script_args(&state.config_type_hash)[..32].to_vec(),
+        role_args,
+    ] {
+        let tx = vote_tx(&mut ctx, &election, &vote_cell, witness_data.clone());
+        let vote_output = tx.output(0).unwrap();
+        let type_script = vote_output
+            .type_()
+            .to_opt()
+            .unwrap()
+            .as_builder()
+            .args(Bytes::from(args).pack())
+            .build();
+        let tx = append_vote(
+            tx,
+            (
+                vote_output
+                    .as_builder()
+                    .type_(Some(type_script).pack())
+                    .build(),
+                Bytes::from(vote_cell.encode()),
+            ),
+            witness_data.clone().into(),
+        );
+        assert_vote_error(&ctx, &tx, VoteError::BadScriptArgs);
+    }
+
+    // Config cells
+    // Offset of the `index`-th field, counting from 1, is in the header of the table
+    let field_offset = |data: &[u8], index: usize| {
+        u32::from_le_bytes(data[4 * index..4 * (index + 1)].try_into().unwrap()) as usize
+    };
+    let with_config_byte = |index: usize, value: u8| {
+        move |config: &VoteConfig| {
+            let mut data = config.encode();
+            let offset = field_offset(&data, index);
+            data[offset] = value;
+            data
+        }
     };
-    ctx.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    let unknown_config = (
+        state.config_cell.clone(),
+        rng.gen(),
+        state.key_image_registry_cell.clone(),
+    );
+    let tx = vote_tx(&mut ctx, &unknown_config, &vote_cell, witness_data.clone());
+    assert_vote_error(&ctx, &tx, VoteError::MissingVoteConfig);
+    type EncodeConfig<'a> = Box<dyn FnOnce(&VoteConfig) -> Vec<u8> + 'a>;
+    let encoders: [(EncodeConfig, VoteError); 8] = [
+        (
+            Box::new(|config| {
+                VoteConfig {
+                    admin_lock_hash: [0; 32],
+                    ..config.clone()
+                }
+                .encode()
+            }),
+            VoteError::BadVoteConfig,
+        ),
+        (
+            Box::new(|config| {
+                let data = config.encode();
+                data[..data.len() - 1].to_vec()
+            }),
+            VoteError::BadVoteConfig,
+        ),
+        (
+            // A leaf count which doesn't follow from the user count and the group size
+            Box::new(|config| {
+                VoteConfig {
+                    leaf_count: config.leaf_count + 1,
+                    ..config.clone()
+                }
+                .encode()
+            }),
+            VoteError::BadVoteConfig,
+        ),
+        (
+            // The version before the network was added
+            Box::new(with_config_byte(1, 1)),
+            VoteError::UnsupportedVoteConfigVersion,
+        ),
+        (
+            // `rsa_key_bits` is 1, while LSAG keys have none
+            Box::new(with_config_byte(8, 1)),
+            VoteError::BadVoteConfig,
+        ),
+        (
+            Box::new(with_config_byte(7, 2)),
+            VoteError::UnsupportedSignatureScheme,
+        ),
+        (
+            // RSA, whatever the key size
+            Box::new(with_config_byte(7, 0)),
+            VoteError::UnsupportedSignatureScheme,
+        ),
+        (
+            Box::new(|config| {
+                VoteConfig {
+                    voting_end: config.voting_start,
+                    ..config.clone()
+                }
+                .encode()
+            }),
+            VoteError::BadVotingPeriod,
+        ),
+    ];
+    for (encode, error) in encoders {
+        let election = deploy_election_with(&mut ctx, &script_out_point, config.clone(), encode);
+        let tx = vote_tx(&mut ctx, &election, &vote_cell, witness_data.clone());
+        assert_vote_error(&ctx, &tx, error);
+    }
+
+    // Key image registry
+    let tx = vote_tx(&mut ctx, &election, &vote_cell, witness_data.clone());
+    let with_registry_data = |data: Vec<u8>| {
+        let mut outputs_data = tx.outputs_data().into_iter().collect::<Vec<_>>();
+        outputs_data[1] = Bytes::from(data).pack();
+        tx.as_advanced_builder()
+            .set_outputs_data(outputs_data)
+            .build()
+    };
+    let mut other_root = tx.outputs_data().get(1).unwrap().raw_data().to_vec();
+    other_root[0] ^= 1;
+    let tx_with_other_root = with_registry_data(other_root);
+    assert_vote_error(&ctx, &tx_with_other_root, VoteError::BadKeyImageProof);
+    let tx_with_short_root = with_registry_data(vec![0; 31]);
+    assert_vote_error(&ctx, &tx_with_short_root, VoteError::BadKeyImageRegistry);
+    // The same image twice in a batch
+    let tx = append_vote(
+        tx.clone(),
+        (
+            tx.output(0).unwrap(),
+            tx.outputs_data().get(0).unwrap().raw_data(),
+        ),
+        witness_data.clone().into(),
+    );
+    assert_vote_error(&ctx, &tx, VoteError::KeyImageAlreadyUsed);
+    // Creating a registry with another type id, or with images in it
+    let always_success_script_op = ctx.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_script = ctx
+        .build_script(&always_success_script_op, Default::default())
+        .unwrap();
+    let input = CellInput::new_builder()
+        .previous_output(
+            ctx.create_cell(
+                CellOutput::new_builder()
+                    .capacity(1000u64.pack())
+                    .lock(always_success_script.clone())
+                    .build(),
+                Bytes::new(),
+            ),
+        )
+        .build();
+    for (type_id, root, error) in [
+        (rng.gen(), [0; 32], VoteError::InvalidTypeId),
+        (
+            calculate_type_id(&input, 0),
+            [1; 32],
+            VoteError::BadKeyImageRegistry,
+        ),
+    ] {
+        let type_script = ctx
+            .build_script(
+                &script_out_point,
+                key_image_registry_script_args(&type_id).into(),
+            )
+            .unwrap();
+        let tx = TransactionBuilder::default()
+            .input(input.clone())
+            .output(
+                CellOutput::new_builder()
+                    .capacity(1000u64.pack())
+                    .lock(always_success_script.clone())
+                    .type_(Some(type_script).pack())
+                    .build(),
+            )
+            .output_data(Bytes::copy_from_slice(&root).pack())
+            .build();
+        let tx = ctx.complete_tx(tx);
+        assert_vote_error(&ctx, &tx, error);
+    }
+}
+
+/// Cycles of a single vote signed by the first of `keys`, over a ring of all of them
+fn vote_cycles<S: RingSignatureScheme>(
+    verifier_bin: &Bytes,
+    scheme: S,
+    keys: Vec<S::PrivateKey>,
+) -> u64 {
+    let mut ctx = Context::default();
+    let script_out_point = ctx.deploy_cell(verifier_bin.clone());
+    let ring_size = keys.len();
+    let state = prepare(&mut ctx, &script_out_point, scheme, keys, ring_size);
+    let (vote_cell, witness) = sign_vote(&state, 0, &state.candidates[0].id);
+    let (cell_deps, tx_input, type_script) = prepare_vote_tx(
+        &mut ctx,
+        &state.config_cell,
+        &state.config_type_hash,
+        &script_out_point,
+    );
+    let registry_update = KeyImageRegistry::default()
+        .insert_images(&[&vote_cell.image])
+        .unwrap();
+    let vote_cell_data = Bytes::from(vote_cell.encode());
+    let vote_output = CellOutput::new_builder()
+        .capacity((vote_cell_data.len() as u64).pack())
+        .type_(Some(type_script).pack())
+        .build();
+    let tx = build_vote_tx(
+        &mut ctx,
+        &cell_deps,
+        &tx_input,
+        &state.key_image_registry_cell,
+        &registry_update,
+        (vote_output, vote_cell_data),
+        witness
+            .encode(state.scheme.signature_scheme())
+            .unwrap()
+            .into(),
+    );
+    ctx.verify_tx(&tx, u64::MAX).unwrap()
+}
+
+#[test]
+fn test_known_answer_signature() {
+    let key_size = RsaKeySize::Rsa2048;
+    let (ring, signature) = known_answer_signature(KAT_SEED, key_size, 3, 1, KAT_MESSAGE);
+    assert!(verify_signature(&ring, &KAT_ELECTION_ID, KAT_MESSAGE, &signature).unwrap());
+    assert_eq!(
+        known_answer_signature(KAT_SEED, key_size, 3, 1, KAT_MESSAGE),
+        (ring, signature)
+    );
+}
+
+#[test]
+fn test_vectors() {
+    let mut ctx = Context::default();
+    let loader = Loader::default();
+    let verifier_bin = loader.load_binary("ring-signature-verify");
+    for vector in test_vectors::load() {
+        let script_out_point =
+            deploy_cell_with_type_id(&mut ctx, verifier_bin.clone(), vector.contract_type_id);
+        let config = VoteConfig {
+            voting_start: VOTING_START,
+            voting_end: VOTING_END,
+            ..VoteConfig::decode(&vector.config).unwrap()
+        };
+        let image = VoteCell::decode(&vector.vote_cell, config.signature_scheme)
+            .unwrap()
+            .image;
+        let (config_cell, config_type_hash, key_image_registry_cell) = deploy_election_with_type_id(
+            &mut ctx,
+            &script_out_point,
+            config,
+            VoteConfig::encode,
+            vector.config_type_id,
+        );
+        assert_eq!(config_type_hash, vector.election_id);
+        let (cell_deps, tx_input, type_script) =
+            prepare_vote_tx(&mut ctx, &config_cell, &config_type_hash, &script_out_point);
+        assert_eq!(
+            type_script.code_hash().as_slice(),
+            vector.contract_code_hash
+        );
+        let registry_update = KeyImageRegistry::default()
+            .insert_images(&[&image])
+            .unwrap();
+        let vote_output = CellOutput::new_builder()
+            .capacity((vector.vote_cell.len() as u64).pack())
+            .type_(Some(type_script).pack())
+            .build();
+        let tx = build_vote_tx(
+            &mut ctx,
+            &cell_deps,
+            &tx_input,
+            &key_image_registry_cell,
+            &registry_update,
+            (vote_output, vector.vote_cell.into()),
+            vector.witness.into(),
+        );
+        println!("{}", vector.name);
+        match vector.error {
+            None => {
+                ctx.verify_tx(&tx, MAX_CYCLES).unwrap();
+            }
+            Some(_) => assert_exit_code(&ctx, &tx, vector.exit_code),
+        }
+    }
+}
+
+/// Regenerate `sign-rsa2l-700.bin` of the contract unit tests, run with
+/// `cargo test --release -p contract-unit-tests generate_contract_test_vector -- --ignored`
+#[test]
+#[ignore]
+fn generate_contract_test_vector() {
+    let (_, signature) =
+        known_answer_signature(KAT_SEED, RsaKeySize::Rsa2048, 700, 350, KAT_MESSAGE);
+    // Same as `RSASignature::to_bytes`, without the key size
+    let mut buf = signature.to_bytes().unwrap();
+    buf.drain(4..8);
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../contracts/ring-signature-verify/src/tests/sign-rsa2l-700.bin"
+    );
+    std::fs::write(path, buf).unwrap();
+}
+
+/// Cycles of a single LSAG vote by ring size, run with
+/// `cargo test --release -p contract-unit-tests bench_cycles_per_ring_size -- --ignored --nocapture`
+#[test]
+#[ignore]
+fn bench_cycles_per_ring_size() {
+    const RING_SIZES: [usize; 7] = [1, 5, 10, 15, 20, 30, 50];
+    let loader = Loader::default();
+    let verifier_bin = loader.load_binary("ring-signature-verify");
+    let mut report = Vec::new();
+    let keys = (0..RING_SIZES[RING_SIZES.len() - 1])
+        .map(|_| LsagPrivateKey::random(&mut rand::thread_rng()))
+        .collect::<Vec<_>>();
+    for ring_size in RING_SIZES {
+        let cycles = vote_cycles(&verifier_bin, LsagScheme, keys[..ring_size].to_vec());
+        report.push((String::from("LSAG"), ring_size, cycles));
+    }
+    println!("scheme | ring size | cycles | cycles per member | fits in MAX_CYCLES");
+    for (scheme, ring_size, cycles) in report {
+        println!(
+            "{} | {} | {} | {} | {}",
+            scheme,
+            ring_size,
+            cycles,
+            cycles / ring_size as u64,
+            cycles <= MAX_CYCLES
+        );
+    }
 }
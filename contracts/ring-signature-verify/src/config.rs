@@ -0,0 +1,150 @@
+//! Vote config cell
+//!
+//! The config cell is found among cell deps by its type hash, which vote cells commit to in
+//! their args. Its data is a `VoteConfig` of `vote-types`.
+use alloc::vec::Vec;
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::prelude::*,
+    high_level::{load_cell_data, load_cell_lock_hash, load_cell_type_hash, QueryIter},
+};
+use vote_types::{
+    leaf_count, schema::VoteConfigReader, SIGNATURE_SCHEME_LSAG, SIGNATURE_SCHEME_RSA,
+};
+
+use crate::{lsag::LSAG_ITEM_SIZE, VoteError};
+
+const VOTE_CONFIG_VERSION: u8 = 2;
+
+pub struct VoteConfig {
+    data: Vec<u8>,
+}
+
+impl VoteConfig {
+    /// The config cell among cell deps by its type hash. The type id script lets the
+    /// administrator replace the cell, so this is whichever config is live, and a config replaced
+    /// during the election is only caught off chain by `vote-counting`
+    pub fn load(config_type_hash: &[u8]) -> Result<Self, VoteError> {
+        let index = QueryIter::new(load_cell_type_hash, Source::CellDep)
+            .position(|type_hash| {
+                type_hash.as_ref().map(|hash| &hash[..]) == Some(config_type_hash)
+            })
+            .ok_or(VoteError::MissingVoteConfig)?;
+        let config = Self::from_data(load_cell_data(index, Source::CellDep)?)?;
+        if load_cell_lock_hash(index, Source::CellDep)? != config.admin_lock_hash() {
+            return Err(VoteError::BadVoteConfig);
+        }
+        Ok(config)
+    }
+
+    /// Check everything about the config except where it's from
+    pub fn from_data(data: Vec<u8>) -> Result<Self, VoteError> {
+        VoteConfigReader::from_slice(&data).map_err(|e| {
+            ckb_std::debug!("Failed to read vote config: {}", e);
+            VoteError::BadVoteConfig
+        })?;
+        let config = Self { data };
+        if config.reader().version().as_slice()[0] != VOTE_CONFIG_VERSION {
+            return Err(VoteError::UnsupportedVoteConfigVersion);
+        }
+        // Rings are the partition of all keys by the group size, so the sizes must agree
+        if config.group_size() == 0
+            || config.leaf_count() != leaf_count(config.user_count(), config.group_size())
+        {
+            return Err(VoteError::BadVoteConfig);
+        }
+        match config.signature_scheme() {
+            // An RSA key image is a multiple of a prime of the signer's modulus, so another
+            // multiple votes again with the same key and the gcd with each modulus of the ring
+            // finds the voter. RSA elections are refused until images are sound
+            SIGNATURE_SCHEME_RSA => return Err(VoteError::UnsupportedSignatureScheme),
+            SIGNATURE_SCHEME_LSAG => {
+                if config.rsa_key_bits() != 0 {
+                    return Err(VoteError::BadVoteConfig);
+                }
+            }
+            _ => return Err(VoteError::UnsupportedSignatureScheme),
+        }
+        Ok(config)
+    }
+
+    /// Data was verified when loading
+    fn reader(&self) -> VoteConfigReader<'_> {
+        VoteConfigReader::new_unchecked(&self.data)
+    }
+
+    /// Genesis block hash of the chain, which votes sign
+    pub fn network(&self) -> [u8; 32] {
+        self.reader().network().into()
+    }
+
+    pub fn merkle_root(&self) -> [u8; 32] {
+        self.reader().merkle_root().into()
+    }
+
+    pub fn user_count(&self) -> usize {
+        u32::from(self.reader().user_count()) as usize
+    }
+
+    pub fn leaf_count(&self) -> usize {
+        u32::from(self.reader().leaf_count()) as usize
+    }
+
+    pub fn group_size(&self) -> usize {
+        u32::from(self.reader().group_size()) as usize
+    }
+
+    /// Which is also the item id of signatures in vote witnesses
+    pub fn signature_scheme(&self) -> u8 {
+        self.reader().signature_scheme().as_slice()[0]
+    }
+
+    /// 0 for LSAG
+    pub fn rsa_key_bits(&self) -> u32 {
+        u32::from(self.reader().rsa_key_bits())
+    }
+
+    /// Size of every big integer in vote cells and witnesses
+    pub fn rsa_key_bytes(&self) -> usize {
+        self.rsa_key_bits() as usize / 8
+    }
+
+    /// Size of key images in vote cells
+    pub fn image_size(&self) -> usize {
+        match self.signature_scheme() {
+            SIGNATURE_SCHEME_LSAG => LSAG_ITEM_SIZE,
+            _ => self.rsa_key_bytes(),
+        }
+    }
+
+    /// Size of public keys in registry cells, an RSA key carries its exponent as a Uint32
+    pub fn public_key_size(&self) -> usize {
+        match self.signature_scheme() {
+            SIGNATURE_SCHEME_LSAG => LSAG_ITEM_SIZE,
+            _ => self.rsa_key_bytes() + 4,
+        }
+    }
+
+    pub fn voting_start(&self) -> u64 {
+        u64::from(self.reader().voting_start())
+    }
+
+    pub fn voting_end(&self) -> u64 {
+        u64::from(self.reader().voting_end())
+    }
+
+    pub fn admin_lock_hash(&self) -> &[u8] {
+        self.reader().admin_lock_hash().raw_data()
+    }
+
+    pub fn key_image_registry_type_hash(&self) -> &[u8] {
+        self.reader().key_image_registry_type_hash().raw_data()
+    }
+
+    pub fn has_candidate(&self, candidate_id: &[u8]) -> bool {
+        self.reader()
+            .candidates()
+            .iter()
+            .any(|candidate| candidate.id().raw_data() == candidate_id)
+    }
+}
@@ -0,0 +1,198 @@
+//! Key image registry
+//!
+//! A registry cell is typed by this same script, with a type id in its args, and holds the
+//! root of a sparse merkle tree indexed by `sha256(image)` of every vote accepted so far.
+//! Each vote transaction has to consume the registry of its election and recreate it with
+//! the images of all the vote cells it creates inserted, so the same image can't vote twice.
+use alloc::vec::Vec;
+use ckb_hash::new_blake2b;
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::{packed::Script, prelude::*},
+    high_level::{
+        load_cell_capacity, load_cell_data, load_cell_lock_hash, load_cell_type,
+        load_cell_type_hash, load_input, load_script_hash, load_witness_args, QueryIter,
+    },
+};
+use sha2::{Digest, Sha256};
+use sparse_merkle_tree::{traits::Hasher, CompiledMerkleProof, H256};
+use vote_types::schema::{KeyImageRegistryCellReader, VoteCellReader};
+
+use crate::{config::VoteConfig, VoteError, SCRIPT_ROLE_VOTE};
+
+/// Leaf value stored for every used image, anything non-zero would do
+pub const KEY_IMAGE_USED: [u8; 32] = [1; 32];
+
+#[derive(Default)]
+pub struct Sha256Hasher(Sha256);
+
+impl Hasher for Sha256Hasher {
+    fn write_h256(&mut self, h: &H256) {
+        self.0.update(h.as_slice());
+    }
+    fn write_byte(&mut self, b: u8) {
+        self.0.update([b]);
+    }
+    fn finish(self) -> H256 {
+        let hash: [u8; 32] = self.0.finalize().into();
+        hash.into()
+    }
+}
+
+pub fn key_image_registry_key(image: &[u8]) -> H256 {
+    let hash: [u8; 32] = Sha256::digest(image).into();
+    hash.into()
+}
+
+fn read_registry_root(data: &[u8]) -> Result<H256, VoteError> {
+    let root: [u8; 32] = KeyImageRegistryCellReader::from_slice(data)
+        .map_err(|_| VoteError::BadKeyImageRegistry)?
+        .root()
+        .raw_data()
+        .try_into()
+        .unwrap();
+    Ok(root.into())
+}
+
+/// Make sure that the registry with the given type hash is updated by current transaction.
+/// Whether the update itself is correct is checked by the registry's own type script, which only
+/// inserts images of votes with its own code hash and hash type. A vote of the same binary under
+/// another hash type would skip the registry, so `vote_script` must have the same ones.
+pub fn verify_key_image_registry_updated(
+    registry_type_hash: &[u8],
+    vote_script: &Script,
+) -> Result<(), VoteError> {
+    let is_registry = |type_hash: &Option<[u8; 32]>| {
+        type_hash.as_ref().map(|hash| &hash[..]) == Some(registry_type_hash)
+    };
+    let input_count = QueryIter::new(load_cell_type_hash, Source::Input)
+        .filter(is_registry)
+        .count();
+    let output_count = QueryIter::new(load_cell_type_hash, Source::Output)
+        .filter(is_registry)
+        .count();
+    if input_count != 1 || output_count != 1 {
+        return Err(VoteError::MissingKeyImageRegistry);
+    }
+    let registry_index = QueryIter::new(load_cell_type_hash, Source::Output)
+        .position(|type_hash| is_registry(&type_hash))
+        .ok_or(VoteError::MissingKeyImageRegistry)?;
+    let registry_script = load_cell_type(registry_index, Source::Output)?
+        .ok_or(VoteError::MissingKeyImageRegistry)?;
+    if registry_script.code_hash().as_slice() != vote_script.code_hash().as_slice()
+        || registry_script.hash_type().as_slice() != vote_script.hash_type().as_slice()
+    {
+        return Err(VoteError::KeyImageRegistryScriptMismatch);
+    }
+    Ok(())
+}
+
+pub fn verify_key_image_registry(script: &Script) -> Result<(), VoteError> {
+    let input_count = QueryIter::new(load_cell_capacity, Source::GroupInput).count();
+    let output_count = QueryIter::new(load_cell_capacity, Source::GroupOutput).count();
+    ckb_std::debug!(
+        "registry input count = {}, output count = {}",
+        input_count,
+        output_count
+    );
+    match (input_count, output_count) {
+        (0, 1) => verify_registry_creation(script),
+        (1, 1) => verify_registry_update(script),
+        _ => Err(VoteError::BadKeyImageRegistry),
+    }
+}
+
+fn verify_registry_creation(script: &Script) -> Result<(), VoteError> {
+    let script_hash = load_script_hash()?;
+    let output_index = QueryIter::new(load_cell_type_hash, Source::Output)
+        .position(|type_hash| type_hash == Some(script_hash))
+        .ok_or(VoteError::BadKeyImageRegistry)?;
+    let type_id = {
+        let mut hasher = new_blake2b();
+        hasher.update(load_input(0, Source::Input)?.as_slice());
+        hasher.update(&(output_index as u64).to_le_bytes());
+        let mut result = [0u8; 32];
+        hasher.finalize(&mut result);
+        result
+    };
+    if script.args().raw_data()[1..] != type_id {
+        return Err(VoteError::InvalidTypeId);
+    }
+    // A registry can only start with nothing in it
+    if !read_registry_root(&load_cell_data(0, Source::GroupOutput)?)?.is_zero() {
+        return Err(VoteError::BadKeyImageRegistry);
+    }
+    Ok(())
+}
+
+fn verify_registry_update(script: &Script) -> Result<(), VoteError> {
+    // Anyone can spend the registry, so make sure it's still spendable by anyone, and nobody
+    // takes its capacity away
+    if load_cell_lock_hash(0, Source::GroupInput)? != load_cell_lock_hash(0, Source::GroupOutput)?
+        || load_cell_capacity(0, Source::GroupOutput)? < load_cell_capacity(0, Source::GroupInput)?
+    {
+        return Err(VoteError::BadKeyImageRegistry);
+    }
+    let old_root = read_registry_root(&load_cell_data(0, Source::GroupInput)?)?;
+    let new_root = read_registry_root(&load_cell_data(0, Source::GroupOutput)?)?;
+
+    // Votes are cells typed by this script with the vote role, whose config points to this
+    // registry. Votes of other elections are left to their own registries
+    let script_hash = load_script_hash()?;
+    let mut keys = Vec::new();
+    for (index, type_script) in QueryIter::new(load_cell_type, Source::Output).enumerate() {
+        let Some(type_script) = type_script else {
+            continue;
+        };
+        let args = type_script.args().raw_data();
+        if type_script.code_hash().as_slice() != script.code_hash().as_slice()
+            || type_script.hash_type().as_slice() != script.hash_type().as_slice()
+            || args.len() != 33
+            || args[0] != SCRIPT_ROLE_VOTE
+            || VoteConfig::load(&args[1..])?.key_image_registry_type_hash() != script_hash
+        {
+            continue;
+        }
+        let vote_cell_data = load_cell_data(index, Source::Output)?;
+        let vote_cell =
+            VoteCellReader::from_slice(&vote_cell_data).map_err(|_| VoteError::BadVoteCell)?;
+        keys.push(key_image_registry_key(vote_cell.image().raw_data()));
+    }
+    ckb_std::debug!("{} images to insert", keys.len());
+    if keys.is_empty() {
+        return Err(VoteError::BadKeyImageRegistry);
+    }
+    let key_count = keys.len();
+    keys.sort_unstable();
+    keys.dedup();
+    if keys.len() != key_count {
+        return Err(VoteError::KeyImageAlreadyUsed);
+    }
+
+    let proof = load_witness_args(0, Source::GroupInput)?
+        .input_type()
+        .to_opt()
+        .ok_or(VoteError::BadKeyImageProof)?
+        .raw_data();
+    let proof = CompiledMerkleProof(proof.to_vec());
+    // All images must be absent before, and present afterwards. Since the same proof is used
+    // for both roots, nothing else in the tree could have been changed
+    let unused_leaves = keys.iter().map(|key| (*key, H256::zero())).collect();
+    if !proof
+        .verify::<Sha256Hasher>(&old_root, unused_leaves)
+        .map_err(|_| VoteError::BadKeyImageProof)?
+    {
+        return Err(VoteError::KeyImageAlreadyUsed);
+    }
+    let used_leaves = keys
+        .into_iter()
+        .map(|key| (key, KEY_IMAGE_USED.into()))
+        .collect();
+    if !proof
+        .verify::<Sha256Hasher>(&new_root, used_leaves)
+        .map_err(|_| VoteError::BadKeyImageProof)?
+    {
+        return Err(VoteError::BadKeyImageProof);
+    }
+    Ok(())
+}
@@ -0,0 +1,78 @@
+//! LSAG ring signatures over ristretto255
+//!
+//! Verification is the same as `verify_signature` of `signature_tools::ec_tools`. Scalars must be
+//! canonical and points must decompress, so every signature has a single encoding.
+use curve25519_dalek::{
+    ristretto::CompressedRistretto, traits::VartimeMultiscalarMul, RistrettoPoint, Scalar,
+};
+use sha2::{Digest, Sha512};
+use vote_types::{LSAG_CHALLENGE_DOMAIN, LSAG_HASH_TO_POINT_DOMAIN};
+
+use crate::VoteError;
+
+/// Size of scalars and compressed points
+pub const LSAG_ITEM_SIZE: usize = 32;
+
+fn hash_to_point(election_id: &[u8], public_key: &[u8]) -> RistrettoPoint {
+    RistrettoPoint::from_hash(
+        Sha512::new()
+            .chain_update(LSAG_HASH_TO_POINT_DOMAIN)
+            .chain_update(election_id)
+            .chain_update(public_key),
+    )
+}
+
+fn decompress(point: &[u8]) -> Result<RistrettoPoint, VoteError> {
+    CompressedRistretto::from_slice(point)
+        .map_err(|_| VoteError::BadPoint)?
+        .decompress()
+        .ok_or(VoteError::BadPoint)
+}
+
+fn scalar(bytes: &[u8]) -> Result<Scalar, VoteError> {
+    let bytes: [u8; LSAG_ITEM_SIZE] = bytes.try_into().map_err(|_| VoteError::BadIntegerSize)?;
+    Option::<Scalar>::from(Scalar::from_canonical_bytes(bytes))
+        .ok_or(VoteError::NonCanonicalInteger)
+}
+
+/// `public_keys` and `r_array` are concatenated, 32 bytes each. Key images are of the election,
+/// so images of a key in other elections differ
+pub fn verify_signature(
+    ring_size: usize,
+    election_id: &[u8],
+    message: &[u8],
+    public_keys: &[u8],
+    signature_c: &[u8],
+    signature_r_array: &[u8],
+    signature_image: &[u8],
+) -> Result<(), VoteError> {
+    ckb_std::debug!("verify LSAG signature, message = {:?}", message);
+    let hasher = Sha512::new()
+        .chain_update(LSAG_CHALLENGE_DOMAIN)
+        .chain_update((ring_size as u32).to_le_bytes())
+        .chain_update(public_keys)
+        .chain_update(message);
+    let image = decompress(signature_image)?;
+    let c0 = scalar(signature_c)?;
+    let mut last_c = c0;
+    for (public_key, r) in public_keys
+        .chunks(LSAG_ITEM_SIZE)
+        .zip(signature_r_array.chunks(LSAG_ITEM_SIZE))
+    {
+        let point = decompress(public_key)?;
+        let r = scalar(r)?;
+        let l = RistrettoPoint::vartime_double_scalar_mul_basepoint(&last_c, &point, &r);
+        let r = RistrettoPoint::vartime_multiscalar_mul(
+            [r, last_c],
+            [hash_to_point(election_id, public_key), image],
+        );
+        let mut challenge_hasher = hasher.clone();
+        challenge_hasher.update(l.compress().as_bytes());
+        challenge_hasher.update(r.compress().as_bytes());
+        last_c = Scalar::from_hash(challenge_hasher);
+    }
+    if last_c != c0 {
+        return Err(VoteError::BadSignature);
+    }
+    Ok(())
+}
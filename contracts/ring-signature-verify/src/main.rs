@@ -1,16 +1,32 @@
 #![cfg_attr(not(test), no_std)]
 #![cfg_attr(not(test), no_main)]
 
+use alloc::vec::Vec;
 use bnum::BUint;
 use ckb_std::{
     ckb_constants::Source,
-    ckb_types::{packed::WitnessArgsReader, prelude::Reader},
-    error::SysError,
-    high_level::{load_cell_data, load_witness},
+    ckb_types::{
+        packed::{Script, WitnessArgsReader},
+        prelude::*,
+    },
+    high_level::{load_cell_data, load_script, load_witness, QueryIter},
 };
+use config::VoteConfig;
+use key_image_registry::{verify_key_image_registry, verify_key_image_registry_updated};
+use public_key_registry::{ring_public_keys, verify_public_key_registry};
 use rs_merkle::MerkleProof;
 use sha2::{Digest, Sha256};
-use utils::{add_mod_expand, mul_mod_expand, power_mod};
+use utils::Montgomery;
+use vote_types::{
+    ballot_message,
+    molecule::NUMBER_SIZE,
+    schema::{
+        BytesVecReader, LsagRingSignatureReader, RingSignatureUnionReader, RsaRingSignatureReader,
+        Uint32VecReader, VoteCellReader, VoteWitnessReader,
+    },
+    VoteError, RSA_IMAGE_BASE_DOMAIN, SIGNATURE_SCHEME_LSAG, SIGNATURE_SCHEME_RSA,
+};
+use voting_period::verify_voting_period;
 
 #[cfg(test)]
 extern crate alloc;
@@ -20,145 +36,179 @@ ckb_std::entry!(program_entry);
 #[cfg(not(test))]
 ckb_std::default_alloc!(4 * 1024, 3 * 1024 * 1024, 64);
 
+mod config;
+mod key_image_registry;
+mod lsag;
+mod public_key_registry;
 #[cfg(test)]
 mod tests;
 mod utils;
+mod voting_period;
 
-#[repr(i8)]
-#[cfg_attr(test, derive(Debug))]
-pub enum VoteError {
-    IndexOutOfBound = 1,
-    ItemMissing,
-    LengthNotEnough,
-    Encoding,
-    BadSignature = 51,
-    BadCandidateId,
-    BadCandidateCellFormat,
-    BadPublicKeyCellFormat,
-    MissingDependency,
-    BadWitness,
-    BadMerkleProof,
-    InvalidMerkleRootHashLength,
-    Unknown,
-}
-
-impl From<SysError> for VoteError {
-    fn from(value: SysError) -> Self {
-        match value {
-            SysError::IndexOutOfBound => VoteError::IndexOutOfBound,
-            SysError::ItemMissing => VoteError::ItemMissing,
-            SysError::LengthNotEnough(_) => VoteError::LengthNotEnough,
-            SysError::Encoding => VoteError::Encoding,
-            SysError::Unknown(s) => {
-                ckb_std::debug!("Unknown error code {}", s);
-                VoteError::Unknown
-            }
-        }
-    }
-}
-
-const VOTE_CELL_INDEX: usize = 0;
-const CANDIDATE_CELL_DEP_INDEX: usize = 0;
-const MERKLE_ROOT_HASH_CELL_DEP_INDEX: usize = 1;
+/// First byte of script args, followed by type hash of the vote config cell
+pub const SCRIPT_ROLE_VOTE: u8 = 0;
+/// First byte of script args, followed by the type id
+pub const SCRIPT_ROLE_KEY_IMAGE_REGISTRY: u8 = 1;
+/// First byte of script args, followed by type hash of the vote config cell
+pub const SCRIPT_ROLE_PUBLIC_KEY_REGISTRY: u8 = 2;
 
-const WITNESS_INDEX: usize = 0;
 pub fn program_entry() -> i8 {
     ckb_std::debug!("Entered");
-    match verify_all() {
+    match verify_entry() {
         Ok(_) => 0,
-        Err(err) => err as i8,
+        Err(err) => err.code(),
     }
 }
 
-fn verify_candidate(candidate_id: &[u8]) -> Result<(), VoteError> {
-    ckb_std::debug!("Veryfing candidate id {:?}", candidate_id);
-    // Verify candidate cell data..
-    let candidate_cell_data = load_cell_data(CANDIDATE_CELL_DEP_INDEX, Source::CellDep)?;
-    let n = u16::from_le_bytes([candidate_cell_data[0], candidate_cell_data[1]]) as usize;
-    let mut matched = false;
-    for i in 0..n {
-        let offset = 2 + i * 104;
-        if candidate_id == &candidate_cell_data[offset..offset + 4] {
-            matched = true;
-            break;
-        }
+/// Vote cells, key image registry cells and public key registry cells use this script, told
+/// apart by the first byte of args
+fn verify_entry() -> Result<(), VoteError> {
+    let script = load_script()?;
+    let args = script.args().raw_data();
+    if args.len() != 33 {
+        return Err(VoteError::BadScriptArgs);
+    }
+    match args[0] {
+        SCRIPT_ROLE_VOTE => verify_all(&script),
+        SCRIPT_ROLE_KEY_IMAGE_REGISTRY => verify_key_image_registry(&script),
+        SCRIPT_ROLE_PUBLIC_KEY_REGISTRY => verify_public_key_registry(&args[1..]),
+        _ => Err(VoteError::BadScriptArgs),
     }
-    if !matched {
+}
+
+fn verify_candidate(config: &VoteConfig, candidate_id: &[u8]) -> Result<(), VoteError> {
+    ckb_std::debug!("Veryfing candidate id {:?}", candidate_id);
+    if !config.has_candidate(candidate_id) {
         return Err(VoteError::BadCandidateId);
     }
     Ok(())
 }
-type Uint2048 = BUint<32>;
-fn sha256_for_integer(num: &Uint2048) -> Uint2048 {
+/// Base of key images of a modulus in the election. Images are multiples of a prime of the
+/// signer whatever the base, which gives the signer away, so RSA elections are refused
+fn sha256_for_integer<const S: usize>(election_id: &[u8], num: &BUint<S>) -> BUint<S> {
     let mut hasher = Sha256::new();
+    hasher.update(RSA_IMAGE_BASE_DOMAIN);
+    hasher.update(election_id);
     for digit in num.digits() {
         hasher.update(unsafe { core::slice::from_raw_parts(digit as *const u64 as *const u8, 8) });
     }
-    Uint2048::from_le_slice(&hasher.finalize()).unwrap()
+    BUint::<S>::from_le_slice(&hasher.finalize()).unwrap()
 }
-fn verify_signature(
+
+/// Verify with the width of integers matching the RSA key size, `S2` is twice the digits of
+/// `S`, so products don't overflow
+#[allow(clippy::too_many_arguments)]
+fn verify_signature_of_size(
+    key_bits: u32,
     ring_size: usize,
-    candidate_id: &[u8],
-    public_key_n_array: &[u8],
-    public_key_e_array: &[u8],
+    election_id: &[u8],
+    message: &[u8],
+    public_keys: &[u8],
     signature_c: &[u8],
     signature_r_array: &[u8],
     signature_i: &[u8],
 ) -> Result<(), VoteError> {
-    ckb_std::debug!("verify signature, candidate id = {:?}", candidate_id);
+    let verify = match key_bits {
+        1024 => verify_signature::<16, 32>,
+        2048 => verify_signature::<32, 64>,
+        3072 => verify_signature::<48, 96>,
+        4096 => verify_signature::<64, 128>,
+        _ => return Err(VoteError::UnsupportedKeySize),
+    };
+    verify(
+        ring_size,
+        election_id,
+        message,
+        public_keys,
+        signature_c,
+        signature_r_array,
+        signature_i,
+    )
+}
+
+/// Integers are of `S` digits, which is `S * 8` bytes in arrays. A public key is `n` followed by
+/// `e` in 4 bytes, as in registry cells
+fn verify_signature<const S: usize, const S2: usize>(
+    ring_size: usize,
+    election_id: &[u8],
+    message: &[u8],
+    public_keys: &[u8],
+    signature_c: &[u8],
+    signature_r_array: &[u8],
+    signature_i: &[u8],
+) -> Result<(), VoteError> {
+    ckb_std::debug!("verify signature, message = {:?}", message);
+    let size = S * 8;
+    let key_size = size + 4;
     let mut hasher = Sha256::new();
-    hasher.update(candidate_id);
-    for i in 0..ring_size {
-        hasher.update(&public_key_n_array[256 * i..256 * (i + 1)]);
-        hasher.update(&public_key_e_array[4 * i..4 * (i + 1)]);
-    }
-    let compund_hash = |integer1: &Uint2048, integer2: &Uint2048| -> Uint2048 {
+    hasher.update(message);
+    hasher.update(&public_keys[..key_size * ring_size]);
+    let compund_hash = |integer1: &BUint<S>, integer2: &BUint<S>| -> BUint<S> {
         let mut local_hasher = hasher.clone();
         for digit in integer1.digits().iter().chain(integer2.digits().iter()) {
             local_hasher.update(unsafe {
                 core::slice::from_raw_parts(digit as *const u64 as *const u8, 8)
             });
         }
-        Uint2048::from_le_slice(&local_hasher.finalize()).unwrap()
+        BUint::<S>::from_le_slice(&local_hasher.finalize()).unwrap()
     };
-    let c0 = Uint2048::from_le_slice(signature_c).unwrap();
+    // c is a sha256 hash
+    if signature_c[32..].iter().any(|&byte| byte != 0) {
+        return Err(VoteError::NonCanonicalInteger);
+    }
+    let c0 = BUint::<S>::from_le_slice(signature_c).unwrap();
     let mut last_c = c0;
-    let image = Uint2048::from_le_slice(signature_i).unwrap();
+    let image = BUint::<S>::from_le_slice(signature_i).unwrap();
+    let mut smallest_n = BUint::<S>::MAX;
     for i in 0..ring_size {
-        let r = Uint2048::from_le_slice(&signature_r_array[i * 256..(i + 1) * 256]).unwrap();
+        let r = BUint::<S>::from_le_slice(&signature_r_array[i * size..(i + 1) * size]).unwrap();
 
-        let e_bytes = &public_key_e_array[i * 4..(i + 1) * 4];
-        let e = u32::from_le_bytes([e_bytes[0], e_bytes[1], e_bytes[2], e_bytes[3]]);
+        let public_key = &public_keys[i * key_size..(i + 1) * key_size];
+        let e = u32::from_le_bytes(public_key[size..].try_into().unwrap());
 
-        let n = Uint2048::from_le_slice(&public_key_n_array[i * 256..(i + 1) * 256]).unwrap();
+        let n = BUint::<S>::from_le_slice(&public_key[..size]).unwrap();
+        // RSA moduli are odd, and the host side refuses even ones as well
+        let n_mod = Montgomery::new::<S2>(n).ok_or(VoteError::BadModulus)?;
+        // Otherwise r + n would verify as well
+        if r >= n {
+            return Err(VoteError::NonCanonicalInteger);
+        }
+        smallest_n = smallest_n.min(n);
 
-        let r_power_e = power_mod::<32, 64>(r, e.into(), n);
-        let c_mul_r_power_e = mul_mod_expand::<32, 64>(last_c, r_power_e, n);
-        let ch_pi_mul_r = mul_mod_expand::<32, 64>(
-            add_mod_expand::<32, 33>(
-                mul_mod_expand::<32, 64>(last_c, sha256_for_integer(&n), n),
-                image,
-                n,
-            ),
-            r_power_e,
-            n,
+        // Only r^e is kept in Montgomery form, so multiplying it by an integer in normal form
+        // gives the product in normal form
+        let r_power_e = n_mod.pow(&n_mod.to_montgomery(&r), e.into());
+        let c_mul_r_power_e = n_mod.mul(&last_c, &r_power_e);
+        let c_mul_hash = n_mod.mul(
+            &sha256_for_integer(election_id, &n),
+            &n_mod.to_montgomery(&last_c),
         );
+        let ch_pi_mul_r = n_mod.mul(&n_mod.add(&c_mul_hash, &n_mod.reduce(&image)), &r_power_e);
         last_c = compund_hash(&c_mul_r_power_e, &ch_pi_mul_r);
     }
+    // The modulus of the signer can't be told apart from the others, so the image must be below
+    // all of them, otherwise any signer could add its modulus to its image
+    if image >= smallest_n {
+        return Err(VoteError::NonCanonicalInteger);
+    }
     if last_c != c0 {
         return Err(VoteError::BadSignature);
     }
     Ok(())
 }
 
+/// Leaf of a ring in the merkle tree, which is the sha256 of its public keys, concatenated in
+/// the encoding of registry cells
+fn leaf_hash(public_keys: &[u8]) -> [u8; 32] {
+    Sha256::digest(public_keys).into()
+}
+
 fn verify_merkle_proof(
     proof: &[u8],
     leaf_count: usize,
     leaf_index: usize,
-    root_hash: &[u8],
-    ring_size: usize,
-    e_arr: &[u8],
-    n_arr: &[u8],
+    root_hash: [u8; 32],
+    leaf_hash: [u8; 32],
 ) -> Result<(), VoteError> {
     ckb_std::debug!("Received proof {:?}", proof);
     ckb_std::debug!(
@@ -168,23 +218,15 @@ fn verify_merkle_proof(
         root_hash
     );
 
-    let leaf_hash: [u8; 32] = {
-        let mut hasher = Sha256::new();
-        for i in 0..ring_size {
-            hasher.update(&n_arr[i * 256..(i + 1) * 256]);
-            hasher.update(&e_arr[i * 4..(i + 1) * 4]);
-        }
-        hasher.finalize().try_into().unwrap()
-    };
     ckb_std::debug!("Leaf hash={:?}", leaf_hash);
-    let root_hash: [u8; 32] = root_hash
-        .try_into()
-        .map_err(|_| VoteError::InvalidMerkleRootHashLength)?;
-
     let proof = MerkleProof::<rs_merkle::algorithms::Sha256>::from_bytes(proof).map_err(|e| {
         ckb_std::debug!("Failed to parse merkle proof: {}", e);
-        VoteError::BadMerkleProof
+        VoteError::BadMerkleProofLength
     })?;
+    // Bits of the index above the height of the tree are ignored by the proof
+    if leaf_index >= leaf_count {
+        return Err(VoteError::BadMerkleProof);
+    }
     if proof.verify(root_hash, &[leaf_index], &[leaf_hash], leaf_count) {
         Ok(())
     } else {
@@ -192,81 +234,315 @@ fn verify_merkle_proof(
     }
 }
 
-fn verify_all() -> Result<(), VoteError> {
-    let merkle_tree_root_cell_data =
-        load_cell_data(MERKLE_ROOT_HASH_CELL_DEP_INDEX, Source::CellDep)?;
-    let merkle_root_hash = &merkle_tree_root_cell_data[0..32];
-    let user_count =
-        u32::from_le_bytes(merkle_tree_root_cell_data[32..36].try_into().unwrap()) as usize;
-    let merkle_leaf_count =
-        u32::from_le_bytes(merkle_tree_root_cell_data[36..40].try_into().unwrap()) as usize;
+/// `script` is the type script of vote cells, its args are the type hash of the config cell after
+/// the role byte, and its code hash is the one votes sign
+fn verify_all(script: &Script) -> Result<(), VoteError> {
+    let args = script.args().raw_data();
+    let config_type_hash = &args[1..];
+    let contract_code_hash = &script.code_hash().raw_data()[..].try_into().unwrap();
+    let config = VoteConfig::load(config_type_hash)?;
     ckb_std::debug!(
         "merkle leaf count = {}, user count = {}",
-        merkle_leaf_count,
-        user_count
+        config.leaf_count(),
+        config.user_count()
     );
+    verify_key_image_registry_updated(config.key_image_registry_type_hash(), script)?;
+    ckb_std::debug!("key image registry updated");
+    verify_voting_period(config.voting_start(), config.voting_end())?;
+    ckb_std::debug!("voting period verified");
 
-    let vote_cell_data = load_cell_data(VOTE_CELL_INDEX, Source::Output)?;
-    verify_candidate(&vote_cell_data[0..4])?;
-    ckb_std::debug!("candidate verified");
-    let witness_data = load_witness(WITNESS_INDEX, Source::Output)?;
+    // Every vote cell in the group is verified with the witness at the same index, so a relayer
+    // could batch votes of many users into one transaction
+    for (index, vote_cell_data) in QueryIter::new(load_cell_data, Source::GroupOutput).enumerate() {
+        ckb_std::debug!("verifying vote {}", index);
+        verify_vote(
+            &config,
+            config_type_hash,
+            contract_code_hash,
+            &vote_cell_data,
+            index,
+        )?;
+    }
+    Ok(())
+}
+
+/// `election_id` is the type hash of the config cell
+fn verify_vote(
+    config: &VoteConfig,
+    election_id: &[u8],
+    contract_code_hash: &[u8; 32],
+    vote_cell_data: &[u8],
+    index: usize,
+) -> Result<(), VoteError> {
+    let (candidate_id, image) = verify_vote_cell(config, vote_cell_data)?;
+    let message = ballot_message(
+        election_id.try_into().unwrap(),
+        &config.network(),
+        contract_code_hash,
+        candidate_id.try_into().unwrap(),
+    );
+    let witness_data = load_witness(index, Source::GroupOutput)?;
 
     let output_type_witness = {
         let witness_reader = WitnessArgsReader::from_slice(&witness_data).map_err(|e| {
             ckb_std::debug!("Failed to read witness: {}", e);
-            VoteError::BadWitness
+            VoteError::BadWitnessArgs
         })?;
         let output_type_witness = witness_reader
             .output_type()
             .to_opt()
-            .ok_or(VoteError::MissingDependency)?
+            .ok_or(VoteError::MissingVoteWitness)?
             .raw_data();
         output_type_witness
     };
-    let ring_size = u32::from_le_bytes([
-        output_type_witness[256],
-        output_type_witness[257],
-        output_type_witness[258],
-        output_type_witness[259],
-    ]) as usize;
-    ckb_std::debug!("ring_size={}", ring_size);
+    verify_vote_witness(
+        config,
+        election_id,
+        &message,
+        image,
+        output_type_witness,
+        |index| Ok(load_cell_data(index, Source::CellDep)?),
+    )
+}
 
-    let (e_arr, n_arr) = {
-        let mut cursor = 256 + 4 + 256 * ring_size;
-        let n_arr = &output_type_witness[cursor..cursor + ring_size * 256];
-        cursor += ring_size * 256;
-        let e_arr = &output_type_witness[cursor..cursor + ring_size * 4];
-        cursor += ring_size * 4;
-        let leaf_index =
-            u32::from_le_bytes(output_type_witness[cursor..cursor + 4].try_into().unwrap())
-                as usize;
-        cursor += 4;
-        let proof_length =
-            u32::from_le_bytes(output_type_witness[cursor..cursor + 4].try_into().unwrap())
-                as usize;
-        cursor += 4;
-        let proof = &output_type_witness[cursor..cursor + proof_length];
-        verify_merkle_proof(
-            proof,
-            merkle_leaf_count,
-            leaf_index,
-            merkle_root_hash,
-            ring_size,
-            e_arr,
-            n_arr,
-        )?;
-        (e_arr, n_arr)
+/// Candidate id and key image of a vote cell, the candidate must be of the election
+fn verify_vote_cell<'a>(
+    config: &VoteConfig,
+    vote_cell_data: &'a [u8],
+) -> Result<(&'a [u8], &'a [u8]), VoteError> {
+    let vote_cell = VoteCellReader::from_slice(vote_cell_data).map_err(|e| {
+        ckb_std::debug!("Failed to read vote cell: {}", e);
+        VoteError::BadVoteCell
+    })?;
+    let candidate_id = vote_cell.candidate_id().raw_data();
+    let image = vote_cell.image().raw_data();
+    if image.len() != config.image_size() {
+        return Err(VoteError::BadImageSize);
+    }
+    verify_candidate(config, candidate_id)?;
+    ckb_std::debug!("candidate verified");
+    Ok((candidate_id, image))
+}
+
+/// Verify the `VoteWitness` of a vote, which is in `output_type` of the witness. `message` is
+/// the `ballot_message` of the vote, and `load_cell_dep` loads data of the cell dep at an index,
+/// for witnesses with the keys of the ring in a registry cell
+fn verify_vote_witness(
+    config: &VoteConfig,
+    election_id: &[u8],
+    message: &[u8],
+    image: &[u8],
+    output_type_witness: &[u8],
+    load_cell_dep: impl FnOnce(usize) -> Result<Vec<u8>, VoteError>,
+) -> Result<(), VoteError> {
+    let witness = VoteWitnessReader::from_slice(output_type_witness).map_err(|e| {
+        ckb_std::debug!("Failed to read vote witness: {}", e);
+        VoteError::BadVoteWitness
+    })?;
+    let leaf_index = u32::from(witness.leaf_index()) as usize;
+    let proof = witness.merkle_proof().raw_data();
+    let ring_cell = match witness.ring_cell_dep().to_opt() {
+        Some(index) => Some(load_cell_dep(u32::from(index) as usize)?),
+        None => None,
     };
+    let ring_cell = ring_cell.as_deref();
+    // The signature must be of the scheme of the election
+    match (witness.signature().to_enum(), config.signature_scheme()) {
+        (RingSignatureUnionReader::RsaRingSignature(signature), SIGNATURE_SCHEME_RSA) => {
+            verify_rsa_vote(
+                config,
+                election_id,
+                message,
+                image,
+                signature,
+                leaf_index,
+                proof,
+                ring_cell,
+            )
+        }
+        (RingSignatureUnionReader::LsagRingSignature(signature), SIGNATURE_SCHEME_LSAG) => {
+            verify_lsag_vote(
+                config,
+                election_id,
+                message,
+                image,
+                signature,
+                leaf_index,
+                proof,
+                ring_cell,
+            )
+        }
+        _ => Err(VoteError::SignatureSchemeMismatch),
+    }
+}
+
+/// The ring must be the whole ring at its leaf index, not a part of it, so a voter with several
+/// keys can't make rings of another partition
+fn verify_ring_size(
+    config: &VoteConfig,
+    leaf_index: usize,
+    ring_size: usize,
+) -> Result<(), VoteError> {
+    if ring_size == 0 {
+        return Err(VoteError::EmptyRing);
+    }
+    if ring_size > config.group_size() {
+        return Err(VoteError::RingTooLarge);
+    }
+    if ring_size != vote_types::ring_size(config.user_count(), config.group_size(), leaf_index) {
+        return Err(VoteError::UnexpectedRingSize);
+    }
+    Ok(())
+}
+
+/// Keys of the ring in the registry cell `ring_cell`, if the witness references one, in which
+/// case the signature must have no keys
+fn referenced_ring<'a>(
+    config: &VoteConfig,
+    ring_cell: Option<&'a [u8]>,
+    leaf_index: usize,
+    ring_size: usize,
+    keys_in_witness: usize,
+) -> Result<Option<&'a [u8]>, VoteError> {
+    let Some(ring_cell) = ring_cell else {
+        return Ok(None);
+    };
+    if keys_in_witness != 0 {
+        return Err(VoteError::RingKeysInWitness);
+    }
+    verify_ring_size(config, leaf_index, ring_size)?;
+    ring_public_keys(config, ring_cell, leaf_index, ring_size).map(Some)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn verify_rsa_vote(
+    config: &VoteConfig,
+    election_id: &[u8],
+    message: &[u8],
+    image: &[u8],
+    signature: RsaRingSignatureReader,
+    leaf_index: usize,
+    proof: &[u8],
+    ring_cell: Option<&[u8]>,
+) -> Result<(), VoteError> {
+    let ring_size = signature.r().len();
+    ckb_std::debug!("ring_size={}", ring_size);
+    // All big integers are in the size of RSA keys of the election
+    let key_bytes = config.rsa_key_bytes();
+    let keys_in_witness = signature.n().len().max(signature.e().len());
+    let inline_keys;
+    let public_keys =
+        match referenced_ring(config, ring_cell, leaf_index, ring_size, keys_in_witness)? {
+            Some(public_keys) => public_keys,
+            None => {
+                if signature.n().len() != ring_size || signature.e().len() != ring_size {
+                    return Err(VoteError::RingSizeMismatch);
+                }
+                verify_ring_size(config, leaf_index, ring_size)?;
+                inline_keys = concat_public_keys(signature.n(), signature.e(), key_bytes)?;
+                &inline_keys
+            }
+        };
+    if signature.c().raw_data().len() != key_bytes {
+        return Err(VoteError::BadIntegerSize);
+    }
+    let r_arr = concat_integers(signature.r(), key_bytes)?;
+    verify_merkle_proof(
+        proof,
+        config.leaf_count(),
+        leaf_index,
+        config.merkle_root(),
+        leaf_hash(public_keys),
+    )?;
+    ckb_std::debug!("merkle proof verified");
+    verify_signature_of_size(
+        config.rsa_key_bits(),
+        ring_size,
+        election_id,
+        message,
+        public_keys,
+        signature.c().raw_data(),
+        &r_arr,
+        image,
+    )?;
+    ckb_std::debug!("signature verified");
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn verify_lsag_vote(
+    config: &VoteConfig,
+    election_id: &[u8],
+    message: &[u8],
+    image: &[u8],
+    signature: LsagRingSignatureReader,
+    leaf_index: usize,
+    proof: &[u8],
+    ring_cell: Option<&[u8]>,
+) -> Result<(), VoteError> {
+    let ring_size = signature.r().len();
+    ckb_std::debug!("ring_size={}", ring_size);
+    let keys_in_witness = signature.public_keys().len();
+    let public_keys =
+        match referenced_ring(config, ring_cell, leaf_index, ring_size, keys_in_witness)? {
+            Some(public_keys) => public_keys,
+            None => {
+                if keys_in_witness != ring_size {
+                    return Err(VoteError::RingSizeMismatch);
+                }
+                verify_ring_size(config, leaf_index, ring_size)?;
+                // Items of fixed size vectors are laid out one by one after the item count
+                &signature.public_keys().as_slice()[NUMBER_SIZE..]
+            }
+        };
+    let r_arr = &signature.r().as_slice()[NUMBER_SIZE..];
+    verify_merkle_proof(
+        proof,
+        config.leaf_count(),
+        leaf_index,
+        config.merkle_root(),
+        leaf_hash(public_keys),
+    )?;
     ckb_std::debug!("merkle proof verified");
-    verify_signature(
+    lsag::verify_signature(
         ring_size,
-        &vote_cell_data[0..4],
-        n_arr,
-        e_arr,
-        &output_type_witness[0..256],
-        &output_type_witness[256 + 4..256 + 4 + 256 * ring_size],
-        &vote_cell_data[4..4 + 256],
+        election_id,
+        message,
+        public_keys,
+        signature.c().raw_data(),
+        r_arr,
+        image,
     )?;
     ckb_std::debug!("signature verified");
     Ok(())
 }
+
+/// Public keys of an RSA ring in the witness, each `n` followed by `e`, in the encoding of
+/// registry cells. `n` must be of `size` bytes
+fn concat_public_keys(
+    n: BytesVecReader,
+    e: Uint32VecReader,
+    size: usize,
+) -> Result<Vec<u8>, VoteError> {
+    let mut result = Vec::with_capacity(n.len() * (size + 4));
+    for (n, e) in n.iter().zip(e.iter()) {
+        if n.raw_data().len() != size {
+            return Err(VoteError::BadIntegerSize);
+        }
+        result.extend_from_slice(n.raw_data());
+        result.extend_from_slice(e.as_slice());
+    }
+    Ok(result)
+}
+
+/// Concatenate integers of a `BytesVec`, all of which must be of `size` bytes
+fn concat_integers(integers: BytesVecReader, size: usize) -> Result<Vec<u8>, VoteError> {
+    let mut result = Vec::with_capacity(integers.len() * size);
+    for integer in integers.iter() {
+        if integer.raw_data().len() != size {
+            return Err(VoteError::BadIntegerSize);
+        }
+        result.extend_from_slice(integer.raw_data());
+    }
+    Ok(result)
+}
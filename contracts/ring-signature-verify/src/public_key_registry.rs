@@ -0,0 +1,57 @@
+//! Public key registry
+//!
+//! Registry cells are typed by this same script, with the type hash of the config cell of their
+//! election in the args, and publish the public keys of the election in chunks, so voters can
+//! rebuild every ring and its merkle proof from the chain alone. Only the administrator of the
+//! election creates or destroys them. Whether the keys add up to the merkle root of the config
+//! is left to readers, which rebuild the tree anyway.
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::prelude::*,
+    high_level::{load_cell_data, load_cell_lock_hash, QueryIter},
+};
+use vote_types::schema::PublicKeyRegistryCellReader;
+
+use crate::{config::VoteConfig, VoteError};
+
+pub fn verify_public_key_registry(config_type_hash: &[u8]) -> Result<(), VoteError> {
+    let config = VoteConfig::load(config_type_hash)?;
+    // The admin lock guards the config cell, so an input of it proves the administrator signed
+    if !QueryIter::new(load_cell_lock_hash, Source::Input)
+        .any(|lock_hash| lock_hash[..] == *config.admin_lock_hash())
+    {
+        return Err(VoteError::UnauthorizedPublicKeyRegistry);
+    }
+    let key_size = config.public_key_size();
+    for data in QueryIter::new(load_cell_data, Source::GroupOutput) {
+        let cell = PublicKeyRegistryCellReader::from_slice(&data)
+            .map_err(|_| VoteError::BadPublicKeyRegistry)?;
+        let public_keys = cell.public_keys().raw_data();
+        if public_keys.is_empty() || !public_keys.len().is_multiple_of(key_size) {
+            return Err(VoteError::BadPublicKeyRegistry);
+        }
+    }
+    Ok(())
+}
+
+/// Keys of the ring at `leaf_index` in the data of a registry cell, which a vote references
+/// instead of carrying them. The cell isn't checked to be a registry cell of the election, since
+/// the merkle proof of the vote checks the keys anyway
+pub fn ring_public_keys<'a>(
+    config: &VoteConfig,
+    data: &'a [u8],
+    leaf_index: usize,
+    ring_size: usize,
+) -> Result<&'a [u8], VoteError> {
+    let cell = PublicKeyRegistryCellReader::from_slice(data)
+        .map_err(|_| VoteError::BadPublicKeyRegistry)?;
+    let key_size = config.public_key_size();
+    // The ring is of the size at its leaf index, so it starts before the last key
+    let offset = (leaf_index * config.group_size())
+        .checked_sub(u32::from(cell.start()) as usize)
+        .ok_or(VoteError::RingNotInRegistry)?;
+    cell.public_keys()
+        .raw_data()
+        .get(offset * key_size..(offset + ring_size) * key_size)
+        .ok_or(VoteError::RingNotInRegistry)
+}
@@ -0,0 +1,416 @@
+//! Property tests of verifying votes made of arbitrary config, vote cell and witness bytes
+//!
+//! Whatever the bytes are, verification must return an error instead of panicking, and must not
+//! accept a signature which no key of the ring made. Loops of verification are bounded by the
+//! ring size, which is bounded by the size of the witness, so nothing could loop forever.
+use curve25519_dalek::{RistrettoPoint, Scalar};
+use proptest::{collection::vec, prelude::*, sample::Index};
+use sha2::{Digest, Sha256, Sha512};
+use vote_types::{
+    molecule::prelude::*,
+    schema::{
+        Byte32Vec, BytesVec, Candidate, CandidateVec, LsagRingSignature, PublicKeyRegistryCell,
+        RingSignature, RsaRingSignature, Uint32Opt, Uint32Vec, VoteCell, VoteConfig, VoteWitness,
+    },
+    SIGNATURE_SCHEME_LSAG, SIGNATURE_SCHEME_RSA,
+};
+
+use super::{verify, ELECTION_ID};
+
+/// Rings are generated up to this size, with group sizes around it
+const MAX_RING_SIZE: usize = 4;
+
+#[derive(Debug, Clone)]
+enum Signature {
+    Rsa {
+        c: Vec<u8>,
+        r: Vec<Vec<u8>>,
+        n: Vec<Vec<u8>>,
+        e: Vec<u32>,
+    },
+    Lsag {
+        c: [u8; 32],
+        r: Vec<[u8; 32]>,
+        public_keys: Vec<[u8; 32]>,
+    },
+}
+
+impl Signature {
+    /// Number of keys of the ring
+    fn ring_size(&self) -> usize {
+        match self {
+            Signature::Rsa { n, .. } => n.len(),
+            Signature::Lsag { public_keys, .. } => public_keys.len(),
+        }
+    }
+
+    /// Keys of the ring concatenated as in registry cells
+    fn public_keys(&self) -> Vec<u8> {
+        match self {
+            Signature::Rsa { n, e, .. } => n
+                .iter()
+                .zip(e)
+                .flat_map(|(n, e)| [&n[..], &e.to_le_bytes()].concat())
+                .collect(),
+            Signature::Lsag { public_keys, .. } => public_keys.concat(),
+        }
+    }
+
+    /// Leaf hash of the ring, computed the same way as the contract when sizes are right
+    fn leaf_hash(&self) -> [u8; 32] {
+        Sha256::digest(self.public_keys()).into()
+    }
+
+    /// The signature with the keys left out, for the ring in a registry cell
+    fn without_keys(&self) -> Self {
+        match self.clone() {
+            Signature::Rsa { c, r, .. } => Signature::Rsa {
+                c,
+                r,
+                n: vec![],
+                e: vec![],
+            },
+            Signature::Lsag { c, r, .. } => Signature::Lsag {
+                c,
+                r,
+                public_keys: vec![],
+            },
+        }
+    }
+
+    fn encode(&self) -> RingSignature {
+        let bytes_vec = |items: &[Vec<u8>]| {
+            BytesVec::new_builder()
+                .set(items.iter().map(|item| item.as_slice().into()).collect())
+                .build()
+        };
+        let byte32_vec = |items: &[[u8; 32]]| {
+            Byte32Vec::new_builder()
+                .set(items.iter().map(|&item| item.into()).collect())
+                .build()
+        };
+        let builder = RingSignature::new_builder();
+        match self {
+            Signature::Rsa { c, r, n, e } => builder.set(
+                RsaRingSignature::new_builder()
+                    .c(c.as_slice().into())
+                    .r(bytes_vec(r))
+                    .n(bytes_vec(n))
+                    .e(Uint32Vec::new_builder()
+                        .set(e.iter().map(|&e| e.into()).collect())
+                        .build())
+                    .build(),
+            ),
+            Signature::Lsag { c, r, public_keys } => builder.set(
+                LsagRingSignature::new_builder()
+                    .c((*c).into())
+                    .r(byte32_vec(r))
+                    .public_keys(byte32_vec(public_keys))
+                    .build(),
+            ),
+        }
+        .build()
+    }
+}
+
+/// Fields of a config, a vote cell and a witness, which are mostly close to valid ones
+#[derive(Debug, Clone)]
+struct Vote {
+    version: u8,
+    merkle_root: [u8; 32],
+    leaf_count: u32,
+    group_size: u32,
+    signature_scheme: u8,
+    rsa_key_bits: u32,
+    candidates: Vec<[u8; 4]>,
+    candidate_id: [u8; 4],
+    /// Whether `candidate_id` is also one of the candidates
+    known_candidate: bool,
+    image: Vec<u8>,
+    signature: Signature,
+    leaf_index: u32,
+    merkle_proof: Vec<u8>,
+    /// Whether the merkle tree is the ring of the signature alone, so verification goes on to
+    /// the signature
+    single_leaf: bool,
+    /// `start` of a registry cell in cell dep 0 with the keys of the ring, which are then left
+    /// out of the signature
+    ring_cell_start: Option<u32>,
+}
+
+impl Vote {
+    /// Config, vote cell, witness and data of cell deps
+    fn encode(&self) -> (Vec<u8>, Vec<u8>, Vec<u8>, Vec<Vec<u8>>) {
+        // The user count follows the leaf count, so the config is consistent when the group
+        // size is not zero
+        let (merkle_root, user_count, leaf_count, leaf_index, merkle_proof) = if self.single_leaf {
+            let ring_size = self.signature.ring_size() as u32;
+            (self.signature.leaf_hash(), ring_size, 1, 0, &[][..])
+        } else {
+            (
+                self.merkle_root,
+                self.leaf_count.saturating_mul(self.group_size),
+                self.leaf_count,
+                self.leaf_index,
+                &self.merkle_proof[..],
+            )
+        };
+        let mut candidates = self.candidates.clone();
+        if self.known_candidate {
+            candidates.push(self.candidate_id);
+        }
+        let config = VoteConfig::new_builder()
+            .version(Byte::new(self.version))
+            .merkle_root(merkle_root.into())
+            .user_count(user_count.into())
+            .leaf_count(leaf_count.into())
+            .group_size(self.group_size.into())
+            .signature_scheme(Byte::new(self.signature_scheme))
+            .rsa_key_bits(self.rsa_key_bits.into())
+            .voting_end(u64::MAX.into())
+            .candidates(
+                CandidateVec::new_builder()
+                    .set(
+                        candidates
+                            .into_iter()
+                            .map(|id| Candidate::new_builder().id(id.into()).build())
+                            .collect(),
+                    )
+                    .build(),
+            )
+            .build();
+        let vote_cell = VoteCell::new_builder()
+            .candidate_id(self.candidate_id.into())
+            .image(self.image.as_slice().into())
+            .build();
+        let (signature, ring_cell_dep, cell_deps) = match self.ring_cell_start {
+            Some(start) => {
+                let ring_cell = PublicKeyRegistryCell::new_builder()
+                    .start(start.into())
+                    .public_keys(self.signature.public_keys().as_slice().into())
+                    .build();
+                (
+                    self.signature.without_keys(),
+                    Uint32Opt::new_builder().set(Some(0u32.into())).build(),
+                    vec![ring_cell.as_slice().to_vec()],
+                )
+            }
+            None => (self.signature.clone(), Uint32Opt::default(), vec![]),
+        };
+        let witness = VoteWitness::new_builder()
+            .signature(signature.encode())
+            .leaf_index(leaf_index.into())
+            .merkle_proof(merkle_proof.into())
+            .ring_cell_dep(ring_cell_dep)
+            .build();
+        (
+            config.as_slice().to_vec(),
+            vote_cell.as_slice().to_vec(),
+            witness.as_slice().to_vec(),
+            cell_deps,
+        )
+    }
+}
+
+/// Small values are the ones passing checks, so they are as likely as any other
+fn small_or_any(max_small: u32) -> BoxedStrategy<u32> {
+    prop_oneof![0..=max_small, any::<u32>()].boxed()
+}
+
+/// Big integer of RSA, mostly of `size` bytes
+fn integer(size: usize) -> BoxedStrategy<Vec<u8>> {
+    let mut one = vec![0; size];
+    if let Some(byte) = one.first_mut() {
+        *byte = 1;
+    }
+    prop_oneof![
+        4 => vec(any::<u8>(), size),
+        1 => Just(vec![0; size]),
+        1 => Just(one),
+        1 => Just(vec![0xff; size]),
+        1 => vec(any::<u8>(), 0..size + 8),
+    ]
+    .boxed()
+}
+
+fn exponent() -> BoxedStrategy<u32> {
+    prop_oneof![Just(65537), Just(3), Just(1), Just(0), any::<u32>()].boxed()
+}
+
+/// Scalar of LSAG, canonical or not
+fn scalar() -> BoxedStrategy<[u8; 32]> {
+    prop_oneof![
+        any::<[u8; 32]>().prop_map(|bytes| Scalar::from_bytes_mod_order(bytes).to_bytes()),
+        any::<[u8; 32]>(),
+    ]
+    .boxed()
+}
+
+/// Compressed point of LSAG, which decompresses or not
+fn point() -> BoxedStrategy<[u8; 32]> {
+    prop_oneof![
+        any::<[u8; 32]>().prop_map(|bytes| RistrettoPoint::hash_from_bytes::<Sha512>(&bytes)
+            .compress()
+            .to_bytes()),
+        any::<[u8; 32]>(),
+    ]
+    .boxed()
+}
+
+/// Mostly `ring_size` items, sometimes another count
+fn items<T: std::fmt::Debug + 'static>(
+    item: BoxedStrategy<T>,
+    ring_size: usize,
+) -> BoxedStrategy<Vec<T>> {
+    prop_oneof![
+        4 => vec(item.clone(), ring_size),
+        1 => vec(item, 0..=MAX_RING_SIZE + 1),
+    ]
+    .boxed()
+}
+
+/// Signature of the scheme of the config, sometimes of the other one
+fn signature(lsag: bool, key_bytes: usize, ring_size: usize) -> BoxedStrategy<Signature> {
+    let rsa = (
+        integer(key_bytes),
+        items(integer(key_bytes), ring_size),
+        items(integer(key_bytes), ring_size),
+        items(exponent(), ring_size),
+    )
+        .prop_map(|(c, r, n, e)| Signature::Rsa { c, r, n, e });
+    let lsag_signature = (
+        scalar(),
+        items(scalar(), ring_size),
+        items(point(), ring_size),
+    )
+        .prop_map(|(c, r, public_keys)| Signature::Lsag { c, r, public_keys });
+    if lsag {
+        prop_oneof![9 => lsag_signature, 1 => rsa].boxed()
+    } else {
+        prop_oneof![9 => rsa, 1 => lsag_signature].boxed()
+    }
+}
+
+fn vote() -> impl Strategy<Value = Vote> {
+    let scheme = prop_oneof![
+        1 => Just((SIGNATURE_SCHEME_RSA, 1024u32)),
+        1 => Just((SIGNATURE_SCHEME_RSA, 2048)),
+        2 => Just((SIGNATURE_SCHEME_LSAG, 0)),
+        1 => (any::<u8>(), any::<u32>()),
+    ];
+    (scheme, 0..=MAX_RING_SIZE).prop_flat_map(|((signature_scheme, rsa_key_bits), ring_size)| {
+        let lsag = signature_scheme == SIGNATURE_SCHEME_LSAG;
+        // Sizes of arbitrary key bits are capped, so integers stay small
+        let key_bytes = (rsa_key_bits / 8).min(600) as usize;
+        let config = (
+            prop_oneof![9 => Just(2u8), 1 => any::<u8>()],
+            any::<[u8; 32]>(),
+            small_or_any(4),
+            prop_oneof![
+                3 => Just(MAX_RING_SIZE as u32),
+                1 => small_or_any(MAX_RING_SIZE as u32 + 1),
+            ],
+            vec(any::<[u8; 4]>(), 0..=3),
+        );
+        let image = if lsag {
+            point().prop_map(Vec::from).boxed()
+        } else {
+            integer(key_bytes)
+        };
+        let vote_cell = (
+            any::<[u8; 4]>(),
+            prop::bool::weighted(0.9),
+            prop_oneof![9 => image, 1 => vec(any::<u8>(), 0..64)],
+        );
+        let witness = (
+            signature(lsag, key_bytes, ring_size),
+            small_or_any(4),
+            prop_oneof![
+                vec(any::<[u8; 32]>(), 0..=3).prop_map(|hashes| hashes.concat()),
+                vec(any::<u8>(), 0..=128),
+            ],
+            prop::bool::weighted(0.7),
+            prop_oneof![2 => Just(None), 1 => Just(Some(0)), 1 => small_or_any(8).prop_map(Some)],
+        );
+        (
+            Just((signature_scheme, rsa_key_bits)),
+            config,
+            vote_cell,
+            witness,
+        )
+            .prop_map(
+                |(
+                    (signature_scheme, rsa_key_bits),
+                    (version, merkle_root, leaf_count, group_size, candidates),
+                    (candidate_id, known_candidate, image),
+                    (signature, leaf_index, merkle_proof, single_leaf, ring_cell_start),
+                )| Vote {
+                    version,
+                    merkle_root,
+                    leaf_count,
+                    group_size,
+                    signature_scheme,
+                    rsa_key_bits,
+                    candidates,
+                    candidate_id,
+                    known_candidate,
+                    image,
+                    signature,
+                    leaf_index,
+                    merkle_proof,
+                    single_leaf,
+                    ring_cell_start,
+                },
+            )
+    })
+}
+
+proptest! {
+    #[test]
+    fn arbitrary_bytes_are_rejected(
+        config in vec(any::<u8>(), 0..600),
+        vote_cell in vec(any::<u8>(), 0..300),
+        witness in vec(any::<u8>(), 0..600),
+    ) {
+        prop_assert!(verify(&ELECTION_ID, &[0; 32], config, &vote_cell, &witness, &[]).is_err());
+    }
+
+    #[test]
+    fn arbitrary_votes_are_rejected(vote in vote()) {
+        let (config, vote_cell, witness, cell_deps) = vote.encode();
+        prop_assert!(
+            verify(&ELECTION_ID, &[0; 32], config, &vote_cell, &witness, &cell_deps).is_err()
+        );
+    }
+
+    /// Any byte changed in the vote cell or the witness of a valid vote makes it invalid
+    #[test]
+    fn tampered_votes_are_rejected(
+        vector in any::<Index>(),
+        in_witness in any::<bool>(),
+        position in any::<Index>(),
+        flip in 1..=u8::MAX,
+    ) {
+        let vectors = test_vectors::load()
+            .into_iter()
+            .filter(|vector| vector.exit_code == 0)
+            .collect::<Vec<_>>();
+        let mut vector = vector.get(&vectors).clone();
+        let data = if in_witness {
+            &mut vector.witness
+        } else {
+            &mut vector.vote_cell
+        };
+        let position = position.index(data.len());
+        data[position] ^= flip;
+        prop_assert!(verify(
+            &vector.election_id,
+            &vector.contract_code_hash,
+            vector.config,
+            &vector.vote_cell,
+            &vector.witness,
+            &[],
+        )
+        .is_err());
+    }
+}
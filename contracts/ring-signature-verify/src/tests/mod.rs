@@ -1,24 +1,143 @@
-use crate::verify_signature;
+use bnum::{cast::As, BUint};
 
-const MESSAGE: &'static [u8] = b"hello, world!";
+use crate::{
+    config::VoteConfig,
+    utils::{mul_mod_expand, Montgomery},
+    verify_signature_of_size, verify_vote_cell, verify_vote_witness, VoteError,
+};
+use vote_types::ballot_message;
+
+mod fuzz;
+
+const MESSAGE: &[u8] = b"hello, world!";
+/// Election of the signature of `sign-rsa2l-700.bin`
+const ELECTION_ID: [u8; 32] = *b"ckb-vote known answer election!!";
+
+/// Verify a vote in the same way as `verify_all`, with the election id, the code hash of the
+/// contract, the config, the vote cell, the `output_type` of its witness and data of cell deps
+/// given instead of loaded from the transaction
+fn verify(
+    election_id: &[u8; 32],
+    contract_code_hash: &[u8; 32],
+    config: Vec<u8>,
+    vote_cell: &[u8],
+    witness: &[u8],
+    cell_deps: &[Vec<u8>],
+) -> Result<(), VoteError> {
+    let config = VoteConfig::from_data(config)?;
+    let (candidate_id, image) = verify_vote_cell(&config, vote_cell)?;
+    let message = ballot_message(
+        election_id,
+        &config.network(),
+        contract_code_hash,
+        candidate_id.try_into().unwrap(),
+    );
+    verify_vote_witness(&config, election_id, &message, image, witness, |index| {
+        cell_deps
+            .get(index)
+            .cloned()
+            .ok_or(VoteError::IndexOutOfBound)
+    })
+}
 
 #[test]
 fn test_signature_verify() {
+    // Generated from a fixed seed by `generate_contract_test_vector` of contract-tests
     let buf = include_bytes!("./sign-rsa2l-700.bin");
 
     let n = u32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]) as usize;
     assert_eq!(buf.len(), 4 + 256 + 256 + n * (256 + 256 + 4));
     let c0 = &buf[4..4 + 256];
     let image = &buf[4 + 256..4 + 256 + 256];
-    let mut n_buf: Vec<u8> = vec![];
-    let mut e_buf: Vec<u8> = vec![];
+    // Public keys are `n` followed by `e`, as in registry cells
+    let mut public_keys: Vec<u8> = vec![];
     let mut r_buf: Vec<u8> = vec![];
     for i in 0..n {
         let start_offset = 4 + 256 + 256 + i * (256 + 256 + 4);
         r_buf.extend(&buf[start_offset..start_offset + 256]);
         let e_bytes = &buf[start_offset + 256..start_offset + 256 + 4];
-        e_buf.extend(e_bytes);
-        n_buf.extend(&buf[start_offset + 256 + 4..start_offset + 256 + 4 + 256]);
+        public_keys.extend(&buf[start_offset + 256 + 4..start_offset + 256 + 4 + 256]);
+        public_keys.extend(e_bytes);
+    }
+    let verify = |key_bits, election_id: &[u8]| {
+        verify_signature_of_size(
+            key_bits,
+            n,
+            election_id,
+            MESSAGE,
+            &public_keys,
+            c0,
+            &r_buf,
+            image,
+        )
+    };
+    verify(2048, &ELECTION_ID).unwrap();
+    // The key image is of the election
+    assert!(matches!(
+        verify(2048, &[0; 32]),
+        Err(VoteError::BadSignature)
+    ));
+    assert!(matches!(
+        verify(1000, &ELECTION_ID),
+        Err(VoteError::UnsupportedKeySize)
+    ));
+}
+
+#[test]
+fn test_vectors() {
+    for vector in test_vectors::load() {
+        let exit_code = verify(
+            &vector.election_id,
+            &vector.contract_code_hash,
+            vector.config,
+            &vector.vote_cell,
+            &vector.witness,
+            &[],
+        )
+        .map_or_else(VoteError::code, |_| 0);
+        assert_eq!(exit_code, vector.exit_code, "{}", vector.name);
+    }
+}
+
+#[test]
+fn test_montgomery() {
+    // xorshift, so results are reproducible without a rng crate in the contract
+    let mut state = 0x9e37_79b9_7f4a_7c15u64;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    let mut moduli = vec![BUint::<4>::ONE, BUint::from(3u8), BUint::MAX];
+    moduli.extend((0..20).map(|_| BUint::from_digits([0; 4].map(|_| next())) | BUint::ONE));
+    for n in moduli {
+        let n_mod = Montgomery::new::<8>(n).unwrap();
+        let mul_mod = |a, b| mul_mod_expand::<4, 8>(a, b, n);
+        let pow_mod = |a, e: u32| {
+            (0..32).rev().fold(BUint::ONE % n, |acc, bit| {
+                let acc = mul_mod(acc, acc);
+                if e >> bit & 1 == 1 {
+                    mul_mod(acc, a)
+                } else {
+                    acc
+                }
+            })
+        };
+        for _ in 0..20 {
+            let a = BUint::<4>::from_digits([0; 4].map(|_| next()));
+            let b = BUint::<4>::from_digits([0; 4].map(|_| next()));
+            let e = next() as u32;
+            assert_eq!(n_mod.reduce(&a), a % n);
+            assert_eq!(n_mod.mul(&a, &n_mod.to_montgomery(&b)), mul_mod(a, b));
+            let sum: BUint<8> = (a % n).as_::<BUint<8>>() + (b % n).as_::<BUint<8>>();
+            assert_eq!(
+                n_mod.add(&(a % n), &(b % n)),
+                (sum % n.as_::<BUint<8>>()).as_::<BUint<4>>()
+            );
+            let power = n_mod.mul(&n_mod.pow(&n_mod.to_montgomery(&a), e.into()), &BUint::ONE);
+            assert_eq!(power, pow_mod(a, e));
+        }
     }
-    verify_signature(n, &MESSAGE, &n_buf, &e_buf, c0, &r_buf, &image).unwrap();
+    assert!(Montgomery::<4>::new::<8>(BUint::from(4u8)).is_none());
 }
@@ -10,37 +10,117 @@ pub fn mul_mod_expand<const S: usize, const S2: usize>(
     result.as_()
 }
 
-pub fn add_mod_expand<const S: usize, const S2: usize>(
-    a: BUint<S>,
-    b: BUint<S>,
-    p: BUint<S>,
-) -> BUint<S> {
-    let c = (a.as_::<BUint<S2>>()) + (b.as_::<BUint<S2>>());
-    let result = c % p.as_::<BUint<S2>>();
-    result.as_()
+/// Montgomery arithmetic modulo an odd `n` of `S` digits, with `R = 2^(64 * S)`
+///
+/// A multiplication costs about two `S * S` digit products and no division, while reducing
+/// a product of `S2` digits with `%` runs a long division, which is what dominates cycles of
+/// `power_mod`-style square-and-multiply.
+pub struct Montgomery<const S: usize> {
+    n: BUint<S>,
+    /// `-n^-1 mod 2^64`
+    n_prime: u64,
+    /// `R mod n`, which is 1 in Montgomery form
+    one: BUint<S>,
+    /// `R^2 mod n`, used to bring integers into Montgomery form
+    r2: BUint<S>,
 }
 
-pub unsafe fn mul_mod<const S: usize>(a: BUint<S>, b: BUint<S>, p: BUint<S>) -> BUint<S> {
-    let c: BUint<S> = (a).unchecked_mul(b);
-    let result = c % p;
-    result
-}
+impl<const S: usize> Montgomery<S> {
+    /// Returns `None` for even moduli, which have no inverse modulo `R`
+    pub fn new<const S2: usize>(n: BUint<S>) -> Option<Self> {
+        let n0 = n.digits()[0];
+        if n0 & 1 == 0 {
+            return None;
+        }
+        // Newton's iteration doubles the number of correct low bits every round
+        let mut inv: u64 = 1;
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(n0.wrapping_mul(inv)));
+        }
+        // R mod n = (R - 1) mod n + 1, which is n only if n is 1
+        let mut one = BUint::<S>::MAX % n + BUint::ONE;
+        if one == n {
+            one = BUint::ZERO;
+        }
+        Some(Self {
+            n,
+            n_prime: inv.wrapping_neg(),
+            one,
+            r2: mul_mod_expand::<S, S2>(one, one, n),
+        })
+    }
 
-pub fn power_mod<const S: usize, const S2: usize>(
-    base: BUint<S>,
-    mut index: u64,
-    modular: BUint<S>,
-) -> BUint<S> {
-    let mut base: BUint<S2> = base.as_();
-    let mut result = BUint::<S2>::ONE;
-    let modular: BUint<S2> = modular.as_();
+    /// `a * b / R mod n`, as long as `a * b < n * R`, which holds if either is below `n`
+    pub fn mul(&self, a: &BUint<S>, b: &BUint<S>) -> BUint<S> {
+        let (a, b, n) = (a.digits(), b.digits(), self.n.digits());
+        // t has two more digits than n, kept in t_high
+        let mut t = [0u64; S];
+        let mut t_high = [0u64; 2];
+        for &b_digit in b.iter() {
+            let mut carry = 0u64;
+            for j in 0..S {
+                let x = t[j] as u128 + a[j] as u128 * b_digit as u128 + carry as u128;
+                t[j] = x as u64;
+                carry = (x >> 64) as u64;
+            }
+            let x = t_high[0] as u128 + carry as u128;
+            t_high = [x as u64, (x >> 64) as u64];
 
-    while index != 0 {
-        if (index & 1) == 1 {
-            result = unsafe { mul_mod::<S2>(result, base, modular) };
+            // Add a multiple of n to clear the lowest digit, then shift it out
+            let m = t[0].wrapping_mul(self.n_prime);
+            let x = t[0] as u128 + m as u128 * n[0] as u128;
+            let mut carry = (x >> 64) as u64;
+            for j in 1..S {
+                let x = t[j] as u128 + m as u128 * n[j] as u128 + carry as u128;
+                t[j - 1] = x as u64;
+                carry = (x >> 64) as u64;
+            }
+            let x = t_high[0] as u128 + carry as u128;
+            t[S - 1] = x as u64;
+            t_high = [t_high[1] + (x >> 64) as u64, 0];
+        }
+        // The result is below 2n, and it overflows R only if it's above n
+        let t = BUint::from_digits(t);
+        if t_high[0] != 0 || t >= self.n {
+            t.wrapping_sub(self.n)
+        } else {
+            t
         }
-        base = unsafe { mul_mod::<S2>(base, base, modular) };
-        index >>= 1;
     }
-    result.as_()
+
+    /// Montgomery form of any `a`, which is `a * R mod n`
+    pub fn to_montgomery(&self, a: &BUint<S>) -> BUint<S> {
+        self.mul(a, &self.r2)
+    }
+
+    /// `a mod n`, without a division
+    pub fn reduce(&self, a: &BUint<S>) -> BUint<S> {
+        self.mul(&self.to_montgomery(a), &BUint::ONE)
+    }
+
+    /// `a + b mod n`, for `a` and `b` below `n`
+    pub fn add(&self, a: &BUint<S>, b: &BUint<S>) -> BUint<S> {
+        let (sum, overflow) = a.overflowing_add(*b);
+        if overflow || sum >= self.n {
+            sum.wrapping_sub(self.n)
+        } else {
+            sum
+        }
+    }
+
+    /// `base ^ index` in Montgomery form, with `base` in Montgomery form
+    pub fn pow(&self, base: &BUint<S>, mut index: u64) -> BUint<S> {
+        let mut base = *base;
+        let mut result = self.one;
+        while index != 0 {
+            if (index & 1) == 1 {
+                result = self.mul(&result, &base);
+            }
+            index >>= 1;
+            if index != 0 {
+                base = self.mul(&base, &base);
+            }
+        }
+        result
+    }
 }
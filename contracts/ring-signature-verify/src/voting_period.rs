@@ -0,0 +1,51 @@
+//! Voting period
+//!
+//! A transaction can only prove that it was committed after some block, either by an absolute
+//! block number `since` of one of its inputs, or by a header dep. The greatest such block must
+//! be in `[start, end)`. Nothing on chain can prove a vote is not too late, so votes committed
+//! after `end` are dropped by vote-counting.
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::prelude::*,
+    high_level::{load_header, load_input_since, QueryIter},
+    since::{LockValue, Since},
+};
+
+use crate::VoteError;
+
+fn committed_after() -> u64 {
+    let since_block = QueryIter::new(load_input_since, Source::Input)
+        .map(Since::new)
+        .filter(|since| since.is_absolute())
+        .filter_map(|since| match since.extract_lock_value() {
+            Some(LockValue::BlockNumber(number)) => Some(number),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0);
+    let header_dep_block = QueryIter::new(load_header, Source::HeaderDep)
+        .map(|header| header.raw().number().unpack())
+        .max()
+        .unwrap_or(0);
+    since_block.max(header_dep_block)
+}
+
+pub fn verify_voting_period(start: u64, end: u64) -> Result<(), VoteError> {
+    if start >= end {
+        return Err(VoteError::BadVotingPeriod);
+    }
+    let block_number = committed_after();
+    ckb_std::debug!(
+        "committed after block {}, voting period = [{}, {})",
+        block_number,
+        start,
+        end
+    );
+    if block_number < start {
+        return Err(VoteError::VotingNotStarted);
+    }
+    if block_number >= end {
+        return Err(VoteError::VotingEnded);
+    }
+    Ok(())
+}
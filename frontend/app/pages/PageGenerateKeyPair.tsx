@@ -1,5 +1,7 @@
 import { useState } from "react";
 import { Button, Dimmer, Divider, Form, Loader, Message } from "semantic-ui-react";
+import { derive_lsag_key_pair_from_rand_seed } from "signature-tools-wasm";
+import { LsagKeyPair, randSeed, uint8ArrToHex } from "../utils";
 
 const PageGenerateKeyPair: React.FC<{}> = () => {
     const [loading, setLoading] = useState(false);
@@ -9,14 +11,14 @@ const PageGenerateKeyPair: React.FC<{}> = () => {
     const doGenerate = async () => {
         try {
             setLoading(true);
-            const key = await window.crypto.subtle.generateKey({
-                name: "RSA-OAEP", modulusLength: 2048,
-                publicExponent: new Uint8Array([1, 0, 1]),
-                hash: "SHA-256",
-            },
-                true, ["decrypt", "encrypt"]);
-            setPubKey(JSON.stringify(await window.crypto.subtle.exportKey("jwk", key.publicKey)));
-            setPrivateKey(JSON.stringify(await window.crypto.subtle.exportKey("jwk", key.privateKey)));
+            // Elections are LSAG only, the contract refuses RSA ones
+            const key = derive_lsag_key_pair_from_rand_seed(randSeed());
+            const pair: LsagKeyPair = {
+                privateKey: uint8ArrToHex(key.private_key),
+                publicKey: uint8ArrToHex(key.public_key),
+            };
+            setPubKey(pair.publicKey);
+            setPrivateKey(JSON.stringify(pair));
 
         } catch (e) {
             alert(e);
@@ -1,20 +1,25 @@
 import { useRef, useState } from "react";
 import { Button, Dimmer, Divider, Form, Input, InputOnChangeData, Loader, Message, Modal, Progress, Table, TextArea } from "semantic-ui-react";
-import { AccountData, CandidateEntry, CHUNK_SIZE, convertJWKNumber, encodeBigIntArray, encodeCandidate, encodePubKeyArray, encodeUint32LE, PreparedTx, publishBytesAsCell, randCandidateId, RSAPubKey, uint8ArrToHex } from "../utils";
+import { AccountData, CandidateEntry, CHUNK_SIZE, encodeLsagPublicKeys, hexToUint8Arr, LSAG_KEY_BYTES, PreparedTx, preparePublicKeyRegistryCells, prepareVoteConfigCell, prepareVoteConfigReplacement, randCandidateId, ringSeed, SIGNATURE_SCHEME_LSAG, uint8ArrToHex } from "../utils";
 import { cccClient } from "../ccc-client";
 import _ from "lodash";
 import { useCcc } from "@ckb-ccc/connector-react";
-import { create_merkle_tree_root_rsa } from "signature-tools-wasm";
+import { create_merkle_tree_root_lsag, key_set_root_wasm, ring_order_wasm } from "signature-tools-wasm";
 
 interface VoteTransactions {
-    candidate: PreparedTx;
-    merkleRootHash: PreparedTx;
+    /** Commits to the keys, which are assigned to rings once the seed of the shuffle is mined */
+    config: PreparedTx & { configTypeHash: string };
+    /** Only for the capacity they need, they are prepared again with the keys in ring order */
+    publicKeyRegistry: PreparedTx[];
+    pubKeys: Uint8Array[];
 }
 
 interface VoteCreationResult {
-    candidateCellTxHash: string;
-    merkleRootHash: string;
-    pubkeysData: Uint8Array;
+    /** Creates the election and commits to its keys, the seed of the shuffle depends on its block */
+    firstConfigCellTxHash: string;
+    configCellTxHash: string;
+    configTypeHash: string;
+    publicKeyRegistryTxHashes: string[];
 }
 
 enum Stage {
@@ -37,7 +42,6 @@ interface StageDataPrepared {
     stage: Stage.DATA_PREPARED;
     accountData: AccountData;
     preparedTx: VoteTransactions;
-    pubkeysData: Uint8Array;
     prompt: string;
 }
 interface StageSended {
@@ -54,6 +58,8 @@ const PageStartVote: React.FC<{}> = () => {
     ]);
     const [userUploadType, setUserUploadType] = useState<"file" | "textbox">("file");
     const [keys, setKeys] = useState("");
+    const [votingStart, setVotingStart] = useState("");
+    const [votingEnd, setVotingEnd] = useState("");
     const [loading, setLoading] = useState(false);
     const fileRef = useRef<HTMLInputElement>(null);
 
@@ -75,6 +81,11 @@ const PageStartVote: React.FC<{}> = () => {
 
             const addresses = await signer.getAddressObjs();
             const balance = await cccClient.getBalance(addresses.map(s => s.script));
+            if (votingStart === "" && votingEnd === "") {
+                const tip = await cccClient.getTip();
+                setVotingStart(tip.toString());
+                setVotingEnd((tip + BigInt(100000)).toString());
+            }
             setStage({ stage: Stage.ACCOUNT_LOADED, accountData: { addresses, balance, signer } });
         } catch (e) { console.error(e); alert(e); } finally {
             setLoading(false);
@@ -89,8 +100,12 @@ const PageStartVote: React.FC<{}> = () => {
                 return;
             }
             if (candidates.length === 0) { alert("You must provide at least one candidate"); return; }
+            if (!/^\d+$/.test(votingStart) || !/^\d+$/.test(votingEnd) || BigInt(votingStart) >= BigInt(votingEnd)) {
+                alert("Invalid voting period");
+                return;
+            }
             setProgressText("Reading data..");
-            const pubKeys: RSAPubKey[] = [];
+            const pubKeys: Uint8Array[] = [];
             let stringData;
             if (userUploadType === "file") {
                 const files = fileRef.current!.files;
@@ -110,52 +125,45 @@ const PageStartVote: React.FC<{}> = () => {
             }
             for (const [line, index] of _(stringData.split("\n")).map((val, idx) => [val.trim(), idx] as [string, number]).value()) {
                 if (line === "") continue;
-                const parsed = JSON.parse(line) as { n?: string, e?: string };
-                if (!parsed.e || !parsed.n) {
-                    throw new Error(`Invalid public key at line ${index + 1} (missing e or n)`);
+                const parsed = hexToUint8Arr(line);
+                if (parsed.length !== LSAG_KEY_BYTES) {
+                    throw new Error(`Invalid public key at line ${index + 1} (must be ${LSAG_KEY_BYTES} bytes in hex)`);
                 }
-                pubKeys.push({
-                    e: convertJWKNumber(parsed.e),
-                    n: convertJWKNumber(parsed.n),
-                })
+                pubKeys.push(parsed);
 
             }
             const accountData = stage.accountData;
             const signer = accountData.signer;
-            setTotalCount(2);
+            setTotalCount(1);
             setDoneCount(0);
-            let candidateTx;
-            {
-                setProgressText("Generating candidate data..");
-                const candidatesData = encodeCandidate(candidates);
-                candidateTx = await publishBytesAsCell(candidatesData, accountData.addresses[0].script, signer, "candidate");
-                setDoneCount(1);
-            }
-            setProgressText("Generating merkle tree");
-            const merkleTreeRoot = create_merkle_tree_root_rsa(
-                pubKeys.length,
-                CHUNK_SIZE,
-                encodeBigIntArray(pubKeys.map(s => s.n), 256),
-                encodeBigIntArray(pubKeys.map(s => s.e), 4),
-            )
-
-            const merkleTreeRootTx = await publishBytesAsCell(new Uint8Array([
-                ...merkleTreeRoot,
-                ...encodeUint32LE(pubKeys.length),
-                ...encodeUint32LE(Math.ceil(pubKeys.length / CHUNK_SIZE)),
-
-            ]).buffer, accountData.addresses[0].script, signer, "merkle tree root");
-            setDoneCount(2);
+            setProgressText("Preparing config cell..");
+            const configTx = await prepareVoteConfigCell(signer, accountData.addresses[0].script, {
+                userCount: pubKeys.length,
+                leafCount: Math.ceil(pubKeys.length / CHUNK_SIZE),
+                groupSize: CHUNK_SIZE,
+                signatureScheme: SIGNATURE_SCHEME_LSAG,
+                rsaKeyBits: 0,
+                votingStart: BigInt(votingStart),
+                votingEnd: BigInt(votingEnd),
+                candidates,
+                merkleRoot: key_set_root_wasm(SIGNATURE_SCHEME_LSAG, 0, CHUNK_SIZE, encodeLsagPublicKeys(pubKeys)),
+            });
+            setDoneCount(1);
+            const publicKeyRegistryTxs = preparePublicKeyRegistryCells(
+                signer,
+                accountData.addresses[0].script,
+                { txHash: configTx.tx.hash(), index: 1 },
+                configTx.configTypeHash,
+                { signatureScheme: SIGNATURE_SCHEME_LSAG, rsaKeyBits: 0, groupSize: CHUNK_SIZE },
+                encodeLsagPublicKeys(pubKeys),
+            );
 
-            let requiredCkb = BigInt(0);
-            requiredCkb += candidateTx.tx.getOutputsCapacity();
-            requiredCkb += merkleTreeRootTx.tx.getOutputsCapacity();
+            const requiredCkb = [configTx, ...publicKeyRegistryTxs].reduce((sum, item) => sum + item.tx.getOutputsCapacity(), BigInt(0));
             setStage({
                 stage: Stage.DATA_PREPARED,
-                preparedTx: { candidate: candidateTx, merkleRootHash: merkleTreeRootTx },
+                preparedTx: { config: configTx, publicKeyRegistry: publicKeyRegistryTxs, pubKeys },
                 accountData: stage.accountData,
-                prompt: `You need at least ${requiredCkb / BigInt(100000000) + BigInt(1)} CKB for these transactions. Make sure you have enough balance`,
-                pubkeysData: new Uint8Array(encodePubKeyArray(pubKeys))
+                prompt: `You need at least ${requiredCkb / BigInt(100000000) + BigInt(1)} CKB for these ${publicKeyRegistryTxs.length + 2} transactions. Make sure you have enough balance`,
             })
             setProgressText(null);
         } catch (e) {
@@ -172,48 +180,66 @@ const PageStartVote: React.FC<{}> = () => {
         }
         console.log(stage);
         const txs = stage.preparedTx;
-        setTotalCount(1 + 1);
-        setDoneCount(0);
-        setProgressText("Sending candidate cell..");
-        const candidateHash = await txs.candidate.sendTx();
-        console.log(candidateHash);
-        setDoneCount(1);
-        setProgressText("Sending merkle tree root cell..");
-        const merkleTreeRootHash = await txs.merkleRootHash.sendTx();
-        console.log(merkleTreeRootHash);
-        setDoneCount(2);
-        setStage({
-            stage: Stage.SENDED,
-            accountData: stage.accountData,
-            preparedTx: stage.preparedTx,
-            result: {
-                candidateCellTxHash: candidateHash,
-                merkleRootHash: merkleTreeRootHash,
-                pubkeysData: stage.pubkeysData
+        try {
+            const { pubKeys } = txs;
+            const signer = stage.accountData.signer;
+            setTotalCount(3 + txs.publicKeyRegistry.length);
+            setDoneCount(0);
+            setProgressText("Sending config cell..");
+            const firstConfigCellTxHash = await txs.config.sendTx();
+            console.log(firstConfigCellTxHash);
+            setDoneCount(1);
+            // Keys are committed to by the first config, so nobody can pick them for a seed
+            setProgressText("Waiting for the block seeding the rings..");
+            let seed: Uint8Array | undefined;
+            while (!(seed = await ringSeed(cccClient, firstConfigCellTxHash))) {
+                await new Promise(resolve => setTimeout(resolve, 3000));
             }
-        })
-        setProgressText(null);
-
-    };
-    const doSaveMerkleTreeLeaves = () => {
-        if (stage.stage !== Stage.SENDED) {
-            alert("bad stage");
-            return;
+            setDoneCount(2);
+            setProgressText("Generating merkle tree");
+            const orderedKeys = Array.from(
+                ring_order_wasm(SIGNATURE_SCHEME_LSAG, 0, seed, encodeLsagPublicKeys(pubKeys)),
+                index => pubKeys[index],
+            );
+            const merkleRoot = create_merkle_tree_root_lsag(CHUNK_SIZE, encodeLsagPublicKeys(orderedKeys));
+            setProgressText("Replacing config cell..");
+            const configTx = await prepareVoteConfigReplacement(signer, { txHash: firstConfigCellTxHash, index: 1 }, merkleRoot);
+            const configCellTxHash = await configTx.sendTx();
+            console.log(configCellTxHash);
+            setDoneCount(3);
+            // Voters rebuild their rings from these cells, so no data is handed out of band
+            const publicKeyRegistry = preparePublicKeyRegistryCells(
+                signer,
+                stage.accountData.addresses[0].script,
+                { txHash: configCellTxHash, index: 0 },
+                txs.config.configTypeHash,
+                { signatureScheme: SIGNATURE_SCHEME_LSAG, rsaKeyBits: 0, groupSize: CHUNK_SIZE },
+                encodeLsagPublicKeys(orderedKeys),
+            );
+            const publicKeyRegistryTxHashes: string[] = [];
+            for (const [index, registryTx] of publicKeyRegistry.entries()) {
+                setProgressText(`Sending public key registry cell ${index + 1}/${publicKeyRegistry.length}..`);
+                publicKeyRegistryTxHashes.push(await registryTx.sendTx());
+                setDoneCount(index + 4);
+            }
+            setStage({
+                stage: Stage.SENDED,
+                accountData: stage.accountData,
+                preparedTx: stage.preparedTx,
+                result: {
+                    firstConfigCellTxHash,
+                    configCellTxHash,
+                    configTypeHash: txs.config.configTypeHash,
+                    publicKeyRegistryTxHashes,
+                }
+            })
+        } catch (e) {
+            console.error(e);
+            alert(e);
+        } finally {
+            setProgressText(null);
         }
-        // const encoder = new TextEncoder();
-        const blob = new Blob([
-            // encoder.encode(JSON.stringify(stage.result))
-            stage.result.pubkeysData
-        ]);
-        const url = URL.createObjectURL(blob);
-        const a = document.createElement('a');
-        a.href = url;
-        a.download = 'leaves.bin';
-        document.body.appendChild(a);
-        a.click();
-        document.body.removeChild(a);
-        URL.revokeObjectURL(url);
-    }
+    };
     return <>
         {progressText !== null && <Modal open size="small">
             <Modal.Header>Progress</Modal.Header>
@@ -271,6 +297,16 @@ const PageStartVote: React.FC<{}> = () => {
                         </Table.Body>
                     </Table>
                 </Form.Field>
+                <Form.Group widths="equal">
+                    <Form.Field>
+                        <label>Voting start block</label>
+                        <Input disabled={stage.stage !== Stage.ACCOUNT_LOADED} value={votingStart} onChange={(_, d) => setVotingStart(d.value)}></Input>
+                    </Form.Field>
+                    <Form.Field>
+                        <label>Voting end block (exclusive)</label>
+                        <Input disabled={stage.stage !== Stage.ACCOUNT_LOADED} value={votingEnd} onChange={(_, d) => setVotingEnd(d.value)}></Input>
+                    </Form.Field>
+                </Form.Group>
                 <Form.Group inline>
                     <label>How to upload user public keys</label>
                     <Form.Radio disabled={stage.stage !== Stage.ACCOUNT_LOADED} label="Upload text file" checked={userUploadType === "file"} onClick={() => setUserUploadType("file")}></Form.Radio>
@@ -284,7 +320,7 @@ const PageStartVote: React.FC<{}> = () => {
                     <Message info>
                         <Message.Header>Note</Message.Header>
                         <Message.Content>
-                            You need to select a file containing public keys of users who want to vote. One line for one key. Keys should be in JWK format.
+                            You need to select a file containing public keys of users who want to vote. One line for one key. Keys should be in hex.
                         </Message.Content>
                     </Message>
                 </>}
@@ -296,7 +332,7 @@ const PageStartVote: React.FC<{}> = () => {
                     <Message info>
                         <Message.Header>Note</Message.Header>
                         <Message.Content>
-                            Please provide public keys of users who want to vote. One line for one key. Keys should be in JWK format. If there are two many keys, uploading via file is preferred.
+                            Please provide public keys of users who want to vote. One line for one key. Keys should be in hex. If there are two many keys, uploading via file is preferred.
                         </Message.Content>
                     </Message>
                 </>}
@@ -319,12 +355,13 @@ const PageStartVote: React.FC<{}> = () => {
                     <Message info>
                         <Message.Header>Successfully started</Message.Header>
                         <Message.Content>
-                            <p>Candidate cell tx hash: {stage.result.candidateCellTxHash}, index 0</p>
-                            <p>Merkle tree root cell tx hash: {stage.result.merkleRootHash}, index 0</p>
-                            <p>Please save these hashes and download merkle tree leaves</p>
+                            <p>Config cell tx hash: {stage.result.configCellTxHash}, index 0</p>
+                            <p>Keys committed in tx: {stage.result.firstConfigCellTxHash}</p>
+                            <p>Config cell type hash: {stage.result.configTypeHash}</p>
+                            <p>Public keys published in {stage.result.publicKeyRegistryTxHashes.length} registry cells</p>
+                            <p>Please save these hashes, voters only need the config cell tx hash</p>
                         </Message.Content>
                     </Message>
-                    <Button color="green" onClick={doSaveMerkleTreeLeaves}>Download merkle tree leaves</Button>
                 </>}
             </>}
         </Form>
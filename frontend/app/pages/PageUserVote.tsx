@@ -1,11 +1,11 @@
 import { Button, Dimmer, Form, Input, Loader, Message, Modal, Progress, Table, TextArea } from "semantic-ui-react";
-import { AccountData, CandidateEntry, CHUNK_SIZE, convertJWKNumber, decodeCandidate, decodePubKeyArray, decodeUint32LE, encodeBigIntArray, encodeUint32LE, PubkeyIndexEntry, RSAPubKey, uint8ArrToHex, useInputValue } from "../utils";
-import { useRef, useState } from "react";
+import { AccountData, CandidateEntry, decodeLsagPublicKeys, decodeVoteConfig, describeTxError, encodeLsagPublicKeys, findFirstConfigTx, hexToUint8Arr, LsagKeyPair, publicKeyRegistryType, randSeed, ringSeed, SCRIPT_ROLE_VOTE, SIGNATURE_SCHEME_LSAG, TYPE_ID_CODE_HASH, uint8ArrToHex, useInputValue } from "../utils";
+import { useState } from "react";
 import { cccClient } from "../ccc-client";
 import { ccc } from "@ckb-ccc/core";
-import { bigintToBuf, bufToHex, hexToBuf } from "bigint-conversion";
+import { bufToHex } from "bigint-conversion";
 import _ from "lodash";
-import { create_merkle_tree_proof_rsa, create_ring_signature_rsa_wasm } from "signature-tools-wasm";
+import { ballot_message_wasm, check_ring_assignment_wasm, create_key_image_registry_update, find_ring_registry_cell, public_keys_from_registry_cells, create_merkle_tree_proof_lsag, create_ring_signature_lsag_wasm, decode_vote_cell, encode_vote_cell, encode_vote_witness_lsag, verify_ring_signature_lsag_wasm } from "signature-tools-wasm";
 import offCKBConfig from "@/offckb.config";
 import { useSigner } from "@ckb-ccc/connector-react";
 enum Stage {
@@ -23,8 +23,20 @@ interface StageCandidateLoaded {
     candidate: CandidateEntry[];
     merkleRootHash: string;
     merkleLeafCount: number;
+    groupSize: number;
+    configOutPoint: ccc.OutPoint;
+    configTypeHash: ccc.Hex;
+    network: ccc.Hex;
+    keyImageRegistryTypeHash: ccc.Hex;
+    votingStart: bigint;
+    votingEnd: bigint;
     accountData: AccountData;
-    pubKeys: RSAPubKey[];
+    pubKeys: Uint8Array[];
+    configData: Uint8Array;
+    /** Votes reference the registry cell of their ring instead of carrying its keys */
+    publicKeyRegistryCells: ccc.Cell[];
+    /** Why rings aren't the ones of the public shuffle of the election, if they aren't */
+    ringAssignmentError: string | null;
 }
 
 interface StageVoted extends Omit<StageCandidateLoaded, "stage"> {
@@ -33,18 +45,7 @@ interface StageVoted extends Omit<StageCandidateLoaded, "stage"> {
 }
 
 
-const extractPQEDFromPrivateKey = (key: any): { n: bigint; p: bigint; q: bigint; e: bigint; d: bigint } => {
-
-    return ({
-        n: convertJWKNumber(key.n as string),
-        p: convertJWKNumber(key.p as string),
-        q: convertJWKNumber(key.q as string),
-        e: convertJWKNumber(key.e as string),
-        d: convertJWKNumber(key.d as string),
-    })
-};
-
-const TEST_PRIVATE_KEY = `{"kty":"RSA","n":"yTEpLlTR5F7jCvWC6_ac8yFnJKZcpzSwfjsQwNQTIA79n_FiiCbapRrOBmm98T66TjvuOnlIiZAScwRhBk3Puy0gcn9WwLwtnw3GhEt0oNY0S4jFH7O-7YMs2DyJ0dpMqmjjTQta3btBu7RdWGBxOUY9K2dwJrzSdtc41bTOXRxa9L3R8fc0mG7F_vgzEIV8zUdXniYxtBlPO-ASB8BUXrDR3ZfmfblwJxyipmYdm-7CZhumsLcgbAlD9n0zdEShBd5H_hMfIM6NH-8Mohxue6-tEjN3MK_l60q9gMB24j31tsAhTNnBJaK7hlJuxXyfkizu9xT5bpXVcm7g1dImaQ","e":"AQAB","d":"uc_Ud7zQiLj1uZZbjvAZrhaIevnGkoqAAmUsqNp0u2P86231EUfJITkrVBLu4NKNIFoLQCfpTVspHt7JeRf-JaGc2QIwLhrdi4seb-p8UVrju2sam5fXWuaOUTtAEfcqXSw32V0GU8hxAhmnKEsR5tKvBdonYy96tVU6YWz2NlipcMJEZMbdLPQ0tblJFJHdOfCUYIWsrxT3s93R_ToivvHrVUvBz-Pr1FWVI01MQlkBGH6g6V0h_vLGDV6KZi_m9z5UgPiie8XgZjGdUhG8kVLW_6dsGfdpI0sG5JNNy9QyzHK7ui5DyCSAXsRMsZSKzFCTMj7xgpsYXmwiJy8ubQ","p":"3N0mcJfz87J6Wal4DHwtjMHthKF6vsCrHQzDqYtx1-Zo7q65M640DI_6EN_Vh15w0AJILMuS5k4bELluJ-pctIYx63AEeQoD5HUs6R3RQC3XNPi8-aKqbZirC00mSNAJG9k92SrWtGzgrZ5MmelqGV7wZb1lcFcbi17EDmaJWsc","q":"6TLcOkvKv1LTPnk_nQLDhUIg0CtxmMjzXhZNebMS9t4VHXkvtuTDR954-fqPhdxcKNnw1N4ojE33UJP8S-4VxFST1eyCnbIpU9GBQXnMzNn9Kb7dZDOO66ENEhUJ-AEv2EjwzXOr1lL8djfBOyJWGhK4-7Ku4YdSC60za5jMxU8","dp":"jFDjrvyau-RT3srPvf7WYWqDH5QHi1CUZWxKklhJB0UWvSy79J1j6-c8k6Pg4JObUEti1zKuLSrJ_yIPXcSDCR2IcW9FKDC4sFfRJHkRC5kT9E9in6Y8aExpvlBRVkj0wICPznxs00uooiTDvbSQ93VdfQWKgIiWs0CNhiiWctk","dq":"iUc7hcXgUMi9OmW-IPvjharsDh_E-6AwRA71BNN6MoGCBJu2jwAURVad-OqbWr3iMto5f9ZYIGA1WuYC-9_oSG9Rp_lW2uZqlcEbSiQdf-pDsjN9uOLxG5zvSNnByJFKTRSDTS7u1Xh8zkr8IYeREEA9TU5ezL0Qe3c2cfy9btk","qi":"A_2U4fZkQwJZZiSpKU6mheY0FKCW_-tqmfYsxhBr9_raVwCbskjysVX_rGtA5jZvXgaOTwt5JzFR8IGgHmBSQda0SI8IKvbEkKz31zcabDCdoTo8S-Vu60YiRZweAU7DKChYyRr073Vjid8y2IgOzF-XAJ2gpL-zyrlDMQiHSE4"}`;
+const TEST_PRIVATE_KEY = `{"privateKey":"4a3cb3460e6fbae445e2b9c5adf6e83a26c72acd6f9d009d8a955b204b176e07","publicKey":"24c7d70b70bafa592bcd3815aa210ce8c06d72c7a294c473840729a822e57636"}`;
 
 const PageUserVote: React.FC<{}> = () => {
     const [stage, setStage] = useState<StageInit | StageCandidateLoaded | StageVoted>({ stage: Stage.INIT });
@@ -53,15 +54,12 @@ const PageUserVote: React.FC<{}> = () => {
     const [totalCount, setTotalCount] = useState(1);
     const [progressText, setProgressText] = useState<string | null>(null);
 
-    const candidateHash = useInputValue("0x16d71077a3ee88bed50cac3ee5385df77f7c560f58d803bb3c1d939c793aa985");
-    const merkleRootHash = useInputValue("0x4cac2ca336c5057934a28a9e45b9bbc3907c8b720a356d29c2e56666af79706d");
+    const configHash = useInputValue("");
     const [signPrivateKey, setSignPrivateKey] = useState(TEST_PRIVATE_KEY);
 
     const [loading, setLoading] = useState(false);
     const [selectedCandidate, setSelectedCandidate] = useState<CandidateEntry | null>(null);
 
-    const uploadRef = useRef<HTMLInputElement>(null);
-
     const signer = useSigner();
     console.log(stage);
     const doLoadCandidateAndAccount = async () => {
@@ -74,47 +72,74 @@ const PageUserVote: React.FC<{}> = () => {
             setDoneCount(0);
             setProgressText("Fetching base data..");
             setTotalCount(3);
-            const [candidateTx, addressObjs, merkleRootTx] = await Promise.all([
-                cccClient.getTransaction(candidateHash.value),
+            const [configTx, addressObjs] = await Promise.all([
+                cccClient.getTransaction(configHash.value),
                 signer.getAddressObjs(),
-                cccClient.getTransaction(merkleRootHash.value),
-
             ]);
             setDoneCount(1);
-            if (!candidateTx) {
-                alert("Invalid candidate cell tx hash");
+            // The config cell is the one typed by the built-in type id script, and its type hash
+            // identifies the election
+            const configIndex = configTx?.transaction.outputs.findIndex(output => output.type?.codeHash === TYPE_ID_CODE_HASH) ?? -1;
+            if (!configTx || configIndex === -1) {
+                alert("Invalid config cell tx hash");
                 return;
             }
-            if (!merkleRootTx) {
-                alert("Invalid merkle root cell tx hash");
+            const configType = configTx.transaction.outputs[configIndex].type!;
+            const config = decodeVoteConfig(ccc.bytesFrom(configTx.transaction.outputsData[configIndex]));
+            // The contract refuses RSA elections, whose key images neither stop double votes nor
+            // hide the voter
+            if (config.signatureScheme !== SIGNATURE_SCHEME_LSAG) {
+                alert("Only elections with LSAG keys are supported");
                 return;
             }
             setProgressText("Fetching balance..");
             const balance = await signer.getBalance();
             setDoneCount(2);
 
-            setProgressText("Loading public keys(merkle tree leaves)..");
-            if (!uploadRef.current || !uploadRef.current.files || uploadRef.current.files.length !== 1) {
-                alert("Please select exact one file");
-                return;
+            setProgressText("Loading public keys from registry cells..");
+            const registryCells: ccc.Cell[] = [];
+            for await (const cell of cccClient.findCells({ script: publicKeyRegistryType(configType.hash()), scriptType: "type", scriptSearchMode: "exact" })) {
+                registryCells.push(cell);
+            }
+            const configData = ccc.bytesFrom(configTx.transaction.outputsData[configIndex]);
+            // Fails unless the cells hold all keys of the merkle root of the config
+            const publicKeys = public_keys_from_registry_cells(configData, registryCells.map(cell => ccc.bytesFrom(cell.outputData)));
+            const pubKeys = decodeLsagPublicKeys(publicKeys);
+            // The contract can't tell whether the administrator picked who shares a ring, so it's checked here
+            // against the keys committed to by the first config and the seed mined after it
+            let ringAssignmentError: string | null = null;
+            try {
+                const firstConfig = await findFirstConfigTx(cccClient, configHash.value);
+                const seed = await ringSeed(cccClient, firstConfig.txHash);
+                if (!seed) {
+                    throw new Error("The block seeding the rings is not mined yet");
+                }
+                check_ring_assignment_wasm(configData, firstConfig.configData, seed, publicKeys);
+            } catch (e) {
+                ringAssignmentError = String(e);
             }
-            const file = uploadRef.current.files[0];
-            const bytes = await file.arrayBuffer();
-            const pubKeys = decodePubKeyArray(Buffer.from(bytes));
 
             setDoneCount(3);
 
-            const merkleCellData = hexToBuf(merkleRootTx.transaction.outputsData[0]);
             setStage({
                 stage: Stage.CANDIDATE_LOADED,
-                candidate: decodeCandidate(Buffer.from(hexToBuf(candidateTx.transaction.outputsData[0], true) as ArrayBuffer)),
+                candidate: config.candidates,
                 accountData: {
                     addresses: addressObjs, balance, signer
                 },
                 pubKeys,
-                merkleRootHash: bufToHex(merkleCellData.slice(0, 32)),
-                merkleLeafCount: decodeUint32LE(new Uint8Array(merkleCellData.slice(32, 36)))
-
+                configData,
+                publicKeyRegistryCells: registryCells,
+                ringAssignmentError,
+                merkleRootHash: bufToHex(config.merkleRoot),
+                merkleLeafCount: config.leafCount,
+                groupSize: config.groupSize,
+                configOutPoint: ccc.OutPoint.from({ txHash: configHash.value, index: configIndex }),
+                configTypeHash: configType.hash(),
+                network: ccc.hexFrom(config.network),
+                keyImageRegistryTypeHash: ccc.hexFrom(config.keyImageRegistryTypeHash),
+                votingStart: config.votingStart,
+                votingEnd: config.votingEnd,
             })
         } catch (e) { console.error(e); alert(e) } finally {
             setLoading(false);
@@ -131,76 +156,134 @@ const PageUserVote: React.FC<{}> = () => {
                 return;
             }
             setDoneCount(0);
-            setTotalCount(4);
+            setTotalCount(5);
             setProgressText("Looking for belonging block..");
-            const privateKey = extractPQEDFromPrivateKey(JSON.parse(signPrivateKey));
+            const keyPair = JSON.parse(signPrivateKey) as LsagKeyPair;
+            const publicKey = uint8ArrToHex(hexToUint8Arr(keyPair.publicKey));
             let signerIndex: number | undefined;
             for (let i = 0; i < stage.pubKeys.length; i++) {
-                const currKey = stage.pubKeys[i];
-                if (currKey.e === privateKey.e && currKey.n === privateKey.n) {
+                if (uint8ArrToHex(stage.pubKeys[i]) === publicKey) {
                     signerIndex = i; break;
                 }
             }
             if (signerIndex === undefined) {
-                alert("Unable to find belong block, please ensure you have uploaded the correct merkle tree leaves and your private key is correct");
+                alert("Unable to find belong block, please ensure your private key is correct");
                 return;
             }
             console.log("signer index", signerIndex);
             setDoneCount(1);
             setProgressText("Creating signature..");
-            const signerBlock = Math.floor(signerIndex / CHUNK_SIZE);
-            const signerBlockIdx = signerIndex % CHUNK_SIZE;
-            const blockKeys = stage.pubKeys.slice(signerBlock * CHUNK_SIZE, Math.min((signerBlock + 1) * CHUNK_SIZE, stage.pubKeys.length));
-
+            const signerBlock = Math.floor(signerIndex / stage.groupSize);
+            const signerBlockIdx = signerIndex % stage.groupSize;
+            const blockKeys = stage.pubKeys.slice(signerBlock * stage.groupSize, Math.min((signerBlock + 1) * stage.groupSize, stage.pubKeys.length));
+            const script = offCKBConfig.myScripts["ring-signature-verify"]!;
+            // The ballot binds the candidate to the election, the chain and the contract
+            const message = ballot_message_wasm(
+                ccc.bytesFrom(stage.configTypeHash),
+                ccc.bytesFrom(stage.network),
+                ccc.bytesFrom(script.codeHash),
+                selectedCandidate.id,
+            );
 
-            const signature = create_ring_signature_rsa_wasm(
-                blockKeys.length,
-                encodeBigIntArray(blockKeys.map(s => s.e), 4),
-                encodeBigIntArray(blockKeys.map(s => s.n), 256),
-                encodeBigIntArray([privateKey.p], 256),
-                encodeBigIntArray([privateKey.q], 256),
-                encodeBigIntArray([privateKey.d], 256),
+            const signature = create_ring_signature_lsag_wasm(
+                encodeLsagPublicKeys(blockKeys),
+                hexToUint8Arr(keyPair.privateKey),
                 signerBlockIdx,
-                selectedCandidate.id,
+                ccc.bytesFrom(stage.configTypeHash),
+                message,
+                randSeed(),
             );
             console.log(signature);
+            // Check the ballot before paying to publish it
+            if (!verify_ring_signature_lsag_wasm(
+                encodeLsagPublicKeys(blockKeys),
+                ccc.bytesFrom(stage.configTypeHash),
+                message,
+                signature.c,
+                signature.image,
+                signature.r_arr,
+            )) {
+                alert("Created signature is invalid");
+                return;
+            }
             setDoneCount(2);
 
 
             setProgressText("Creating merkle proof..");
 
-            const proof = create_merkle_tree_proof_rsa(
-                stage.pubKeys.length,
-                CHUNK_SIZE,
-                encodeBigIntArray(stage.pubKeys.map(s => s.n), 256),
-                encodeBigIntArray(stage.pubKeys.map(s => s.e), 4),
+            const proof = create_merkle_tree_proof_lsag(
+                stage.groupSize,
+                encodeLsagPublicKeys(stage.pubKeys),
                 signerBlock);
             console.log(proof);
             setDoneCount(3);
 
+            setProgressText("Loading key image registry..");
+            // Vote cells commit to the config cell in their args
+            const voteType = new ccc.Script(script.codeHash, script.hashType, ccc.hexFrom(new Uint8Array([
+                SCRIPT_ROLE_VOTE,
+                ...ccc.bytesFrom(stage.configTypeHash),
+            ])));
+            let registryCell: ccc.Cell | undefined;
+            for await (const cell of cccClient.findCells({ script: { codeHash: script.codeHash, hashType: script.hashType, args: "0x" }, scriptType: "type", scriptSearchMode: "prefix" })) {
+                if (cell.cellOutput.type?.hash() === stage.keyImageRegistryTypeHash) {
+                    registryCell = cell;
+                    break;
+                }
+            }
+            if (!registryCell) {
+                alert("Unable to find key image registry cell");
+                return;
+            }
+            const registrySearchKey = { script: registryCell.cellOutput.type!, scriptType: "type", scriptSearchMode: "exact" } as const;
+            // Every transaction creating a registry cell inserted images of its vote cells
+            const usedImages: number[] = [];
+            for await (const item of cccClient.findTransactions(registrySearchKey, "asc")) {
+                if (item.isInput) continue;
+                const registryTx = (await cccClient.getTransaction(item.txHash))!.transaction;
+                registryTx.outputs.forEach((output, idx) => {
+                    if (output.type?.eq(voteType)) {
+                        usedImages.push(...decode_vote_cell(SIGNATURE_SCHEME_LSAG, 0, ccc.bytesFrom(registryTx.outputsData[idx])).image);
+                    }
+                });
+            }
+            // Fails if this key has already voted
+            const registryUpdate = create_key_image_registry_update(SIGNATURE_SCHEME_LSAG, 0, new Uint8Array(usedImages), signature.image);
+            setDoneCount(4);
+
             setProgressText("Creating transaction..");
-            const script = offCKBConfig.myScripts["ring-signature-verify"]!;
+            const cellDeps = [
+                ccc.CellDep.from({ outPoint: stage.configOutPoint, depType: 0 }),
+                script.cellDeps[0].cellDep,
+                offCKBConfig.systemScripts.always_success!.script.cellDeps[0].cellDep,
+            ];
+            // The contract reads the keys of the ring from its registry cell, so the witness
+            // leaves them out, unless the ring spans cells
+            const ringCell = find_ring_registry_cell(
+                stage.configData,
+                stage.publicKeyRegistryCells.map(cell => ccc.bytesFrom(cell.outputData)),
+                signerBlock,
+            );
+            let ringCellDep: number | undefined;
+            if (ringCell !== undefined) {
+                ringCellDep = cellDeps.length;
+                cellDeps.push(ccc.CellDep.from({ outPoint: stage.publicKeyRegistryCells[ringCell].outPoint, depType: 0 }));
+            }
             const tx = ccc.Transaction.from({
-                cellDeps: [
-                    ccc.CellDep.from({ outPoint: { txHash: candidateHash.value, index: 0 }, depType: 0 }),
-                    ccc.CellDep.from({ outPoint: { txHash: merkleRootHash.value, index: 0 }, depType: 0 }),
-                    script.cellDeps[0].cellDep,
-                ],
+                cellDeps,
+                // Registry must be the first input, so its witness is at the same index as the vote
+                // An absolute since proves that the voting period has started
+                inputs: [{ previousOutput: registryCell.outPoint, since: stage.votingStart }],
                 outputs: [
                     {
                         lock: stage.accountData.addresses[0].script,
-                        type: new ccc.Script(
-                            script.codeHash,
-                            script.hashType,
-                            "0x00"
-                        )
-                    }
+                        type: voteType
+                    },
+                    registryCell.cellOutput,
                 ],
                 outputsData: [
-                    new Uint8Array([
-                        ...selectedCandidate.id,
-                        ...signature.i,
-                    ])
+                    encode_vote_cell(SIGNATURE_SCHEME_LSAG, 0, selectedCandidate.id, signature.image),
+                    registryUpdate.new_root,
                 ],
 
             });
@@ -211,18 +294,16 @@ const PageUserVote: React.FC<{}> = () => {
             console.log("raw witness", rawWitness);
             tx.setWitnessArgsAt(0, new ccc.WitnessArgs(
                 rawWitness?.lock,
-                rawWitness?.inputType,
+                bufToHex(registryUpdate.proof, true) as `0x${string}`,
                 bufToHex(
-                    new Uint8Array([
-                        ...signature.c,
-                        ...encodeUint32LE(blockKeys.length),
-                        ...signature.r_arr,
-                        ...encodeBigIntArray(blockKeys.map(s => s.n), 256),
-                        ...encodeBigIntArray(blockKeys.map(s => s.e), 4),
-                        ...encodeUint32LE(signerBlock),
-                        ...encodeUint32LE(proof.proof.length),
-                        ...proof.proof
-                    ]),
+                    encode_vote_witness_lsag(
+                        signature.c,
+                        signature.r_arr,
+                        encodeLsagPublicKeys(blockKeys),
+                        signerBlock,
+                        proof.proof,
+                        ringCellDep,
+                    ),
                     true
                 ) as `0x${string}`))
             const newTx = await stage.accountData.signer.signTransaction(tx);
@@ -236,7 +317,7 @@ const PageUserVote: React.FC<{}> = () => {
             })
         } catch (e) {
             console.error(e);
-            alert(e);
+            alert(describeTxError(e));
         } finally {
             setProgressText(null);
         }
@@ -254,21 +335,13 @@ const PageUserVote: React.FC<{}> = () => {
         <Message info>
             <Message.Header>To vote</Message.Header>
             <Message.Content>
-                To vote, you need to provideyour private key corresponding to the public key which was sended to administrator, and the candidate you want to vote. Note that don&apos;t vote twice, or only the first vote will be considered valid
+                To vote, you need to provideyour private key corresponding to the public key which was sended to administrator, and the candidate you want to vote. Each key can only vote once, a second vote will be rejected
             </Message.Content>
         </Message>
         <Form>
             <Form.Field>
-                <label>Candidate cell hash</label>
-                <Input disabled={stage.stage !== Stage.INIT} {...candidateHash}></Input>
-            </Form.Field>
-            <Form.Field>
-                <label>Merkle root cell hash</label>
-                <Input disabled={stage.stage !== Stage.INIT} {...merkleRootHash}></Input>
-            </Form.Field>
-            <Form.Field>
-                <label>Merkle tree leaves data</label>
-                <input disabled={stage.stage !== Stage.INIT} type="file" ref={uploadRef}></input>
+                <label>Config cell tx hash</label>
+                <Input disabled={stage.stage !== Stage.INIT} {...configHash}></Input>
             </Form.Field>
             {stage.stage === Stage.INIT && <Form.Button
                 onClick={doLoadCandidateAndAccount}
@@ -281,6 +354,12 @@ const PageUserVote: React.FC<{}> = () => {
                     <label>Private key of signature</label>
                     <TextArea value={signPrivateKey} onChange={(_, d) => setSignPrivateKey(d.value as string)}></TextArea>
                 </Form.Field>
+                {stage.ringAssignmentError !== null && <Message warning>
+                    <Message.Header>Rings were not assigned by the public shuffle</Message.Header>
+                    <Message.Content>
+                        The administrator may have chosen who shares your ring, which weakens your anonymity: {stage.ringAssignmentError}
+                    </Message.Content>
+                </Message>}
                 <Form.Field>
                     <label>Merkle tree root hash</label>
                     <p>{stage.merkleRootHash}</p>
@@ -289,6 +368,10 @@ const PageUserVote: React.FC<{}> = () => {
                     <label>Merkle tree leaf count</label>
                     <p>{stage.merkleLeafCount}</p>
                 </Form.Field>
+                <Form.Field>
+                    <label>Voting period (blocks)</label>
+                    <p>[{stage.votingStart.toString()}, {stage.votingEnd.toString()})</p>
+                </Form.Field>
 
                 <Form.Field>
                     <label>Candidate</label>
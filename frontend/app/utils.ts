@@ -1,11 +1,12 @@
 import { Address, ccc, CellDep, Script, ScriptLike, Signer, SignerCkbPrivateKey } from "@ckb-ccc/core";
 import { useCallback, useState } from "react";
 import { InputOnChangeData } from "semantic-ui-react";
-import * as bigintConversion from 'bigint-conversion'
-import base64url from "base64url";
-import { Buffer } from "buffer";
+import offCKBConfig from "@/offckb.config";
+import { decode_vote_config, encode_public_key_registry_cells, encode_vote_config, ring_seed_block_wasm, vote_error_reason_wasm } from "signature-tools-wasm";
 export type onChangeType = ((event: React.ChangeEvent<HTMLInputElement>, data: InputOnChangeData) => void);
 export const CHUNK_SIZE = 15;
+/** Rings published in one public key registry cell, so a cell fits in a transaction */
+export const RINGS_PER_REGISTRY_CELL = 10;
 export const useInputValue: (text?: string) => { value: string; onChange: onChangeType } = (text: string = "") => {
     const [value, setValue] = useState(text);
     let onChange: onChangeType = useCallback((_, d) => {
@@ -18,12 +19,19 @@ export function randCandidateId(): Uint8Array {
     globalThis.crypto.getRandomValues(result);
     return result;
 }
+/** Seed of the rng used by the wasm signing functions */
+export function randSeed(): Uint8Array {
+    const result = new Uint8Array(32);
+    globalThis.crypto.getRandomValues(result);
+    return result;
+}
 export function uint8ArrToHex(s: Uint8Array): string {
     return Array.from(s).map(x => x.toString(16).padStart(2, "0")).join("")
 }
-export function convertJWKNumber(input: string): bigint {
-    base64url
-    return BigInt("0x00" + base64url.toBuffer(input).toString("hex"))
+export function hexToUint8Arr(s: string): Uint8Array {
+    const hex = s.trim().replace(/^0x/, "");
+    if (!/^([0-9a-fA-F]{2})*$/.test(hex)) throw new Error(`Invalid hex string: ${s}`);
+    return new Uint8Array(hex.match(/../g)?.map(x => parseInt(x, 16)) ?? []);
 }
 
 export interface CandidateEntry {
@@ -31,131 +39,318 @@ export interface CandidateEntry {
     description: string;
 }
 
-export interface RSAPubKey {
-    e: bigint;
-    n: bigint;
+/**
+ * `signature_scheme` of the vote config, only LSAG elections can be started and voted here, since
+ * the contract refuses RSA ones, whose key images neither stop double votes nor hide the voter
+ */
+export const SIGNATURE_SCHEME_LSAG = 1;
+/** Size of LSAG private keys, scalars, and public keys, compressed ristretto255 points */
+export const LSAG_KEY_BYTES = 32;
+
+/** Key pair of a voter, as generated by `derive_lsag_key_pair_from_rand_seed`, in hex */
+export interface LsagKeyPair {
+    privateKey: string;
+    publicKey: string;
 }
 
-export function encodeCandidate(data: CandidateEntry[]): ArrayBuffer {
-    const buf = Buffer.alloc(2 + data.length * 104);
-    let idx = 0;
-    idx = buf.writeUInt16LE(data.length); // Number of candidate
-    for (const item of data) {
-        buf.set(item.id, idx);
-        idx += 4;
-        const writtenLen = buf.write(item.description, idx, 99, "utf8");
-        idx += writtenLen;
-        for (let i = 1; i <= 100 - writtenLen; i++) {
-            buf.writeInt8(0, idx); idx++;
-        };
-    }
-    return buf.buffer;
-}
-export function decodeCandidate(buf: Buffer): CandidateEntry[] {
-    let idx = 0;
-    const n = buf.readUInt16LE(); idx += 2;
-    const result: CandidateEntry[] = [];
-    for (let i = 0; i < n; i++) {
-        const id = new Uint8Array(buf.subarray(idx, idx + 4));
-        idx += 4;
-        let lastZero = idx + 100 - 1;
-        while (buf.at(lastZero) == 0 && lastZero > idx) lastZero--;
-        const description = buf.toString("utf-8", idx, lastZero + 1);
-        idx += 100;
-        result.push({
-            id, description
-        })
-    }
+/** Public keys in the layout of public key registry cells, concatenated */
+export function encodeLsagPublicKeys(keys: Uint8Array[]): Uint8Array {
+    const buf = new Uint8Array(keys.length * LSAG_KEY_BYTES);
+    keys.forEach((item, idx) => {
+        if (item.length !== LSAG_KEY_BYTES) throw new Error("Bad public key");
+        buf.set(item, idx * LSAG_KEY_BYTES);
+    });
+    return buf;
+}
 
+/** Inverse of `encodeLsagPublicKeys` */
+export function decodeLsagPublicKeys(buf: Uint8Array): Uint8Array[] {
+    const result: Uint8Array[] = [];
+    for (let idx = 0; idx < buf.length; idx += LSAG_KEY_BYTES) {
+        result.push(buf.slice(idx, idx + LSAG_KEY_BYTES));
+    }
     return result;
 }
-export function encodePubKeyArray(keys: RSAPubKey[]): ArrayBuffer {
-    const buf = Buffer.alloc(4 + keys.length * (256 + 4));
-    let idx = 0;
-    idx = buf.writeUint32LE(keys.length);
-    for (const item of keys) {
-        const nBuf = bigintConversion.bigintToBuf(item.n, true) as ArrayBuffer;
-        // bigintConversion gives us big endian, so reverse it
-        if (nBuf.byteLength > 256) throw new Error("Bad modulus");
-        buf.set(new Uint8Array(nBuf).reverse(), idx);
-        idx += 256;
-    }
-    for (const item of keys) {
-        const eBuf = bigintConversion.bigintToBuf(item.e, true) as ArrayBuffer;
-        if (eBuf.byteLength > 4) throw new Error("Bad public exponent");
-        buf.set(new Uint8Array(eBuf).reverse(), idx);
-        idx += 4;
-    }
-    return buf.buffer;
+
+export interface PreparedTx { sendTx: () => Promise<string>; tx: ccc.Transaction };
+
+/** First byte of script args of vote cells, followed by type hash of the config cell */
+export const SCRIPT_ROLE_VOTE = 0;
+/** First byte of script args of key image registry cells, followed by the type id */
+export const SCRIPT_ROLE_KEY_IMAGE_REGISTRY = 1;
+/** First byte of script args of public key registry cells, followed by type hash of the config cell */
+export const SCRIPT_ROLE_PUBLIC_KEY_REGISTRY = 2;
+export const TYPE_ID_CODE_HASH = "0x00000000000000000000000000000000000000000000000000545950455f4944";
+
+/** Same as `signature_tools::vote_config::VoteConfig`, encoded by signature-tools-wasm */
+export interface VoteConfig {
+    /** Genesis block hash of the chain, which votes sign */
+    network: Uint8Array;
+    merkleRoot: Uint8Array;
+    userCount: number;
+    leafCount: number;
+    groupSize: number;
+    signatureScheme: number;
+    /** 0 for LSAG */
+    rsaKeyBits: number;
+    votingStart: bigint;
+    votingEnd: bigint;
+    adminLockHash: Uint8Array;
+    keyImageRegistryTypeHash: Uint8Array;
+    candidates: CandidateEntry[];
 }
 
-function reverseBuffer(buf: Buffer): Buffer {
-    const arr = new Uint8Array(buf);
-    arr.reverse();
-    return Buffer.from(arr);
+export function encodeVoteConfig(config: VoteConfig): Uint8Array {
+    return encode_vote_config(
+        config.network,
+        config.merkleRoot,
+        config.userCount,
+        config.leafCount,
+        config.groupSize,
+        config.signatureScheme,
+        config.rsaKeyBits,
+        config.votingStart,
+        config.votingEnd,
+        config.adminLockHash,
+        config.keyImageRegistryTypeHash,
+        new Uint8Array(config.candidates.flatMap(item => [...item.id])),
+        config.candidates.map(item => item.description),
+    );
 }
 
-export function decodePubKeyArray(buf: Buffer): RSAPubKey[] {
-    const result: Partial<RSAPubKey>[] = [];
-    let idx = 0;
-    const n = buf.readUint32LE(); idx += 4;
-    for (let i = 0; i < n; i++) {
-        const n = bigintConversion.bufToBigint(reverseBuffer(buf.subarray(idx, idx + 256))/* We are in little endian*/);
-        idx += 256;
-        result.push({ n });
+export function decodeVoteConfig(buf: Uint8Array): VoteConfig {
+    const config = decode_vote_config(buf);
+    return {
+        network: config.network,
+        merkleRoot: config.merkle_root,
+        userCount: config.user_count,
+        leafCount: config.leaf_count,
+        groupSize: config.group_size,
+        signatureScheme: config.signature_scheme,
+        rsaKeyBits: config.rsa_key_bits,
+        votingStart: config.voting_start,
+        votingEnd: config.voting_end,
+        adminLockHash: config.admin_lock_hash,
+        keyImageRegistryTypeHash: config.key_image_registry_type_hash,
+        candidates: config.candidate_descriptions.map((description, idx) => ({
+            id: config.candidate_ids.slice(idx * 4, idx * 4 + 4),
+            description,
+        })),
+    };
+}
 
+/**
+ * Creates the key image registry cell and the vote config cell in one transaction
+ *
+ * The key image registry is typed by the vote script with a type id as args, and holds the root
+ * of the sparse merkle tree of used key images, which starts empty. The config cell is typed by
+ * the built-in type id script and locked by the administrator. The network of the config is the
+ * genesis block hash of the chain of the signer.
+ */
+export async function prepareVoteConfigCell(
+    signer: Signer,
+    adminLock: ScriptLike,
+    /** `merkleRoot` commits to the keys, sorted, until the seed of the shuffle is known */
+    config: Omit<VoteConfig, "network" | "adminLockHash" | "keyImageRegistryTypeHash">,
+): Promise<PreparedTx & { configTypeHash: ccc.Hex }> {
+    const script = offCKBConfig.myScripts["ring-signature-verify"]!;
+    const alwaysSuccess = offCKBConfig.systemScripts.always_success!.script;
+    const lock = ccc.Script.from(adminLock);
+    const genesis = await signer.client.getHeaderByNumber(0);
+    if (!genesis) {
+        throw new Error("Genesis block not found");
     }
-    for (let i = 0; i < n; i++) {
-        const e = bigintConversion.bufToBigint(reverseBuffer(buf.subarray(idx, idx + 4)) /* We are in little endian*/);
-        idx += 4;
-        result[i].e = e;
-    }
-    return result as RSAPubKey[];
+    const configData = (keyImageRegistryTypeHash: Uint8Array) => encodeVoteConfig({
+        ...config,
+        network: ccc.bytesFrom(genesis.hash),
+        adminLockHash: ccc.bytesFrom(lock.hash()),
+        keyImageRegistryTypeHash,
+    });
+    // Placeholders have the same size as the final data, so capacities are already correct
+    const tx = ccc.Transaction.from({
+        cellDeps: [script.cellDeps[0].cellDep],
+        outputs: [{
+            lock: new ccc.Script(alwaysSuccess.codeHash, alwaysSuccess.hashType, "0x"),
+            type: new ccc.Script(script.codeHash, script.hashType, ccc.hexFrom(new Uint8Array(33))),
+        }, {
+            lock,
+            type: new ccc.Script(TYPE_ID_CODE_HASH, "type", ccc.hexFrom(new Uint8Array(32))),
+        }],
+        outputsData: [new Uint8Array(32), configData(new Uint8Array(32))],
+    });
+    // Type ids depend on the first input, so inputs must be collected before sending
+    await tx.completeInputsAtLeastOne(signer);
+    tx.outputs[0].type!.args = ccc.hexFrom(new Uint8Array([
+        SCRIPT_ROLE_KEY_IMAGE_REGISTRY,
+        ...ccc.bytesFrom(ccc.hashTypeId(tx.inputs[0], 0)),
+    ]));
+    tx.outputs[1].type!.args = ccc.hashTypeId(tx.inputs[0], 1);
+    tx.outputsData[1] = ccc.hexFrom(configData(ccc.bytesFrom(tx.outputs[0].type!.hash())));
+    await tx.completeFeeBy(signer, 1000);
+    return {
+        sendTx: async () => {
+            console.log(tx);
+            console.log(tx.hash());
+            return await signer.sendTransaction(tx);
+        },
+        tx,
+        configTypeHash: tx.outputs[1].type!.hash(),
+    };
 }
-export interface PubkeyIndexEntry {
-    index: number;
-    txHash: Uint8Array;
+
+/**
+ * Hash of the block seeding the ring shuffle of the election whose first config cell was created
+ * by `configTxHash`, same as `ring_seed` of vote-counting, or undefined until that block is mined
+ */
+export async function ringSeed(client: ccc.Client, configTxHash: ccc.HexLike): Promise<Uint8Array | undefined> {
+    const configTx = await client.getTransaction(configTxHash);
+    if (configTx?.status !== "committed" || !configTx.blockHash) {
+        return undefined;
+    }
+    const configBlock = await client.getHeaderByHash(configTx.blockHash);
+    if (!configBlock) {
+        return undefined;
+    }
+    const seedBlock = await client.getHeaderByNumber(ring_seed_block_wasm(BigInt(configBlock.number)));
+    return seedBlock && ccc.bytesFrom(seedBlock.hash);
 }
 
+/**
+ * Transaction which created the first config cell of an election, found by following the config
+ * cell back through the transactions that replaced it, from the one of `configTxHash`
+ */
+export async function findFirstConfigTx(client: ccc.Client, configTxHash: ccc.HexLike): Promise<{ txHash: ccc.Hex; configData: Uint8Array }> {
+    let txHash = ccc.hexFrom(configTxHash);
+    let tx = (await client.getTransaction(txHash))?.transaction;
+    const configType = tx?.outputs.find(output => output.type?.codeHash === TYPE_ID_CODE_HASH)?.type;
+    if (!tx || !configType) {
+        throw new Error("Invalid config cell tx hash");
+    }
+    let configData = ccc.bytesFrom(tx.outputsData[tx.outputs.findIndex(output => output.type?.eq(configType))]);
+    for (; ;) {
+        // A type id cell is replaced by spending it, so the previous config is an input
+        let previous: { txHash: ccc.Hex; tx: ccc.Transaction; index: number } | undefined;
+        for (const input of tx.inputs) {
+            const inputTx = (await client.getTransaction(input.previousOutput.txHash))?.transaction;
+            const index = Number(input.previousOutput.index);
+            if (inputTx?.outputs[index]?.type?.eq(configType)) {
+                previous = { txHash: input.previousOutput.txHash, tx: inputTx, index };
+                break;
+            }
+        }
+        if (!previous) {
+            return { txHash, configData };
+        }
+        txHash = previous.txHash;
+        tx = previous.tx;
+        configData = ccc.bytesFrom(tx.outputsData[previous.index]);
+    }
+}
 
-export interface PreparedTx { sendTx: () => Promise<string>; tx: ccc.Transaction };
-export async function publishBytesAsCell(bytes: ArrayBuffer, lockScript: ScriptLike, signer: Signer, dataName: string): Promise<PreparedTx> {
+/**
+ * Replaces the config cell at `configOutPoint` with one of the same type id and lock, whose merkle
+ * root is `merkleRoot`, the root of the keys in the order of the ring shuffle
+ */
+export async function prepareVoteConfigReplacement(
+    signer: Signer,
+    configOutPoint: ccc.OutPointLike,
+    merkleRoot: Uint8Array,
+): Promise<PreparedTx> {
+    const outPoint = ccc.OutPoint.from(configOutPoint);
+    const configTx = (await signer.client.getTransaction(outPoint.txHash))?.transaction;
+    const index = Number(outPoint.index);
+    if (!configTx || !configTx.outputs[index]) {
+        throw new Error("Config cell not found");
+    }
+    const config = decodeVoteConfig(ccc.bytesFrom(configTx.outputsData[index]));
     const tx = ccc.Transaction.from({
-        outputs: [{ lock: lockScript }],
-        outputsData: [bytes],
+        inputs: [{ previousOutput: outPoint }],
+        outputs: [configTx.outputs[index]],
+        outputsData: [encodeVoteConfig({ ...config, merkleRoot })],
     });
-
+    await tx.completeFeeBy(signer, 1000);
     return {
         sendTx: async () => {
-            await tx.completeFeeBy(signer, 1000);
-            await tx.completeInputsAll(signer);
             console.log(tx);
             console.log(tx.hash());
             return await signer.sendTransaction(tx);
         },
-        tx
+        tx,
     };
 }
 
+/** Type script of the public key registry cells of the election with the config of `configTypeHash` */
+export function publicKeyRegistryType(configTypeHash: ccc.HexLike): ccc.Script {
+    const script = offCKBConfig.myScripts["ring-signature-verify"]!;
+    return new ccc.Script(script.codeHash, script.hashType, ccc.hexFrom(new Uint8Array([
+        SCRIPT_ROLE_PUBLIC_KEY_REGISTRY,
+        ...ccc.bytesFrom(configTypeHash),
+    ])));
+}
+
+/**
+ * Creates public key registry cells of all public keys of an election, one transaction each
+ *
+ * The vote script only accepts registry cells from the administrator, so every transaction has
+ * the config cell as a dep and spends a cell of the admin lock. Inputs are collected when sending,
+ * after the config cell is sent, so transactions must be sent in order.
+ */
+export function preparePublicKeyRegistryCells(
+    signer: Signer,
+    adminLock: ScriptLike,
+    configOutPoint: ccc.OutPointLike,
+    configTypeHash: ccc.HexLike,
+    config: Pick<VoteConfig, "signatureScheme" | "rsaKeyBits" | "groupSize">,
+    publicKeys: Uint8Array,
+): PreparedTx[] {
+    const script = offCKBConfig.myScripts["ring-signature-verify"]!;
+    const cells = encode_public_key_registry_cells(
+        config.signatureScheme,
+        config.rsaKeyBits,
+        config.groupSize,
+        RINGS_PER_REGISTRY_CELL,
+        publicKeys,
+    );
+    return cells.map(data => {
+        const tx = ccc.Transaction.from({
+            cellDeps: [
+                ccc.CellDep.from({ outPoint: configOutPoint, depType: 0 }),
+                script.cellDeps[0].cellDep,
+            ],
+            outputs: [{ lock: adminLock, type: publicKeyRegistryType(configTypeHash) }],
+            outputsData: [data],
+        });
+        return {
+            sendTx: async () => {
+                await tx.completeInputsAtLeastOne(signer);
+                await tx.completeFeeBy(signer, 1000);
+                console.log(tx);
+                console.log(tx.hash());
+                return await signer.sendTransaction(tx);
+            },
+            tx,
+        };
+    });
+}
+
+/**
+ * Message of an error of sending a transaction, with the reason of the exit code if the vote
+ * script rejected it. Nodes report exit codes of type scripts as "Type ... see error code N", lock
+ * scripts have codes of their own.
+ */
+export function describeTxError(e: unknown): string {
+    const message = String(e);
+    const code = message.match(/\.Type\b[\s\S]*?see error code (-?\d+)/)?.[1];
+    const reason = code === undefined ? undefined : vote_error_reason_wasm(Number(code));
+    return reason === undefined ? message : `Rejected by the vote script: ${reason}\n\n${message}`;
+}
+
 export interface AccountData {
     addresses: Address[];
     balance: bigint;
     signer: Signer;
 }
 
-export function encodeBigIntArray(arr: bigint[], entrySize: number): Uint8Array {
-    const buf = Buffer.alloc(arr.length * entrySize);
-    let idx = 0;
-    for (const item of arr) {
-        const nBuf = bigintConversion.bigintToBuf(item, true) as ArrayBuffer;
-        buf.set(new Uint8Array(nBuf).reverse(), idx);
-        idx += entrySize;
-    }
-    const result = new Uint8Array(buf.buffer);
-    return result;
-}
-
-
 export function encodeUint32LE(x: number): Uint8Array {
 
     return new Uint8Array([
@@ -1,12 +1,22 @@
+use js_sys::Uint8Array;
 use rand::SeedableRng;
 use rand_chacha::ChaCha20Rng;
 use signature_tools::{
+    candidate::Candidate,
     check_size_and_write,
+    ec_tools::{self, LsagPrivateKey, LsagPublicKey, LsagScheme, LsagSignature},
+    key_image_registry::KeyImageRegistry,
+    merkle_tree::{create_merkle_tree_with_proof, create_merkle_tree_with_root_hash, ring_range},
+    public_key_registry::{public_key_registry_cells, rebuild_rings, ring_public_keys},
+    ring_assignment::{check_ring_assignment, key_set_root, ring_order, ring_seed_block},
+    ring_signature::RingSignatureScheme,
     rsa_tools::{
-        create_signature,
-        merkle_tree::{create_merkle_tree_with_proof_rsa, create_merkle_tree_with_root_hash_rsa},
-        PrivateKeyParts, PublicKeyParts, RsaPrivateKey, RsaPublicKey,
+        create_signature_with_rng, ring_key_size, verify_signature, PrivateKeyParts,
+        PublicKeyParts, RSASignature, RSASignaturePubKeyEnt, RsaKeySize, RsaPrivateKey,
+        RsaPublicKey, RsaScheme,
     },
+    vote::{ballot_message, verify_vote, VoteCell, VoteError, VoteWitness, WitnessSignature},
+    vote_config::{SignatureScheme, VoteConfig},
     BigUint,
 };
 use wasm_bindgen::prelude::wasm_bindgen;
@@ -23,7 +33,7 @@ pub struct RawSignature {
 fn ensure_size(a: BigUint, expected_size: usize) -> Vec<u8> {
     let mut x = a.to_bytes_le();
     x.resize(expected_size, 0);
-    return x;
+    x
 }
 #[wasm_bindgen]
 extern "C" {
@@ -32,15 +42,29 @@ extern "C" {
 
 }
 
+fn key_size(key_bits: u32) -> Result<RsaKeySize, String> {
+    RsaKeySize::from_bits(key_bits).map_err(|e| format!("{:?}", e))
+}
+
+/// Scheme of `signature_scheme` and `rsa_key_bits` as in the vote config
+fn signature_scheme(signature_scheme: u8, rsa_key_bits: u32) -> Result<SignatureScheme, String> {
+    SignatureScheme::decode(signature_scheme, rsa_key_bits).map_err(|e| format!("{:?}", e))
+}
+
 fn parse_pubkey_entries_from_raw_buf(
     n: usize,
+    key_bits: u32,
     e_arr: &[u8],
     n_arr: &[u8],
 ) -> Result<Vec<RsaPublicKey>, String> {
+    let size = key_size(key_bits)?.bytes();
+    if e_arr.len() != n * 4 || n_arr.len() != n * size {
+        return Err(format!("Public keys must be {} of {} bits", n, key_bits));
+    }
     let mut pub_keys = Vec::<RsaPublicKey>::new();
     for i in 0..n {
         let e = BigUint::from_bytes_le(&e_arr[i * 4..(i + 1) * 4]);
-        let n = BigUint::from_bytes_le(&n_arr[i * 256..(i + 1) * 256]);
+        let n = BigUint::from_bytes_le(&n_arr[i * size..(i + 1) * size]);
         pub_keys.push(
             RsaPublicKey::new(n.clone(), e.clone())
                 .map_err(|err| format!("Bad public key (n={}, e={}): {}", n, e, err))?,
@@ -49,18 +73,31 @@ fn parse_pubkey_entries_from_raw_buf(
     Ok(pub_keys)
 }
 
+/**
+ * Sign over the ring of keys in the election `election_id`, which is the type hash of the config
+ * cell. The key image is a multiple of a prime of the signer, which gives the signer away, and
+ * the contract refuses RSA elections, see `signature_tools::rsa_tools`. A vote signs the
+ * `message` of `ballot_message_wasm`. Random numbers of the signature are drawn from a rng seeded
+ * with the 32 bytes `rand_seed`, so callers supply the entropy instead of relying on `getrandom`
+ */
 #[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
 pub fn create_ring_signature_rsa_wasm(
     n: usize,
+    key_bits: u32,
     pub_keys_e_arr: &[u8],
     pub_keys_n_arr: &[u8],
     priv_key_p: &[u8],
     priv_key_q: &[u8],
     priv_key_d: &[u8],
     signer: usize,
+    election_id: &[u8],
     message: &[u8],
+    rand_seed: &[u8],
 ) -> Result<RawSignature, String> {
-    let pub_keys = parse_pubkey_entries_from_raw_buf(n, pub_keys_e_arr, pub_keys_n_arr)?;
+    let mut rng = ChaCha20Rng::from_seed(to_array(rand_seed, "Seed")?);
+    let election_id = to_array(election_id, "Election id")?;
+    let pub_keys = parse_pubkey_entries_from_raw_buf(n, key_bits, pub_keys_e_arr, pub_keys_n_arr)?;
 
     let signer_pub_key = &pub_keys[signer];
     let private_key = RsaPrivateKey::from_components(
@@ -73,31 +110,124 @@ pub fn create_ring_signature_rsa_wasm(
         ],
     )
     .map_err(|e| format!("Bad private key: {}", e))?;
-    let signature = create_signature(&pub_keys, &private_key, signer, message)
-        .map_err(|e| format!("Unable to sign: {}", e))?;
-
-    let result = {
-        let mut r_bytes = vec![0u8; 0];
-        let mut e_bytes = vec![0u8; 0];
-        let mut n_bytes = vec![0u8; 0];
-
-        for item in signature.r_and_pubkey.into_iter() {
-            check_size_and_write(&mut r_bytes, &item.r, 256)
-                .map_err(|e| format!("Failed to write: {}", e))?;
-            check_size_and_write(&mut e_bytes, &item.e, 4)
-                .map_err(|e| format!("Failed to write: {}", e))?;
-            check_size_and_write(&mut n_bytes, &item.n, 256)
-                .map_err(|e| format!("Failed to write: {}", e))?;
-        }
-        RawSignature {
-            c: ensure_size(signature.c, 256),
-            i: ensure_size(signature.i, 256),
-            r_arr: r_bytes,
-            e_arr: e_bytes,
-            n_arr: n_bytes,
-        }
+    let signature = create_signature_with_rng(
+        &pub_keys,
+        &private_key,
+        signer,
+        &election_id,
+        message,
+        &mut rng,
+    )
+    .map_err(|e| format!("Unable to sign: {}", e))?;
+
+    raw_signature(signature)
+}
+
+fn raw_signature(signature: RSASignature) -> Result<RawSignature, String> {
+    let size = ring_key_size(&signature.r_and_pubkey)
+        .map_err(|e| format!("{:?}", e))?
+        .bytes();
+    let mut r_bytes = vec![0u8; 0];
+    let mut e_bytes = vec![0u8; 0];
+    let mut n_bytes = vec![0u8; 0];
+
+    for item in signature.r_and_pubkey.into_iter() {
+        check_size_and_write(&mut r_bytes, &item.r, size)
+            .map_err(|e| format!("Failed to write: {}", e))?;
+        check_size_and_write(&mut e_bytes, &item.e, 4)
+            .map_err(|e| format!("Failed to write: {}", e))?;
+        check_size_and_write(&mut n_bytes, &item.n, size)
+            .map_err(|e| format!("Failed to write: {}", e))?;
+    }
+    Ok(RawSignature {
+        c: ensure_size(signature.c, size),
+        i: ensure_size(signature.i, size),
+        r_arr: r_bytes,
+        e_arr: e_bytes,
+        n_arr: n_bytes,
+    })
+}
+
+/**
+ * Verify a signature made by `create_ring_signature_rsa_wasm` over the ring of `n` public keys in
+ * the election `election_id`, so a voter can check the ballot before publishing it
+ */
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn verify_ring_signature_rsa_wasm(
+    n: usize,
+    key_bits: u32,
+    pub_keys_e_arr: &[u8],
+    pub_keys_n_arr: &[u8],
+    election_id: &[u8],
+    message: &[u8],
+    c: &[u8],
+    i: &[u8],
+    r_arr: &[u8],
+) -> Result<bool, String> {
+    let election_id = to_array(election_id, "Election id")?;
+    let pub_keys = parse_pubkey_entries_from_raw_buf(n, key_bits, pub_keys_e_arr, pub_keys_n_arr)?;
+    let size = key_size(key_bits)?.bytes();
+    if r_arr.len() != n * size {
+        return Err(String::from("Bad length of r_arr"));
+    }
+    let signature = RSASignature {
+        c: BigUint::from_bytes_le(c),
+        i: BigUint::from_bytes_le(i),
+        r_and_pubkey: pub_keys
+            .iter()
+            .zip(r_arr.chunks(size))
+            .map(|(key, r)| RSASignaturePubKeyEnt {
+                r: BigUint::from_bytes_le(r),
+                e: key.e().clone(),
+                n: key.n().clone(),
+            })
+            .collect(),
     };
-    Ok(result)
+    verify_signature(&pub_keys, &election_id, message, &signature).map_err(|e| format!("{:?}", e))
+}
+
+/**
+ * Serialize a signature as `RSASignature::to_bytes`
+ */
+#[wasm_bindgen]
+pub fn encode_ring_signature_rsa(
+    key_bits: u32,
+    c: &[u8],
+    i: &[u8],
+    r_arr: &[u8],
+    e_arr: &[u8],
+    n_arr: &[u8],
+) -> Result<Vec<u8>, String> {
+    let size = key_size(key_bits)?.bytes();
+    let ring_size = r_arr.len() / size;
+    if r_arr.len() != ring_size * size
+        || n_arr.len() != ring_size * size
+        || e_arr.len() != ring_size * 4
+    {
+        return Err(String::from("Bad ring size"));
+    }
+    RSASignature {
+        c: BigUint::from_bytes_le(c),
+        i: BigUint::from_bytes_le(i),
+        r_and_pubkey: (0..ring_size)
+            .map(|idx| RSASignaturePubKeyEnt {
+                r: BigUint::from_bytes_le(&r_arr[idx * size..(idx + 1) * size]),
+                e: BigUint::from_bytes_le(&e_arr[idx * 4..(idx + 1) * 4]),
+                n: BigUint::from_bytes_le(&n_arr[idx * size..(idx + 1) * size]),
+            })
+            .collect(),
+    }
+    .to_bytes()
+    .map_err(|e| format!("{:?}", e))
+}
+
+/**
+ * Parse a signature serialized by `RSASignature::to_bytes`
+ */
+#[wasm_bindgen]
+pub fn decode_ring_signature_rsa(data: &[u8]) -> Result<RawSignature, String> {
+    raw_signature(RSASignature::from_bytes(data).map_err(|e| format!("{:?}", e))?)
 }
 
 #[wasm_bindgen(getter_with_clone)]
@@ -113,27 +243,30 @@ pub struct RsaKeyPair {
 }
 
 #[wasm_bindgen]
-pub fn derive_rsa_key_pair_form_rand_seed(seed: &[u8]) -> Result<RsaKeyPair, String> {
+pub fn derive_rsa_key_pair_form_rand_seed(
+    seed: &[u8],
+    key_bits: u32,
+) -> Result<RsaKeyPair, String> {
     if seed.len() != 32 {
         return Err(String::from("Seed must be in 32bytes"));
     }
     let mut seed_fixed = [0u8; 32];
     seed_fixed.copy_from_slice(seed);
     let mut rng = ChaCha20Rng::from_seed(seed_fixed);
-    let privkey = RsaPrivateKey::new(&mut rng, 2048)
+    let privkey = RsaPrivateKey::new(&mut rng, key_size(key_bits)?.bits() as usize)
         .map_err(|e| format!("Failed to generate key pair: {}", e))?;
     Ok(RsaKeyPair {
         n: privkey.n().to_bytes_le(),
         e: privkey.e().to_bytes_le(),
         p: privkey
             .primes()
-            .get(0)
-            .ok_or_else(|| format!("Missing first prime number"))?
+            .first()
+            .ok_or_else(|| String::from("Missing first prime number"))?
             .to_bytes_le(),
         q: privkey
             .primes()
             .get(1)
-            .ok_or_else(|| format!("Missing second prime number"))?
+            .ok_or_else(|| String::from("Missing second prime number"))?
             .to_bytes_le(),
         d: privkey.d().to_bytes_le(),
     })
@@ -141,14 +274,16 @@ pub fn derive_rsa_key_pair_form_rand_seed(seed: &[u8]) -> Result<RsaKeyPair, Str
 #[wasm_bindgen]
 pub fn create_merkle_tree_root_rsa(
     n: usize,
+    key_bits: u32,
     group_size: usize,
     n_arr: &[u8],
     e_arr: &[u8],
 ) -> Result<Vec<u8>, String> {
-    let pub_keys = parse_pubkey_entries_from_raw_buf(n, e_arr, n_arr)?;
+    let pub_keys = parse_pubkey_entries_from_raw_buf(n, key_bits, e_arr, n_arr)?;
 
-    let root = create_merkle_tree_with_root_hash_rsa(&pub_keys, group_size)
-        .map_err(|e| format!("{:?}", e))?;
+    let root =
+        create_merkle_tree_with_root_hash(&RsaScheme(key_size(key_bits)?), &pub_keys, group_size)
+            .map_err(|e| format!("{:?}", e))?;
     Ok(root)
 }
 
@@ -160,17 +295,787 @@ pub struct MerkleProofResultWasm {
 #[wasm_bindgen]
 pub fn create_merkle_tree_proof_rsa(
     n: usize,
+    key_bits: u32,
     group_size: usize,
     n_arr: &[u8],
     e_arr: &[u8],
     leaf_index: usize,
 ) -> Result<MerkleProofResultWasm, String> {
-    let pub_keys = parse_pubkey_entries_from_raw_buf(n, e_arr, n_arr)?;
+    let pub_keys = parse_pubkey_entries_from_raw_buf(n, key_bits, e_arr, n_arr)?;
     log(&format!("pub keys length: {}", n));
-    let result = create_merkle_tree_with_proof_rsa(&pub_keys, group_size, leaf_index)
-        .map_err(|e| format!("{:?}", e))?;
+    let result = create_merkle_tree_with_proof(
+        &RsaScheme(key_size(key_bits)?),
+        &pub_keys,
+        group_size,
+        leaf_index,
+    )
+    .map_err(|e| format!("{:?}", e))?;
+    Ok(MerkleProofResultWasm {
+        proof: result.proof,
+        leaf_hash: result.leaf_hash,
+    })
+}
+
+fn lsag_public_keys(public_keys: &[u8]) -> Result<Vec<LsagPublicKey>, String> {
+    if !public_keys.len().is_multiple_of(32) {
+        return Err(String::from("Public keys must be in 32bytes each"));
+    }
+    Ok(public_keys
+        .chunks(32)
+        .map(|x| x.try_into().unwrap())
+        .collect())
+}
+
+fn lsag_scalars(bytes: &[u8], n: usize, name: &str) -> Result<Vec<[u8; 32]>, String> {
+    if bytes.len() != n * 32 {
+        return Err(format!("{} must be {} of 32bytes", name, n));
+    }
+    Ok(bytes.chunks(32).map(|x| x.try_into().unwrap()).collect())
+}
+
+#[wasm_bindgen(getter_with_clone)]
+/**
+ * Private key is a canonical little endian scalar, public key is a compressed ristretto255 point
+ */
+pub struct LsagKeyPair {
+    pub private_key: Vec<u8>,
+    pub public_key: Vec<u8>,
+}
+
+#[wasm_bindgen]
+pub fn derive_lsag_key_pair_from_rand_seed(seed: &[u8]) -> Result<LsagKeyPair, String> {
+    let mut rng = ChaCha20Rng::from_seed(to_array(seed, "Seed")?);
+    let key = LsagPrivateKey::random(&mut rng);
+    Ok(LsagKeyPair {
+        private_key: key.to_bytes().to_vec(),
+        public_key: key.public_key().to_vec(),
+    })
+}
+
+#[wasm_bindgen(getter_with_clone)]
+/**
+ * `r_arr` is concatenated, 32 bytes each
+ */
+pub struct LsagSignatureWasm {
+    pub c: Vec<u8>,
+    pub image: Vec<u8>,
+    pub r_arr: Vec<u8>,
+}
+
+/**
+ * Sign over the ring of concatenated `public_keys`, 32 bytes each, in the election `election_id`.
+ * Random scalars are drawn from a rng seeded with `rand_seed`, same as
+ * `create_ring_signature_rsa_wasm`
+ */
+#[wasm_bindgen]
+pub fn create_ring_signature_lsag_wasm(
+    public_keys: &[u8],
+    private_key: &[u8],
+    signer: usize,
+    election_id: &[u8],
+    message: &[u8],
+    rand_seed: &[u8],
+) -> Result<LsagSignatureWasm, String> {
+    let mut rng = ChaCha20Rng::from_seed(to_array(rand_seed, "Seed")?);
+    let election_id = to_array(election_id, "Election id")?;
+    let ring = lsag_public_keys(public_keys)?;
+    let private_key = LsagPrivateKey::from_bytes(to_array(private_key, "Private key")?)
+        .map_err(|e| format!("Bad private key: {}", e))?;
+    let signature = ec_tools::create_signature_with_rng(
+        &ring,
+        &private_key,
+        signer,
+        &election_id,
+        message,
+        &mut rng,
+    )
+    .map_err(|e| format!("Unable to sign: {}", e))?;
+    Ok(LsagSignatureWasm {
+        c: signature.c.to_vec(),
+        image: signature.image.to_vec(),
+        r_arr: signature.r.concat(),
+    })
+}
+
+/**
+ * Verify a signature made by `create_ring_signature_lsag_wasm`
+ */
+#[wasm_bindgen]
+pub fn verify_ring_signature_lsag_wasm(
+    public_keys: &[u8],
+    election_id: &[u8],
+    message: &[u8],
+    c: &[u8],
+    image: &[u8],
+    r_arr: &[u8],
+) -> Result<bool, String> {
+    let public_keys = lsag_public_keys(public_keys)?;
+    let election_id = to_array(election_id, "Election id")?;
+    let signature = LsagSignature {
+        c: to_array(c, "c")?,
+        image: to_array(image, "Image")?,
+        r: lsag_scalars(r_arr, public_keys.len(), "r_arr")?,
+        public_keys: public_keys.clone(),
+    };
+    ec_tools::verify_signature(&public_keys, &election_id, message, &signature)
+        .map_err(|e| format!("{:?}", e))
+}
+
+#[wasm_bindgen]
+pub fn create_merkle_tree_root_lsag(
+    group_size: usize,
+    public_keys: &[u8],
+) -> Result<Vec<u8>, String> {
+    create_merkle_tree_with_root_hash(&LsagScheme, &lsag_public_keys(public_keys)?, group_size)
+        .map_err(|e| format!("{:?}", e))
+}
+
+#[wasm_bindgen]
+pub fn create_merkle_tree_proof_lsag(
+    group_size: usize,
+    public_keys: &[u8],
+    leaf_index: usize,
+) -> Result<MerkleProofResultWasm, String> {
+    let result = create_merkle_tree_with_proof(
+        &LsagScheme,
+        &lsag_public_keys(public_keys)?,
+        group_size,
+        leaf_index,
+    )
+    .map_err(|e| format!("{:?}", e))?;
     Ok(MerkleProofResultWasm {
         proof: result.proof,
         leaf_hash: result.leaf_hash,
     })
 }
+
+#[wasm_bindgen(getter_with_clone)]
+pub struct KeyImageRegistryUpdateWasm {
+    pub proof: Vec<u8>,
+    pub old_root: Vec<u8>,
+    pub new_root: Vec<u8>,
+}
+
+/**
+ * Images are concatenated, each in the size of the scheme, in the same encoding as in vote cells
+ */
+#[wasm_bindgen]
+pub fn create_key_image_registry_update(
+    signature_scheme: u8,
+    rsa_key_bits: u32,
+    used_images: &[u8],
+    new_images: &[u8],
+) -> Result<KeyImageRegistryUpdateWasm, String> {
+    let size = self::signature_scheme(signature_scheme, rsa_key_bits)?.image_size();
+    if !used_images.len().is_multiple_of(size) || !new_images.len().is_multiple_of(size) {
+        return Err(format!("Images must be in {}bytes", size));
+    }
+    let mut registry =
+        KeyImageRegistry::from_images(used_images.chunks(size)).map_err(|e| format!("{:?}", e))?;
+    let update = registry
+        .insert_images(&new_images.chunks(size).collect::<Vec<_>>())
+        .map_err(|e| format!("{:?}", e))?;
+    Ok(KeyImageRegistryUpdateWasm {
+        proof: update.proof,
+        old_root: update.old_root.to_vec(),
+        new_root: update.new_root.to_vec(),
+    })
+}
+
+fn decode_public_keys<S: RingSignatureScheme>(
+    scheme: &S,
+    public_keys: &[u8],
+) -> Result<Vec<S::PublicKey>, String> {
+    let key_size = scheme.signature_scheme().public_key_size();
+    if !public_keys.len().is_multiple_of(key_size) {
+        return Err(format!("Public keys must be in {}bytes each", key_size));
+    }
+    public_keys
+        .chunks(key_size)
+        .map(|key| {
+            scheme
+                .decode_public_key(key)
+                .map_err(|e| format!("{:?}", e))
+        })
+        .collect()
+}
+
+fn encode_registry_cells<S: RingSignatureScheme>(
+    scheme: &S,
+    group_size: usize,
+    rings_per_cell: usize,
+    public_keys: &[u8],
+) -> Result<Vec<Uint8Array>, String> {
+    let public_keys = decode_public_keys(scheme, public_keys)?;
+    public_key_registry_cells(&public_keys, group_size, rings_per_cell)
+        .map_err(|e| format!("{:?}", e))?
+        .iter()
+        .map(|cell| {
+            let data = cell.encode(scheme).map_err(|e| format!("{:?}", e))?;
+            Ok(Uint8Array::from(&data[..]))
+        })
+        .collect()
+}
+
+/**
+ * Data of the public key registry cells of an election, `rings_per_cell` rings each. Public keys
+ * are concatenated in the order of the merkle tree, an RSA key is `n` in the size of keys followed
+ * by `e` in 4 bytes, an LSAG key is the compressed point
+ */
+#[wasm_bindgen]
+pub fn encode_public_key_registry_cells(
+    signature_scheme: u8,
+    rsa_key_bits: u32,
+    group_size: usize,
+    rings_per_cell: usize,
+    public_keys: &[u8],
+) -> Result<Vec<Uint8Array>, String> {
+    let scheme = self::signature_scheme(signature_scheme, rsa_key_bits)?;
+    match scheme {
+        SignatureScheme::Rsa(key_size) => encode_registry_cells(
+            &RsaScheme(key_size),
+            group_size,
+            rings_per_cell,
+            public_keys,
+        ),
+        SignatureScheme::Lsag => {
+            encode_registry_cells(&LsagScheme, group_size, rings_per_cell, public_keys)
+        }
+    }
+}
+
+fn rebuild_public_keys<S: RingSignatureScheme>(
+    scheme: &S,
+    config: &VoteConfig,
+    cells: &[Vec<u8>],
+) -> Result<Vec<u8>, String> {
+    let mut public_keys = vec![];
+    for ring in rebuild_rings(scheme, config, cells.iter().map(Vec::as_slice))
+        .map_err(|e| format!("{:?}", e))?
+    {
+        for key in &ring.public_keys {
+            public_keys.extend(
+                scheme
+                    .encode_public_key(key)
+                    .map_err(|e| format!("{:?}", e))?,
+            );
+        }
+    }
+    Ok(public_keys)
+}
+
+/**
+ * All public keys of the election with the encoded `config`, from data of its public key registry
+ * cells in any order, in the same layout as `encode_public_key_registry_cells`. Fails unless the
+ * cells cover all keys once and the keys match the merkle root of the config
+ */
+#[wasm_bindgen]
+pub fn public_keys_from_registry_cells(
+    config: &[u8],
+    cells: Vec<Uint8Array>,
+) -> Result<Vec<u8>, String> {
+    let config = VoteConfig::decode(config).map_err(|e| format!("{:?}", e))?;
+    let cells = cells.iter().map(Uint8Array::to_vec).collect::<Vec<_>>();
+    match config.signature_scheme {
+        SignatureScheme::Rsa(key_size) => {
+            rebuild_public_keys(&RsaScheme(key_size), &config, &cells)
+        }
+        SignatureScheme::Lsag => rebuild_public_keys(&LsagScheme, &config, &cells),
+    }
+}
+
+fn ring_order_of<S: RingSignatureScheme>(
+    scheme: &S,
+    seed: &[u8; 32],
+    public_keys: &[u8],
+) -> Result<Vec<u32>, String> {
+    Ok(
+        ring_order(scheme, &decode_public_keys(scheme, public_keys)?, seed)
+            .map_err(|e| format!("{:?}", e))?
+            .into_iter()
+            .map(|index| index as u32)
+            .collect(),
+    )
+}
+
+/**
+ * Order of public keys in the merkle tree of an election, whose shuffle is seeded by `seed`, the
+ * hash of the block `ring_seed_block_wasm`, so rings are assigned by a public shuffle rather than
+ * by the administrator. Keys are in the layout of `encode_public_key_registry_cells`, in any
+ * order, and the key at `i` of the tree is the one at `order[i]` of `public_keys`
+ */
+#[wasm_bindgen]
+pub fn ring_order_wasm(
+    signature_scheme: u8,
+    rsa_key_bits: u32,
+    seed: &[u8],
+    public_keys: &[u8],
+) -> Result<Vec<u32>, String> {
+    let seed = to_array::<32>(seed, "Seed")?;
+    match self::signature_scheme(signature_scheme, rsa_key_bits)? {
+        SignatureScheme::Rsa(key_size) => ring_order_of(&RsaScheme(key_size), &seed, public_keys),
+        SignatureScheme::Lsag => ring_order_of(&LsagScheme, &seed, public_keys),
+    }
+}
+
+/**
+ * Block whose hash seeds the shuffle of an election, whose config cell was first created in
+ * `config_block`
+ */
+#[wasm_bindgen]
+pub fn ring_seed_block_wasm(config_block: u64) -> u64 {
+    ring_seed_block(config_block)
+}
+
+fn key_set_root_of<S: RingSignatureScheme>(
+    scheme: &S,
+    group_size: usize,
+    public_keys: &[u8],
+) -> Result<Vec<u8>, String> {
+    key_set_root(
+        scheme,
+        &decode_public_keys(scheme, public_keys)?,
+        group_size,
+    )
+    .map(Vec::from)
+    .map_err(|e| format!("{:?}", e))
+}
+
+/**
+ * Merkle root of public keys sorted by their encoding, which the first config cell of an
+ * election carries to commit to its keys before the seed of the shuffle is known. Keys are in
+ * the layout of `encode_public_key_registry_cells`, in any order
+ */
+#[wasm_bindgen]
+pub fn key_set_root_wasm(
+    signature_scheme: u8,
+    rsa_key_bits: u32,
+    group_size: usize,
+    public_keys: &[u8],
+) -> Result<Vec<u8>, String> {
+    match self::signature_scheme(signature_scheme, rsa_key_bits)? {
+        SignatureScheme::Rsa(key_size) => {
+            key_set_root_of(&RsaScheme(key_size), group_size, public_keys)
+        }
+        SignatureScheme::Lsag => key_set_root_of(&LsagScheme, group_size, public_keys),
+    }
+}
+
+fn check_ring_order<S: RingSignatureScheme>(
+    scheme: &S,
+    first_config: &VoteConfig,
+    seed: &[u8; 32],
+    public_keys: &[u8],
+) -> Result<(), String> {
+    check_ring_assignment(
+        scheme,
+        &decode_public_keys(scheme, public_keys)?,
+        first_config,
+        seed,
+    )
+    .map_err(|e| format!("{:?}", e))
+}
+
+/**
+ * Check that `public_keys` of the election with the encoded `config`, in the order of
+ * `public_keys_from_registry_cells`, are the keys committed to by the encoded `first_config`,
+ * the config which created the election, and are assigned to rings by the shuffle of
+ * `ring_order_wasm` seeded with `seed`
+ */
+#[wasm_bindgen]
+pub fn check_ring_assignment_wasm(
+    config: &[u8],
+    first_config: &[u8],
+    seed: &[u8],
+    public_keys: &[u8],
+) -> Result<(), String> {
+    let config = VoteConfig::decode(config).map_err(|e| format!("{:?}", e))?;
+    let first_config = VoteConfig::decode(first_config).map_err(|e| format!("{:?}", e))?;
+    let seed = to_array::<32>(seed, "Seed")?;
+    match config.signature_scheme {
+        SignatureScheme::Rsa(key_size) => {
+            check_ring_order(&RsaScheme(key_size), &first_config, &seed, public_keys)
+        }
+        SignatureScheme::Lsag => check_ring_order(&LsagScheme, &first_config, &seed, public_keys),
+    }
+}
+
+/**
+ * Index among `cells`, data of public key registry cells of the election with the encoded
+ * `config`, of the cell with all keys of the ring at `leaf_index`, which a vote can reference as
+ * a cell dep instead of carrying the keys, or none if no cell has all of them
+ */
+#[wasm_bindgen]
+pub fn find_ring_registry_cell(
+    config: &[u8],
+    cells: Vec<Uint8Array>,
+    leaf_index: u32,
+) -> Result<Option<u32>, String> {
+    let config = VoteConfig::decode(config).map_err(|e| format!("{:?}", e))?;
+    let ring_size = ring_range(
+        config.user_count as usize,
+        config.group_size as usize,
+        leaf_index as usize,
+    )
+    .len();
+    Ok(cells
+        .iter()
+        .position(|cell| ring_public_keys(&cell.to_vec(), &config, leaf_index, ring_size).is_ok())
+        .map(|index| index as u32))
+}
+
+fn to_array<const N: usize>(bytes: &[u8], name: &str) -> Result<[u8; N], String> {
+    bytes
+        .try_into()
+        .map_err(|_| format!("{} must be in {}bytes", name, N))
+}
+
+/**
+ * Encode data of a vote cell as `VoteCell`, the scheme is `signature_scheme` and `rsa_key_bits`
+ * of the config
+ */
+#[wasm_bindgen]
+pub fn encode_vote_cell(
+    signature_scheme: u8,
+    rsa_key_bits: u32,
+    candidate_id: &[u8],
+    image: &[u8],
+) -> Result<Vec<u8>, String> {
+    let scheme = self::signature_scheme(signature_scheme, rsa_key_bits)?;
+    if image.len() != scheme.image_size() {
+        return Err(format!("Image must be in {}bytes", scheme.image_size()));
+    }
+    Ok(VoteCell {
+        candidate_id: to_array(candidate_id, "Candidate id")?,
+        image: image.to_vec(),
+    }
+    .encode())
+}
+
+#[wasm_bindgen(getter_with_clone)]
+pub struct VoteCellWasm {
+    pub candidate_id: Vec<u8>,
+    pub image: Vec<u8>,
+}
+
+#[wasm_bindgen]
+pub fn decode_vote_cell(
+    signature_scheme: u8,
+    rsa_key_bits: u32,
+    data: &[u8],
+) -> Result<VoteCellWasm, String> {
+    let scheme = self::signature_scheme(signature_scheme, rsa_key_bits)?;
+    let cell = VoteCell::decode(data, scheme).map_err(|e| format!("{:?}", e))?;
+    Ok(VoteCellWasm {
+        candidate_id: cell.candidate_id.to_vec(),
+        image: cell.image,
+    })
+}
+
+/**
+ * Encode `output_type` of the witness of a vote cell as `VoteWitness`, arrays are in the same
+ * layout as in `RawSignature`. With `ring_cell_dep`, the index of a cell dep with the keys of
+ * the ring in a public key registry cell, the keys are left out of the witness
+ */
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn encode_vote_witness_rsa(
+    key_bits: u32,
+    c: &[u8],
+    r_arr: &[u8],
+    n_arr: &[u8],
+    e_arr: &[u8],
+    leaf_index: u32,
+    merkle_proof: &[u8],
+    ring_cell_dep: Option<u32>,
+) -> Result<Vec<u8>, String> {
+    let key_size = key_size(key_bits)?;
+    let size = key_size.bytes();
+    let ring_size = r_arr.len() / size;
+    if c.len() != size
+        || r_arr.len() != ring_size * size
+        || n_arr.len() != ring_size * size
+        || e_arr.len() != ring_size * 4
+    {
+        return Err(String::from("Bad ring size"));
+    }
+    VoteWitness {
+        signature: WitnessSignature::Rsa {
+            c: BigUint::from_bytes_le(c),
+            r_and_pubkey: (0..ring_size)
+                .map(|i| RSASignaturePubKeyEnt {
+                    r: BigUint::from_bytes_le(&r_arr[i * size..(i + 1) * size]),
+                    e: BigUint::from_bytes_le(&e_arr[i * 4..(i + 1) * 4]),
+                    n: BigUint::from_bytes_le(&n_arr[i * size..(i + 1) * size]),
+                })
+                .collect(),
+        },
+        leaf_index,
+        merkle_proof: merkle_proof.to_vec(),
+        ring_cell_dep,
+    }
+    .encode(SignatureScheme::Rsa(key_size))
+    .map_err(|e| format!("{:?}", e))
+}
+
+#[wasm_bindgen(getter_with_clone)]
+pub struct VoteWitnessWasm {
+    pub c: Vec<u8>,
+    pub r_arr: Vec<u8>,
+    pub e_arr: Vec<u8>,
+    pub n_arr: Vec<u8>,
+    pub leaf_index: u32,
+    pub merkle_proof: Vec<u8>,
+}
+
+#[wasm_bindgen]
+pub fn decode_vote_witness_rsa(key_bits: u32, data: &[u8]) -> Result<VoteWitnessWasm, String> {
+    let key_size = key_size(key_bits)?;
+    let size = key_size.bytes();
+    let witness = VoteWitness::decode(data, SignatureScheme::Rsa(key_size))
+        .map_err(|e| format!("{:?}", e))?;
+    let WitnessSignature::Rsa { c, r_and_pubkey } = witness.signature else {
+        unreachable!("Decoded as RSA");
+    };
+    let mut result = VoteWitnessWasm {
+        c: ensure_size(c, size),
+        r_arr: vec![],
+        e_arr: vec![],
+        n_arr: vec![],
+        leaf_index: witness.leaf_index,
+        merkle_proof: witness.merkle_proof,
+    };
+    for item in r_and_pubkey.into_iter() {
+        result.r_arr.extend(ensure_size(item.r, size));
+        result.e_arr.extend(ensure_size(item.e, 4));
+        result.n_arr.extend(ensure_size(item.n, size));
+    }
+    Ok(result)
+}
+
+/**
+ * Encode `output_type` of the witness of a vote cell as `VoteWitness`, arrays are in the same
+ * layout as in `LsagSignatureWasm`, and `ring_cell_dep` is as in `encode_vote_witness_rsa`
+ */
+#[wasm_bindgen]
+pub fn encode_vote_witness_lsag(
+    c: &[u8],
+    r_arr: &[u8],
+    public_keys: &[u8],
+    leaf_index: u32,
+    merkle_proof: &[u8],
+    ring_cell_dep: Option<u32>,
+) -> Result<Vec<u8>, String> {
+    let public_keys = lsag_public_keys(public_keys)?;
+    VoteWitness {
+        signature: WitnessSignature::Lsag {
+            c: to_array(c, "c")?,
+            r: lsag_scalars(r_arr, public_keys.len(), "r_arr")?,
+            public_keys,
+        },
+        leaf_index,
+        merkle_proof: merkle_proof.to_vec(),
+        ring_cell_dep,
+    }
+    .encode(SignatureScheme::Lsag)
+    .map_err(|e| format!("{:?}", e))
+}
+
+#[wasm_bindgen(getter_with_clone)]
+pub struct VoteWitnessLsagWasm {
+    pub c: Vec<u8>,
+    pub r_arr: Vec<u8>,
+    pub public_keys: Vec<u8>,
+    pub leaf_index: u32,
+    pub merkle_proof: Vec<u8>,
+}
+
+#[wasm_bindgen]
+pub fn decode_vote_witness_lsag(data: &[u8]) -> Result<VoteWitnessLsagWasm, String> {
+    let witness =
+        VoteWitness::decode(data, SignatureScheme::Lsag).map_err(|e| format!("{:?}", e))?;
+    let WitnessSignature::Lsag { c, r, public_keys } = witness.signature else {
+        unreachable!("Decoded as LSAG");
+    };
+    Ok(VoteWitnessLsagWasm {
+        c: c.to_vec(),
+        r_arr: r.concat(),
+        public_keys: public_keys.concat(),
+        leaf_index: witness.leaf_index,
+        merkle_proof: witness.merkle_proof,
+    })
+}
+
+#[wasm_bindgen(getter_with_clone)]
+pub struct VoteConfigWasm {
+    /// Genesis block hash of the chain
+    pub network: Vec<u8>,
+    pub merkle_root: Vec<u8>,
+    pub user_count: u32,
+    pub leaf_count: u32,
+    pub group_size: u32,
+    /// 0 for RSA and 1 for LSAG
+    pub signature_scheme: u8,
+    /// 0 for LSAG
+    pub rsa_key_bits: u32,
+    pub voting_start: u64,
+    pub voting_end: u64,
+    pub admin_lock_hash: Vec<u8>,
+    pub key_image_registry_type_hash: Vec<u8>,
+    /// 4 bytes each
+    pub candidate_ids: Vec<u8>,
+    pub candidate_descriptions: Vec<String>,
+}
+
+/**
+ * Encode data of the vote config cell, `candidate_ids` are concatenated, 4 bytes each
+ */
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn encode_vote_config(
+    network: &[u8],
+    merkle_root: &[u8],
+    user_count: u32,
+    leaf_count: u32,
+    group_size: u32,
+    signature_scheme: u8,
+    rsa_key_bits: u32,
+    voting_start: u64,
+    voting_end: u64,
+    admin_lock_hash: &[u8],
+    key_image_registry_type_hash: &[u8],
+    candidate_ids: &[u8],
+    candidate_descriptions: Vec<String>,
+) -> Result<Vec<u8>, String> {
+    if candidate_ids.len() != candidate_descriptions.len() * 4 {
+        return Err(String::from("Candidate ids and descriptions mismatch"));
+    }
+    Ok(VoteConfig {
+        network: to_array(network, "Network")?,
+        merkle_root: to_array(merkle_root, "Merkle root")?,
+        user_count,
+        leaf_count,
+        group_size,
+        signature_scheme: self::signature_scheme(signature_scheme, rsa_key_bits)?,
+        voting_start,
+        voting_end,
+        admin_lock_hash: to_array(admin_lock_hash, "Admin lock hash")?,
+        key_image_registry_type_hash: to_array(
+            key_image_registry_type_hash,
+            "Key image registry type hash",
+        )?,
+        candidates: candidate_ids
+            .chunks(4)
+            .zip(candidate_descriptions)
+            .map(|(id, description)| Candidate {
+                id: id.try_into().unwrap(),
+                description,
+            })
+            .collect(),
+    }
+    .encode())
+}
+
+#[wasm_bindgen]
+pub fn decode_vote_config(data: &[u8]) -> Result<VoteConfigWasm, String> {
+    let config = VoteConfig::decode(data).map_err(|e| format!("{:?}", e))?;
+    Ok(VoteConfigWasm {
+        network: config.network.to_vec(),
+        merkle_root: config.merkle_root.to_vec(),
+        user_count: config.user_count,
+        leaf_count: config.leaf_count,
+        group_size: config.group_size,
+        signature_scheme: config.signature_scheme.id(),
+        rsa_key_bits: config.signature_scheme.rsa_key_bits(),
+        voting_start: config.voting_start,
+        voting_end: config.voting_end,
+        admin_lock_hash: config.admin_lock_hash.to_vec(),
+        key_image_registry_type_hash: config.key_image_registry_type_hash.to_vec(),
+        candidate_ids: config.candidates.iter().flat_map(|x| x.id).collect(),
+        candidate_descriptions: config
+            .candidates
+            .into_iter()
+            .map(|x| x.description)
+            .collect(),
+    })
+}
+
+/**
+ * Message a vote for `candidate_id` signs, which binds it to the election `election_id`, the
+ * genesis block hash `network` of the config and the code hash `contract_code_hash` of the type
+ * script of vote cells. Votes sign it with `create_ring_signature_rsa_wasm` or
+ * `create_ring_signature_lsag_wasm`, and the contract verifies it
+ */
+#[wasm_bindgen]
+pub fn ballot_message_wasm(
+    election_id: &[u8],
+    network: &[u8],
+    contract_code_hash: &[u8],
+    candidate_id: &[u8],
+) -> Result<Vec<u8>, String> {
+    Ok(ballot_message(
+        &to_array(election_id, "Election id")?,
+        &to_array(network, "Network")?,
+        &to_array(contract_code_hash, "Contract code hash")?,
+        &to_array(candidate_id, "Candidate id")?,
+    )
+    .to_vec())
+}
+
+/**
+ * Verify an encoded vote cell and its `VoteWitness` against the encoded config of the election
+ * `election_id`, which is the type hash of the config cell, for the contract of code hash
+ * `contract_code_hash`, in the same way as the contract does, except the key image registry and
+ * the voting period. `ring_cell` is data of the registry cell the witness references for the
+ * keys of the ring, if any
+ */
+#[wasm_bindgen]
+pub fn verify_vote_wasm(
+    election_id: &[u8],
+    contract_code_hash: &[u8],
+    config: &[u8],
+    vote_cell: &[u8],
+    witness: &[u8],
+    ring_cell: Option<Vec<u8>>,
+) -> Result<(), String> {
+    let election_id = to_array(election_id, "Election id")?;
+    let contract_code_hash = to_array(contract_code_hash, "Contract code hash")?;
+    let config = VoteConfig::decode(config).map_err(|e| format!("{:?}", e))?;
+    let cell =
+        VoteCell::decode(vote_cell, config.signature_scheme).map_err(|e| format!("{:?}", e))?;
+    let witness = VoteWitness::decode_with_cell_deps(witness, &config, |_| {
+        ring_cell.ok_or_else(|| VoteError::IndexOutOfBound.into())
+    })
+    .map_err(|e| format!("{:?}", e))?;
+    verify_vote(&election_id, &contract_code_hash, &config, &cell, &witness)
+        .map_err(|e| format!("Rejected vote: {}", e))
+}
+
+/**
+ * Reason of an exit code of the contract, such as the one of a rejected transaction, or none if
+ * the contract never exits with the code
+ */
+#[wasm_bindgen]
+pub fn vote_error_reason_wasm(code: i8) -> Option<String> {
+    VoteError::from_code(code).map(|error| error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::verify_vote_wasm;
+
+    #[test]
+    fn test_vectors() {
+        for vector in test_vectors::load() {
+            let result = verify_vote_wasm(
+                &vector.election_id,
+                &vector.contract_code_hash,
+                &vector.config,
+                &vector.vote_cell,
+                &vector.witness,
+                None,
+            );
+            assert_eq!(result.is_ok(), vector.error.is_none(), "{}", vector.name);
+        }
+    }
+}
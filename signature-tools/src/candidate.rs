@@ -1,24 +1,51 @@
-use std::io::Write;
+use anyhow::{anyhow, Context};
+use vote_types::{
+    molecule::prelude::*,
+    schema::{self, CandidateVec, CandidateVecReader},
+};
 
+    /// Encode candidates as a `CandidateVec`, descriptions are truncated to 99 bytes
     pub fn encode_candidate_cell(entries: &[Candidate]) -> Vec<u8> {
-        let mut buf = Vec::<u8>::new();
-        buf.write_all(&(entries.len() as u16).to_le_bytes())
-            .unwrap();
-        for item in entries.iter() {
-            buf.write_all(&item.id).unwrap();
-            let mut str_bytes = item.description.as_bytes().to_vec();
-            while str_bytes.len() > 99 {
-                str_bytes.pop();
-            }
-            while str_bytes.len() < 100 {
-                str_bytes.push(0);
-            }
-            buf.write_all(&str_bytes).unwrap()
-        }
-        buf
+        CandidateVec::new_builder()
+            .set(entries.iter().map(Candidate::to_schema).collect())
+            .build()
+            .as_slice()
+            .to_vec()
+    }
+    pub fn decode_candidate_cell(buf: &[u8]) -> anyhow::Result<Vec<Candidate>> {
+        let reader = CandidateVecReader::from_slice(buf)
+            .map_err(|e| anyhow!("Bad candidate cell: {}", e))?;
+        decode_candidates(reader)
+    }
+    pub(crate) fn decode_candidates(reader: CandidateVecReader) -> anyhow::Result<Vec<Candidate>> {
+        reader
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let desc = c.description().raw_data();
+                let desc_len = desc.iter().rposition(|x| *x != 0).map_or(0, |x| x + 1);
+                Ok(Candidate {
+                    id: c.id().into(),
+                    description: String::from_utf8(desc[..desc_len].to_vec())
+                        .with_context(|| anyhow!("Bad utf8 bytes for candidate index {}", i))?,
+                })
+            })
+            .collect()
     }
-    #[derive(Debug)]
+    #[derive(Debug, Clone, PartialEq)]
     pub struct Candidate {
         pub id: [u8; 4],
         pub description: String,
     }
+    impl Candidate {
+        pub(crate) fn to_schema(&self) -> schema::Candidate {
+            let mut description = [0u8; 100];
+            let str_bytes = self.description.as_bytes();
+            let len = str_bytes.len().min(99);
+            description[..len].copy_from_slice(&str_bytes[..len]);
+            schema::Candidate::new_builder()
+                .id(self.id.into())
+                .description(description.into())
+                .build()
+        }
+    }
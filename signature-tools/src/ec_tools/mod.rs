@@ -0,0 +1,376 @@
+//! Linkable spontaneous anonymous group signatures (LSAG) over ristretto255
+//!
+//! A private key is a scalar `x` and its public key is `P = x * G`. Signing over a ring of
+//! public keys also gives the key image `I = x * Hp(P)`, where `Hp` hashes the election id and a
+//! public key onto the group, so every signature made by the same key in an election has the same
+//! image, while images of the key in other elections can't be linked to it. Scalars and compressed
+//! points are 32 bytes, so a ring member costs 64 bytes of witness.
+use anyhow::{anyhow, bail};
+use curve25519_dalek::{
+    ristretto::CompressedRistretto, traits::VartimeMultiscalarMul, RistrettoPoint, Scalar,
+};
+use rand_core::CryptoRngCore;
+use sha2::{Digest, Sha256, Sha512};
+use vote_types::{VoteError, LSAG_CHALLENGE_DOMAIN, LSAG_HASH_TO_POINT_DOMAIN};
+
+use crate::{ring_signature::RingSignatureScheme, vote_config::SignatureScheme};
+
+/// Compressed ristretto255 point
+pub type LsagPublicKey = [u8; 32];
+
+#[derive(Clone)]
+pub struct LsagPrivateKey {
+    secret: Scalar,
+    public: RistrettoPoint,
+}
+
+impl std::fmt::Debug for LsagPrivateKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LsagPrivateKey")
+            .field("public", &self.public_key())
+            .finish_non_exhaustive()
+    }
+}
+
+impl LsagPrivateKey {
+    pub fn random(rng: &mut impl CryptoRngCore) -> Self {
+        Self::from_scalar(Scalar::random(rng))
+    }
+
+    fn from_scalar(secret: Scalar) -> Self {
+        Self {
+            secret,
+            public: RistrettoPoint::mul_base(&secret),
+        }
+    }
+
+    /// Canonical little endian encoding of a non-zero scalar
+    pub fn from_bytes(bytes: [u8; 32]) -> anyhow::Result<Self> {
+        let secret = Option::<Scalar>::from(Scalar::from_canonical_bytes(bytes))
+            .ok_or_else(|| anyhow!("Private key is not a canonical scalar"))?;
+        if secret == Scalar::ZERO {
+            bail!("Private key is zero");
+        }
+        Ok(Self::from_scalar(secret))
+    }
+
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.secret.to_bytes()
+    }
+
+    pub fn public_key(&self) -> LsagPublicKey {
+        self.public.compress().to_bytes()
+    }
+
+    /// Key image of all signatures made by this key in the election `election_id`
+    pub fn key_image(&self, election_id: &[u8; 32]) -> [u8; 32] {
+        (self.secret * hash_to_point(election_id, &self.public_key()))
+            .compress()
+            .to_bytes()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LsagSignature {
+    pub c: [u8; 32],
+    /// Key image
+    pub image: [u8; 32],
+    pub r: Vec<[u8; 32]>,
+    pub public_keys: Vec<LsagPublicKey>,
+}
+
+fn hash_to_point(election_id: &[u8; 32], public_key: &LsagPublicKey) -> RistrettoPoint {
+    RistrettoPoint::from_hash(
+        Sha512::new()
+            .chain_update(LSAG_HASH_TO_POINT_DOMAIN)
+            .chain_update(election_id)
+            .chain_update(public_key),
+    )
+}
+
+fn decompress(point: &[u8; 32], name: &str) -> anyhow::Result<RistrettoPoint> {
+    CompressedRistretto(*point)
+        .decompress()
+        .ok_or_else(|| anyhow!("{} is not a valid point", name))
+}
+
+fn scalar(bytes: &[u8; 32], name: &str) -> anyhow::Result<Scalar> {
+    Option::<Scalar>::from(Scalar::from_canonical_bytes(*bytes))
+        .ok_or_else(|| anyhow!("{} is not a canonical scalar", name))
+}
+
+/// Hasher of challenges, committed to the ring and the message
+fn challenge_hasher(ring: &[LsagPublicKey], message: &[u8]) -> Sha512 {
+    let mut hasher = Sha512::new()
+        .chain_update(LSAG_CHALLENGE_DOMAIN)
+        .chain_update((ring.len() as u32).to_le_bytes());
+    for public_key in ring {
+        hasher.update(public_key);
+    }
+    hasher.chain_update(message)
+}
+
+fn challenge(hasher: &Sha512, l: &RistrettoPoint, r: &RistrettoPoint) -> Scalar {
+    Scalar::from_hash(
+        hasher
+            .clone()
+            .chain_update(l.compress().as_bytes())
+            .chain_update(r.compress().as_bytes()),
+    )
+}
+
+/// `r * G + c * P` and `r * Hp(P) + c * I` of a ring member
+fn ring_step(
+    election_id: &[u8; 32],
+    public_key: &LsagPublicKey,
+    image: &RistrettoPoint,
+    r: &Scalar,
+    c: &Scalar,
+) -> anyhow::Result<(RistrettoPoint, RistrettoPoint)> {
+    let point = decompress(public_key, "Public key")?;
+    Ok((
+        RistrettoPoint::vartime_double_scalar_mul_basepoint(c, &point, r),
+        RistrettoPoint::vartime_multiscalar_mul(
+            [r, c],
+            [hash_to_point(election_id, public_key), *image],
+        ),
+    ))
+}
+
+/// Sign `message` in the election `election_id`, which is the type hash of its config cell
+pub fn create_signature(
+    ring: &[LsagPublicKey],
+    signer_private_key: &LsagPrivateKey,
+    signer: usize,
+    election_id: &[u8; 32],
+    message: &[u8],
+) -> anyhow::Result<LsagSignature> {
+    create_signature_with_rng(
+        ring,
+        signer_private_key,
+        signer,
+        election_id,
+        message,
+        &mut rand::thread_rng(),
+    )
+}
+
+/// Same as [`create_signature`], but random scalars of the signature are drawn from `rng`, so a
+/// seeded rng gives the same signature every time
+pub fn create_signature_with_rng(
+    ring: &[LsagPublicKey],
+    signer_private_key: &LsagPrivateKey,
+    signer: usize,
+    election_id: &[u8; 32],
+    message: &[u8],
+    rng: &mut impl CryptoRngCore,
+) -> anyhow::Result<LsagSignature> {
+    let n = ring.len();
+    if ring.get(signer) != Some(&signer_private_key.public_key()) {
+        bail!("Signer is not in the ring at index {}", signer);
+    }
+    let hasher = challenge_hasher(ring, message);
+    let image = signer_private_key.secret * hash_to_point(election_id, &ring[signer]);
+    let mut r_arr = vec![Scalar::ZERO; n];
+    let mut c_arr = vec![Scalar::ZERO; n];
+
+    let a = Scalar::random(rng);
+    c_arr[(signer + 1) % n] = challenge(
+        &hasher,
+        &RistrettoPoint::mul_base(&a),
+        &(a * hash_to_point(election_id, &ring[signer])),
+    );
+    let mut i = (signer + 1) % n;
+    while i != signer {
+        r_arr[i] = Scalar::random(rng);
+        let (l, r) = ring_step(election_id, &ring[i], &image, &r_arr[i], &c_arr[i])?;
+        c_arr[(i + 1) % n] = challenge(&hasher, &l, &r);
+        i = (i + 1) % n;
+    }
+    r_arr[signer] = a - c_arr[signer] * signer_private_key.secret;
+    Ok(LsagSignature {
+        c: c_arr[0].to_bytes(),
+        image: image.compress().to_bytes(),
+        r: r_arr.iter().map(Scalar::to_bytes).collect(),
+        public_keys: ring.to_vec(),
+    })
+}
+
+/// Verify a signature made by `create_signature` over the ring `keys` in the election
+/// `election_id`, in the same way as the contract does
+pub fn verify_signature(
+    keys: &[LsagPublicKey],
+    election_id: &[u8; 32],
+    message: &[u8],
+    signature: &LsagSignature,
+) -> anyhow::Result<bool> {
+    if signature.public_keys.len() != keys.len() || signature.r.len() != keys.len() {
+        bail!(
+            "Signature is made over {} keys, but {} given",
+            signature.public_keys.len(),
+            keys.len()
+        );
+    }
+    if signature.public_keys != keys {
+        return Ok(false);
+    }
+    let hasher = challenge_hasher(keys, message);
+    let image = decompress(&signature.image, "Key image")?;
+    let c0 = scalar(&signature.c, "c")?;
+    let mut last_c = c0;
+    for (public_key, r) in keys.iter().zip(&signature.r) {
+        let (l, r) = ring_step(election_id, public_key, &image, &scalar(r, "r")?, &last_c)?;
+        last_c = challenge(&hasher, &l, &r);
+    }
+    Ok(last_c == c0)
+}
+
+/// LSAG over ristretto255
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LsagScheme;
+
+impl RingSignatureScheme for LsagScheme {
+    type PrivateKey = LsagPrivateKey;
+    type PublicKey = LsagPublicKey;
+    type Signature = LsagSignature;
+    /// Compressed point
+    type KeyImage = [u8; 32];
+
+    fn signature_scheme(&self) -> SignatureScheme {
+        SignatureScheme::Lsag
+    }
+
+    fn generate_key(&self, rng: &mut impl CryptoRngCore) -> anyhow::Result<LsagPrivateKey> {
+        Ok(LsagPrivateKey::random(rng))
+    }
+
+    fn public_key(&self, key: &LsagPrivateKey) -> LsagPublicKey {
+        key.public_key()
+    }
+
+    fn encode_public_key(&self, key: &LsagPublicKey) -> anyhow::Result<Vec<u8>> {
+        Ok(key.to_vec())
+    }
+
+    /// Points are not decompressed, same as for leaf hashes
+    fn decode_public_key(&self, bytes: &[u8]) -> anyhow::Result<LsagPublicKey> {
+        bytes
+            .try_into()
+            .map_err(|_| anyhow!("Public key must be 32 bytes"))
+    }
+
+    fn sign_with_rng(
+        &self,
+        ring: &[LsagPublicKey],
+        key: &LsagPrivateKey,
+        signer: usize,
+        election_id: &[u8; 32],
+        message: &[u8],
+        rng: &mut impl CryptoRngCore,
+    ) -> anyhow::Result<LsagSignature> {
+        create_signature_with_rng(ring, key, signer, election_id, message, rng)
+    }
+
+    fn verify(
+        &self,
+        ring: &[LsagPublicKey],
+        election_id: &[u8; 32],
+        message: &[u8],
+        signature: &LsagSignature,
+    ) -> anyhow::Result<bool> {
+        verify_signature(ring, election_id, message, signature)
+    }
+
+    /// Points must decompress and scalars must be canonical, checked in the order the contract
+    /// reads them
+    fn check_encoding(&self, signature: &LsagSignature) -> Result<(), VoteError> {
+        decompress(&signature.image, "Key image").map_err(|_| VoteError::BadPoint)?;
+        scalar(&signature.c, "c").map_err(|_| VoteError::NonCanonicalInteger)?;
+        for (public_key, r) in signature.public_keys.iter().zip(&signature.r) {
+            decompress(public_key, "Public key").map_err(|_| VoteError::BadPoint)?;
+            scalar(r, "r").map_err(|_| VoteError::NonCanonicalInteger)?;
+        }
+        Ok(())
+    }
+
+    fn ring(&self, signature: &LsagSignature) -> Vec<LsagPublicKey> {
+        signature.public_keys.clone()
+    }
+
+    fn link_tag(&self, signature: &LsagSignature) -> anyhow::Result<[u8; 32]> {
+        Ok(signature.image)
+    }
+
+    /// sha256 of the concatenated public keys
+    fn leaf_hash(&self, ring: &[LsagPublicKey]) -> anyhow::Result<[u8; 32]> {
+        if ring.is_empty() {
+            bail!("Empty ring");
+        }
+        let mut hasher = Sha256::new();
+        for key in ring {
+            hasher.update(key);
+        }
+        Ok(hasher.finalize().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{thread_rng, SeedableRng};
+    use rand_chacha::ChaCha20Rng;
+
+    use super::{create_signature, create_signature_with_rng, verify_signature, LsagPrivateKey};
+
+    const ELECTION_ID: [u8; 32] = [1; 32];
+
+    #[test]
+    fn test_lsag_signature() {
+        let keys = (0..5)
+            .map(|_| LsagPrivateKey::random(&mut thread_rng()))
+            .collect::<Vec<_>>();
+        let ring = keys.iter().map(|x| x.public_key()).collect::<Vec<_>>();
+        let message = b"candidate";
+        let signature = create_signature(&ring, &keys[3], 3, &ELECTION_ID, message).unwrap();
+        assert!(verify_signature(&ring, &ELECTION_ID, message, &signature).unwrap());
+        assert!(!verify_signature(&ring, &ELECTION_ID, b"another", &signature).unwrap());
+        assert_eq!(signature.image, keys[3].key_image(&ELECTION_ID));
+        // Signatures of the same key are linked by the image
+        let another = create_signature(&ring[1..], &keys[3], 2, &ELECTION_ID, b"another").unwrap();
+        assert_eq!(another.image, signature.image);
+        assert!(create_signature(&ring, &keys[3], 2, &ELECTION_ID, message).is_err());
+        // Key images are of the election, so the same key isn't linked across elections
+        assert!(!verify_signature(&ring, &[2; 32], message, &signature).unwrap());
+        let other_election = create_signature(&ring, &keys[3], 3, &[2; 32], message).unwrap();
+        assert!(verify_signature(&ring, &[2; 32], message, &other_election).unwrap());
+        assert_ne!(other_election.image, signature.image);
+        assert_eq!(other_election.image, keys[3].key_image(&[2; 32]));
+        // Signatures made with the same seed are the same
+        let seeded = |seed| {
+            let mut rng = ChaCha20Rng::seed_from_u64(seed);
+            create_signature_with_rng(&ring, &keys[3], 3, &ELECTION_ID, message, &mut rng).unwrap()
+        };
+        assert_eq!(seeded(1), seeded(1));
+        assert_ne!(seeded(1), seeded(2));
+        assert!(verify_signature(&ring, &ELECTION_ID, message, &seeded(1)).unwrap());
+
+        let mut tampered = signature.clone();
+        tampered.r[0][0] ^= 1;
+        assert!(!verify_signature(&ring, &ELECTION_ID, message, &tampered).unwrap());
+        let mut other_image = signature.clone();
+        other_image.image = keys[0].key_image(&ELECTION_ID);
+        assert!(!verify_signature(&ring, &ELECTION_ID, message, &other_image).unwrap());
+        let mut non_canonical = signature.clone();
+        non_canonical.r[0] = [0xff; 32];
+        assert!(verify_signature(&ring, &ELECTION_ID, message, &non_canonical).is_err());
+
+        let single = create_signature(&ring[..1], &keys[0], 0, &ELECTION_ID, message).unwrap();
+        assert!(verify_signature(&ring[..1], &ELECTION_ID, message, &single).unwrap());
+        assert_eq!(
+            LsagPrivateKey::from_bytes(keys[0].to_bytes())
+                .unwrap()
+                .public_key(),
+            ring[0]
+        );
+        assert!(LsagPrivateKey::from_bytes([0; 32]).is_err());
+    }
+}
@@ -0,0 +1,175 @@
+use anyhow::{anyhow, bail};
+use sha2::{Digest, Sha256};
+use sparse_merkle_tree::{
+    default_store::DefaultStore, traits::Hasher, CompiledMerkleProof, SparseMerkleTree, H256,
+};
+
+/// Leaf value stored for every used image, must be the same as the one in contract
+pub const KEY_IMAGE_USED: [u8; 32] = [1; 32];
+
+#[derive(Default)]
+pub struct Sha256Hasher(Sha256);
+
+impl Hasher for Sha256Hasher {
+    fn write_h256(&mut self, h: &H256) {
+        self.0.update(h.as_slice());
+    }
+    fn write_byte(&mut self, b: u8) {
+        self.0.update([b]);
+    }
+    fn finish(self) -> H256 {
+        let hash: [u8; 32] = self.0.finalize().into();
+        hash.into()
+    }
+}
+
+/// Key of an image in the registry, image is in the same encoding as in vote cell
+pub fn key_image_registry_key(image: &[u8]) -> H256 {
+    let hash: [u8; 32] = Sha256::digest(image).into();
+    hash.into()
+}
+
+#[derive(Clone, Debug)]
+pub struct KeyImageRegistryUpdate {
+    /// Goes into `input_type` of the witness of the registry input
+    pub proof: Vec<u8>,
+    pub old_root: [u8; 32],
+    pub new_root: [u8; 32],
+}
+
+/// Off-chain mirror of the key image registry cell, which only stores the root on chain
+#[derive(Default)]
+pub struct KeyImageRegistry {
+    tree: SparseMerkleTree<Sha256Hasher, H256, DefaultStore<H256>>,
+}
+
+impl KeyImageRegistry {
+    /// Rebuild the registry from images of all votes that were accepted
+    pub fn from_images<'a>(images: impl IntoIterator<Item = &'a [u8]>) -> anyhow::Result<Self> {
+        let mut tree = SparseMerkleTree::default();
+        tree.update_all(
+            images
+                .into_iter()
+                .map(|image| (key_image_registry_key(image), KEY_IMAGE_USED.into()))
+                .collect(),
+        )
+        .map_err(|e| anyhow!("Failed to insert images: {}", e))?;
+        Ok(Self { tree })
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        (*self.tree.root()).into()
+    }
+
+    pub fn contains(&self, image: &[u8]) -> anyhow::Result<bool> {
+        Ok(!self
+            .tree
+            .get(&key_image_registry_key(image))
+            .map_err(|e| anyhow!("Failed to query image: {}", e))?
+            .is_zero())
+    }
+
+    /// Insert images of votes in one transaction, returning the proof for the registry update
+    pub fn insert_images(&mut self, images: &[&[u8]]) -> anyhow::Result<KeyImageRegistryUpdate> {
+        let mut keys = images
+            .iter()
+            .map(|image| key_image_registry_key(image))
+            .collect::<Vec<_>>();
+        keys.sort_unstable();
+        keys.dedup();
+        if keys.len() != images.len() {
+            bail!("Duplicated images in one update");
+        }
+        for key in keys.iter() {
+            if !self
+                .tree
+                .get(key)
+                .map_err(|e| anyhow!("Failed to query image: {}", e))?
+                .is_zero()
+            {
+                bail!("Image already used: {:?}", key.as_slice());
+            }
+        }
+        let old_root = self.root();
+        let proof = self
+            .tree
+            .merkle_proof(keys.clone())
+            .and_then(|proof| proof.compile(keys.clone()))
+            .map_err(|e| anyhow!("Failed to create proof: {}", e))?;
+        self.tree
+            .update_all(
+                keys.into_iter()
+                    .map(|key| (key, KEY_IMAGE_USED.into()))
+                    .collect(),
+            )
+            .map_err(|e| anyhow!("Failed to insert images: {}", e))?;
+        Ok(KeyImageRegistryUpdate {
+            proof: proof.into(),
+            old_root,
+            new_root: self.root(),
+        })
+    }
+}
+
+/// Check a registry update in the same way as the contract does
+pub fn verify_key_image_registry_update(
+    images: &[&[u8]],
+    update: &KeyImageRegistryUpdate,
+) -> Result<bool, String> {
+    let proof = CompiledMerkleProof(update.proof.clone());
+    let keys = images
+        .iter()
+        .map(|image| key_image_registry_key(image))
+        .collect::<Vec<_>>();
+    let unused = proof
+        .verify::<Sha256Hasher>(
+            &update.old_root.into(),
+            keys.iter().map(|key| (*key, H256::zero())).collect(),
+        )
+        .map_err(|e| format!("Failed to verify old root: {}", e))?;
+    let used = proof
+        .verify::<Sha256Hasher>(
+            &update.new_root.into(),
+            keys.iter()
+                .map(|key| (*key, KEY_IMAGE_USED.into()))
+                .collect(),
+        )
+        .map_err(|e| format!("Failed to verify new root: {}", e))?;
+    Ok(unused && used)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{thread_rng, Rng};
+
+    use super::{verify_key_image_registry_update, KeyImageRegistry};
+
+    #[test]
+    fn test_key_image_registry() {
+        let mut rng = thread_rng();
+        let images = (0..20)
+            .map(|_| (0..256).map(|_| rng.gen()).collect::<Vec<u8>>())
+            .collect::<Vec<_>>();
+        let mut registry = KeyImageRegistry::default();
+        assert_eq!(registry.root(), [0u8; 32]);
+        for chunk in images[..15].chunks(4) {
+            let chunk = chunk.iter().map(|x| &x[..]).collect::<Vec<_>>();
+            let update = registry.insert_images(&chunk).unwrap();
+            assert!(verify_key_image_registry_update(&chunk, &update).unwrap());
+        }
+        let rebuilt =
+            KeyImageRegistry::from_images(images[..15].iter().map(|x| &x[..])).unwrap();
+        assert_eq!(rebuilt.root(), registry.root());
+        assert!(registry.contains(&images[3]).unwrap());
+        assert!(!registry.contains(&images[17]).unwrap());
+
+        // An image can't be inserted twice, and a proof doesn't work for other images
+        assert!(registry.insert_images(&[&images[3]]).is_err());
+        let update = registry.insert_images(&[&images[17]]).unwrap();
+        assert_eq!(update.old_root, rebuilt.root());
+        assert!(!matches!(
+            verify_key_image_registry_update(&[&images[3]], &update),
+            Ok(true)
+        ));
+    }
+}
@@ -2,9 +2,18 @@ use std::io::Write;
 
 use sha2::Digest;
 use sha2::Sha256;
+use vote_types::RSA_IMAGE_BASE_DOMAIN;
 
 pub mod candidate;
+pub mod ec_tools;
+pub mod key_image_registry;
+pub mod merkle_tree;
+pub mod public_key_registry;
+pub mod ring_assignment;
+pub mod ring_signature;
 pub mod rsa_tools;
+pub mod vote;
+pub mod vote_config;
 pub use rsa::BigUint;
 pub fn check_size_and_write(
     out_buf: &mut impl Write,
@@ -17,8 +26,12 @@ pub fn check_size_and_write(
     Ok(())
 }
 
-fn sha256_for_integer(num: &BigUint) -> BigUint {
+/// Base of RSA key images of a modulus in the election `election_id`, which is the type hash of
+/// its config cell
+fn sha256_for_integer(election_id: &[u8; 32], num: &BigUint) -> BigUint {
     let mut hasher = Sha256::new();
+    hasher.update(RSA_IMAGE_BASE_DOMAIN);
+    hasher.update(election_id);
     hasher.update(num.to_bytes_le());
     BigUint::from_bytes_le(&hasher.finalize())
 }
@@ -0,0 +1,194 @@
+//! Merkle tree of all public keys of an election
+//!
+//! Keys are split into rings of `group_size` keys in tree order, the last ring has the rest, and
+//! every ring is a leaf hashed by [`RingSignatureScheme::leaf_hash`]. Keys of an election are in
+//! the order of [`crate::ring_assignment::assign_rings`], so nobody picks who shares a ring.
+use std::ops::Range;
+
+use anyhow::{anyhow, bail, Context};
+use rs_merkle::{proof_serializers::DirectHashesOrder, MerkleProof, MerkleTree};
+use vote_types::{leaf_count, ring_size};
+
+use crate::ring_signature::RingSignatureScheme;
+
+/// Keys of the ring at `leaf_index`, by the same rule as the contract, see
+/// [`vote_types::ring_size`]
+pub fn ring_range(user_count: usize, group_size: usize, leaf_index: usize) -> Range<usize> {
+    let start = leaf_index.saturating_mul(group_size);
+    start..start + ring_size(user_count, group_size, leaf_index)
+}
+
+/// Create a merkle tree, grouping pubkeys with group_size
+pub fn create_merkle_tree<S: RingSignatureScheme, P: FnMut(usize, &[u8])>(
+    scheme: &S,
+    pub_keys: &[S::PublicKey],
+    group_size: usize,
+    mut leaf_hash_visitor: Option<P>,
+) -> anyhow::Result<MerkleTree<rs_merkle::algorithms::Sha256>> {
+    if group_size == 0 {
+        bail!("Group size must not be 0");
+    }
+    let mut hashes = vec![];
+    for index in 0..leaf_count(pub_keys.len(), group_size) {
+        let ring = &pub_keys[ring_range(pub_keys.len(), group_size, index)];
+        let hash = scheme.leaf_hash(ring)?;
+        if let Some(f) = leaf_hash_visitor.as_mut() {
+            f(index, &hash);
+        }
+        hashes.push(hash);
+    }
+    let merkle_tree = MerkleTree::<rs_merkle::algorithms::Sha256>::from_leaves(&hashes);
+    Ok(merkle_tree)
+}
+
+pub fn create_merkle_tree_with_root_hash<S: RingSignatureScheme>(
+    scheme: &S,
+    pub_keys: &[S::PublicKey],
+    group_size: usize,
+) -> anyhow::Result<Vec<u8>> {
+    let tree = create_merkle_tree(
+        scheme,
+        pub_keys,
+        group_size,
+        Option::<Box<dyn Fn(usize, &[u8])>>::None,
+    )
+    .with_context(|| anyhow!("Failed to create merkle tree"))?;
+
+    Ok(tree
+        .root()
+        .ok_or_else(|| anyhow!("Unable to get merkle tree root"))?
+        .to_vec())
+}
+
+#[derive(Clone, Debug)]
+pub struct MerkleProofResult {
+    pub proof: Vec<u8>,
+    pub leaf_hash: Vec<u8>,
+}
+
+pub fn create_merkle_tree_with_proof<S: RingSignatureScheme>(
+    scheme: &S,
+    pub_keys: &[S::PublicKey],
+    group_size: usize,
+    proof_index: usize,
+) -> anyhow::Result<MerkleProofResult> {
+    let mut leaf_hash = None;
+
+    let tree = create_merkle_tree(
+        scheme,
+        pub_keys,
+        group_size,
+        Some(|idx: usize, val: &[u8]| {
+            if idx == proof_index {
+                leaf_hash = Some(val.to_vec());
+            }
+        }),
+    )
+    .with_context(|| anyhow!("Failed to create merkle tree"))?;
+
+    Ok(MerkleProofResult {
+        proof: tree.proof(&[proof_index]).serialize::<DirectHashesOrder>(),
+        leaf_hash: leaf_hash.ok_or_else(|| anyhow!("Bad proof index"))?,
+    })
+}
+
+pub fn verify_merkle_proof(
+    proof: &[u8],
+    root_hash: &[u8],
+    leaf_index: usize,
+    leaf_hash: &[u8],
+    leaf_count: usize,
+) -> Result<bool, String> {
+    let proof = MerkleProof::<rs_merkle::algorithms::Sha256>::from_bytes(proof)
+        .map_err(|e| format!("Failed to parse merkle proof: {}", e))?;
+    // Bits of the index above the height of the tree are ignored by the proof
+    if leaf_index >= leaf_count {
+        return Ok(false);
+    }
+    Ok(proof.verify(
+        root_hash
+            .try_into()
+            .map_err(|e| format!("Invalid length of root hash: {}", e))?,
+        &[leaf_index],
+        &[leaf_hash
+            .try_into()
+            .map_err(|e| format!("Invalid length of leaf hash: {}", e))?],
+        leaf_count,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{thread_rng, Rng};
+    use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+    use rsa::RsaPrivateKey;
+
+    use super::{
+        create_merkle_tree_with_proof, create_merkle_tree_with_root_hash, verify_merkle_proof,
+        MerkleProofResult,
+    };
+    use crate::{
+        ec_tools::{LsagPrivateKey, LsagScheme},
+        rsa_tools::{RsaKeySize, RsaScheme},
+    };
+
+    const N: usize = 40;
+    const GROUP_SIZE: usize = 15;
+
+    #[test]
+    fn test_merkle_tree() {
+        let keys = (0..N)
+            .into_par_iter()
+            .enumerate()
+            .map(|(idx, _)| {
+                let mut rng = thread_rng();
+                println!("{} generation done", idx);
+                RsaPrivateKey::new(&mut rng, 2048).unwrap().to_public_key()
+            })
+            .collect::<Vec<_>>();
+        let scheme = RsaScheme(RsaKeySize::Rsa2048);
+        let mut rng = thread_rng();
+        let tree_root = create_merkle_tree_with_root_hash(&scheme, &keys, GROUP_SIZE).unwrap();
+        let group_count = N.div_ceil(GROUP_SIZE);
+        let group_index = rng.gen_range(0..group_count);
+        let MerkleProofResult {
+            leaf_hash,
+            proof: proof_bytes,
+        } = create_merkle_tree_with_proof(&scheme, &keys, GROUP_SIZE, group_index).unwrap();
+
+        assert!(verify_merkle_proof(
+            &proof_bytes,
+            &tree_root,
+            group_index,
+            &leaf_hash,
+            group_count
+        )
+        .unwrap());
+        // Keys must be of the size of the scheme
+        assert!(
+            create_merkle_tree_with_root_hash(&RsaScheme(RsaKeySize::Rsa1024), &keys, GROUP_SIZE)
+                .is_err()
+        );
+
+        let keys = (0..N)
+            .map(|_| LsagPrivateKey::random(&mut rng).public_key())
+            .collect::<Vec<_>>();
+        let tree_root = create_merkle_tree_with_root_hash(&LsagScheme, &keys, GROUP_SIZE).unwrap();
+        let MerkleProofResult { leaf_hash, proof } =
+            create_merkle_tree_with_proof(&LsagScheme, &keys, GROUP_SIZE, group_count - 1)
+                .unwrap();
+        assert!(
+            verify_merkle_proof(&proof, &tree_root, group_count - 1, &leaf_hash, group_count)
+                .unwrap()
+        );
+        // Nor with bits above the height of the tree
+        assert!(!verify_merkle_proof(
+            &proof,
+            &tree_root,
+            group_count - 1 + 256,
+            &leaf_hash,
+            group_count
+        )
+        .unwrap());
+    }
+}
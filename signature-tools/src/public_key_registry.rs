@@ -0,0 +1,313 @@
+//! Public key registry cells
+//!
+//! The config only stores the merkle root of all public keys, while a voter needs the keys of a
+//! whole ring and its merkle proof to vote. The administrator publishes the keys in registry
+//! cells, each a chunk of whole rings in the order of the merkle tree, typed by the contract with
+//! [`public_key_registry_script_args`](crate::vote_config::public_key_registry_script_args) of
+//! the config, so only the administrator can create them. [`rebuild_rings`] gets every ring and
+//! its proof back from the cells alone, and checks them against the merkle root of the config.
+//!
+//! Data of a registry cell is a `PublicKeyRegistryCell` of `vote-types`, with keys encoded by
+//! [`RingSignatureScheme::encode_public_key`] and concatenated.
+//!
+//! A vote may reference the registry cell of its ring as a cell dep instead of carrying the keys,
+//! see [`Ring::registry_cell`].
+use anyhow::{anyhow, bail};
+use rs_merkle::proof_serializers::DirectHashesOrder;
+use vote_types::{
+    leaf_count,
+    molecule::prelude::*,
+    schema::{self, PublicKeyRegistryCellReader},
+    VoteError,
+};
+
+use crate::{
+    merkle_tree::{create_merkle_tree, ring_range},
+    ring_signature::RingSignatureScheme,
+    vote_config::VoteConfig,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PublicKeyRegistryCell<K> {
+    /// Index of the first key of the cell among all keys of the election
+    pub start: u32,
+    pub public_keys: Vec<K>,
+}
+
+impl<K> PublicKeyRegistryCell<K> {
+    pub fn encode<S: RingSignatureScheme<PublicKey = K>>(
+        &self,
+        scheme: &S,
+    ) -> anyhow::Result<Vec<u8>> {
+        let mut public_keys = vec![];
+        for key in &self.public_keys {
+            public_keys.extend(scheme.encode_public_key(key)?);
+        }
+        Ok(schema::PublicKeyRegistryCell::new_builder()
+            .start(self.start.into())
+            .public_keys(public_keys.as_slice().into())
+            .build()
+            .as_slice()
+            .to_vec())
+    }
+
+    pub fn decode<S: RingSignatureScheme<PublicKey = K>>(
+        buf: &[u8],
+        scheme: &S,
+    ) -> anyhow::Result<Self> {
+        let reader = PublicKeyRegistryCellReader::from_slice(buf)
+            .map_err(|e| anyhow!("Bad public key registry cell: {}", e))?;
+        let public_keys = reader.public_keys().raw_data();
+        let key_size = scheme.signature_scheme().public_key_size();
+        if !public_keys.len().is_multiple_of(key_size) {
+            bail!("Public keys must be in {} bytes each", key_size);
+        }
+        Ok(Self {
+            start: reader.start().into(),
+            public_keys: public_keys
+                .chunks(key_size)
+                .map(|key| scheme.decode_public_key(key))
+                .collect::<anyhow::Result<_>>()?,
+        })
+    }
+}
+
+/// Split all keys of an election into registry cells of `rings_per_cell` rings, the size of a
+/// cell is bounded by the size of a transaction
+pub fn public_key_registry_cells<K: Clone>(
+    public_keys: &[K],
+    group_size: usize,
+    rings_per_cell: usize,
+) -> anyhow::Result<Vec<PublicKeyRegistryCell<K>>> {
+    if group_size == 0 || rings_per_cell == 0 {
+        bail!("Group size and rings per cell must not be 0");
+    }
+    let chunk_size = group_size * rings_per_cell;
+    public_keys
+        .chunks(chunk_size)
+        .enumerate()
+        .map(|(index, keys)| {
+            Ok(PublicKeyRegistryCell {
+                start: u32::try_from(index * chunk_size)?,
+                public_keys: keys.to_vec(),
+            })
+        })
+        .collect()
+}
+
+/// Keys of the ring at `leaf_index` in data of a registry cell, concatenated as in the cell. The
+/// ring is found in the same way as the contract does for votes referencing the cell
+pub fn ring_public_keys<'a>(
+    data: &'a [u8],
+    config: &VoteConfig,
+    leaf_index: u32,
+    ring_size: usize,
+) -> Result<&'a [u8], VoteError> {
+    let cell = PublicKeyRegistryCellReader::from_slice(data)
+        .map_err(|_| VoteError::BadPublicKeyRegistry)?;
+    let key_size = config.signature_scheme.public_key_size();
+    let offset = ring_range(
+        config.user_count as usize,
+        config.group_size as usize,
+        leaf_index as usize,
+    )
+    .start
+    .checked_sub(u32::from(cell.start()) as usize)
+    .ok_or(VoteError::RingNotInRegistry)?;
+    cell.public_keys()
+        .raw_data()
+        .get(offset * key_size..(offset + ring_size) * key_size)
+        .ok_or(VoteError::RingNotInRegistry)
+}
+
+/// A leaf of the merkle tree, with everything a voter of the ring needs to vote
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ring<K> {
+    pub leaf_index: u32,
+    pub public_keys: Vec<K>,
+    pub merkle_proof: Vec<u8>,
+    /// Index among the cells given to [`rebuild_rings`] of the cell with all keys of the ring,
+    /// which a vote can reference as a cell dep instead of carrying the keys, or none if the
+    /// ring spans cells
+    pub registry_cell: Option<usize>,
+}
+
+/// Rebuild every ring of the election with `config` from data of its registry cells, in any
+/// order. Cells must cover all keys exactly once, and the keys must add up to the merkle root
+pub fn rebuild_rings<'a, S: RingSignatureScheme>(
+    scheme: &S,
+    config: &VoteConfig,
+    cells: impl IntoIterator<Item = &'a [u8]>,
+) -> anyhow::Result<Vec<Ring<S::PublicKey>>> {
+    if scheme.signature_scheme() != config.signature_scheme {
+        bail!("Signature scheme doesn't match the config");
+    }
+    let mut cells = cells
+        .into_iter()
+        .map(|data| PublicKeyRegistryCell::decode(data, scheme))
+        .enumerate()
+        .map(|(index, cell)| Ok((index, cell?)))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    cells.sort_by_key(|(_, cell)| cell.start);
+    let mut public_keys = Vec::with_capacity(config.user_count as usize);
+    // Index of the cell of each key
+    let mut key_cells = Vec::with_capacity(config.user_count as usize);
+    for (index, cell) in cells {
+        if cell.start as usize != public_keys.len() {
+            bail!(
+                "Registry cells must cover keys in order, expected a cell at {}, got {}",
+                public_keys.len(),
+                cell.start
+            );
+        }
+        key_cells.extend(std::iter::repeat_n(index, cell.public_keys.len()));
+        public_keys.extend(cell.public_keys);
+    }
+    if public_keys.len() != config.user_count as usize {
+        bail!(
+            "Registry cells have {} keys, expected {}",
+            public_keys.len(),
+            config.user_count
+        );
+    }
+    let group_size = config.group_size as usize;
+    let tree = create_merkle_tree(
+        scheme,
+        &public_keys,
+        group_size,
+        Option::<Box<dyn Fn(usize, &[u8])>>::None,
+    )?;
+    if tree.root() != Some(config.merkle_root) {
+        bail!("Keys of registry cells don't match the merkle root of the config");
+    }
+    (0..leaf_count(public_keys.len(), group_size))
+        .map(|index| {
+            let range = ring_range(public_keys.len(), group_size, index);
+            let cell = key_cells[range.start];
+            Ok(Ring {
+                leaf_index: u32::try_from(index)?,
+                public_keys: public_keys[range.clone()].to_vec(),
+                merkle_proof: tree.proof(&[index]).serialize::<DirectHashesOrder>(),
+                registry_cell: key_cells[range]
+                    .iter()
+                    .all(|&other| other == cell)
+                    .then_some(cell),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{thread_rng, Rng};
+
+    use super::{public_key_registry_cells, rebuild_rings, PublicKeyRegistryCell};
+    use crate::{
+        ec_tools::LsagScheme,
+        merkle_tree::{create_merkle_tree_with_root_hash, verify_merkle_proof},
+        ring_signature::RingSignatureScheme,
+        rsa_tools::{RsaKeySize, RsaScheme},
+        vote_config::{SignatureScheme, VoteConfig},
+    };
+
+    #[test]
+    fn test_rebuild_rings() {
+        let scheme = LsagScheme;
+        let public_keys = (0..23)
+            .map(|_| scheme.public_key(&scheme.generate_key(&mut thread_rng()).unwrap()))
+            .collect::<Vec<_>>();
+        let config = VoteConfig {
+            network: [0; 32],
+            merkle_root: create_merkle_tree_with_root_hash(&scheme, &public_keys, 5)
+                .unwrap()
+                .try_into()
+                .unwrap(),
+            user_count: 23,
+            leaf_count: 5,
+            group_size: 5,
+            signature_scheme: SignatureScheme::Lsag,
+            voting_start: 0,
+            voting_end: 1000,
+            admin_lock_hash: [0; 32],
+            key_image_registry_type_hash: [0; 32],
+            candidates: vec![],
+        };
+        let mut cells = public_key_registry_cells(&public_keys, 5, 2)
+            .unwrap()
+            .iter()
+            .map(|cell| cell.encode(&scheme).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(cells.len(), 3);
+        // Cells are found on chain in any order
+        cells.reverse();
+        let rings = rebuild_rings(&scheme, &config, cells.iter().map(Vec::as_slice)).unwrap();
+        assert_eq!(rings.len(), 5);
+        assert_eq!(rings[4].public_keys, public_keys[20..]);
+        assert_eq!(
+            rings
+                .iter()
+                .map(|ring| ring.registry_cell)
+                .collect::<Vec<_>>(),
+            [Some(2), Some(2), Some(1), Some(1), Some(0)]
+        );
+        for ring in &rings {
+            assert!(verify_merkle_proof(
+                &ring.merkle_proof,
+                &config.merkle_root,
+                ring.leaf_index as usize,
+                &scheme.leaf_hash(&ring.public_keys).unwrap(),
+                5,
+            )
+            .unwrap());
+        }
+
+        // Missing, repeated and forged cells
+        assert!(rebuild_rings(&scheme, &config, cells[1..].iter().map(Vec::as_slice)).is_err());
+        let repeated = [&cells[..], &cells[..1]].concat();
+        assert!(rebuild_rings(&scheme, &config, repeated.iter().map(Vec::as_slice)).is_err());
+        let mut forged = PublicKeyRegistryCell::decode(&cells[0], &scheme).unwrap();
+        forged.public_keys[0] = thread_rng().gen();
+        let forged = [
+            forged.encode(&scheme).unwrap(),
+            cells[1].clone(),
+            cells[2].clone(),
+        ];
+        assert!(rebuild_rings(&scheme, &config, forged.iter().map(Vec::as_slice)).is_err());
+        let rsa_scheme = RsaScheme(RsaKeySize::Rsa1024);
+        assert!(rebuild_rings(&rsa_scheme, &config, cells.iter().map(Vec::as_slice)).is_err());
+
+        // Cells of other sizes are fine, but rings spanning cells can't be referenced
+        let cells = public_key_registry_cells(&public_keys, 4, 2)
+            .unwrap()
+            .iter()
+            .map(|cell| cell.encode(&scheme).unwrap())
+            .collect::<Vec<_>>();
+        let rings = rebuild_rings(&scheme, &config, cells.iter().map(Vec::as_slice)).unwrap();
+        assert_eq!(
+            rings
+                .iter()
+                .map(|ring| ring.registry_cell)
+                .collect::<Vec<_>>(),
+            [Some(0), None, Some(1), None, Some(2)]
+        );
+    }
+
+    #[test]
+    fn test_rsa_registry_cell_encoding() {
+        let scheme = RsaScheme(RsaKeySize::Rsa1024);
+        let public_keys = (0..3)
+            .map(|_| scheme.public_key(&scheme.generate_key(&mut thread_rng()).unwrap()))
+            .collect::<Vec<_>>();
+        let cell = PublicKeyRegistryCell {
+            start: 15,
+            public_keys,
+        };
+        let encoded = cell.encode(&scheme).unwrap();
+        assert_eq!(
+            PublicKeyRegistryCell::decode(&encoded, &scheme).unwrap(),
+            cell
+        );
+        // Keys of another size
+        assert!(PublicKeyRegistryCell::decode(&encoded, &RsaScheme(RsaKeySize::Rsa2048)).is_err());
+    }
+}
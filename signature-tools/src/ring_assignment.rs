@@ -0,0 +1,271 @@
+//! Public, verifiable assignment of keys to rings
+//!
+//! Rings are consecutive keys in the order of the merkle tree, so whoever orders the keys picks
+//! who shares an anonymity set, and could put a voter in a ring of colluders. Keys are instead put
+//! in the order of [`assign_rings`]: sorted by their encoding, which drops the order they were
+//! uploaded in, then shuffled by Fisher-Yates with randomness derived from a seed that nobody
+//! picks and that is only known once the keys are fixed:
+//!
+//! 1. When registration closes, the administrator creates the config cell with the merkle root of
+//!    the keys in sorted order, see [`key_set_root`], which commits to the keys.
+//! 2. The seed is the hash of the block [`ring_seed_block`], a few blocks after the one of that
+//!    transaction.
+//! 3. The administrator replaces the config cell, whose type id keeps the election id, with one
+//!    of the merkle root of the shuffled keys.
+//!
+//! An administrator who dislikes the shuffle can only abandon the election and start another one,
+//! which leaves the first config on chain for everyone to see. The contract only sees the merkle
+//! root, so the order is checked off chain: anyone can redo the shuffle from the keys of the
+//! registry cells, the first config and the seed, see [`check_ring_assignment`].
+use anyhow::{anyhow, bail};
+use sha2::{Digest, Sha256};
+use vote_types::{RING_ASSIGNMENT_DOMAIN, RING_SEED_DELAY};
+
+use crate::{
+    merkle_tree::create_merkle_tree_with_root_hash, ring_signature::RingSignatureScheme,
+    vote_config::VoteConfig,
+};
+
+/// Randomness of the shuffle, sha256 of the domain, the seed and a counter in 8 bytes, read as
+/// little endian u64s
+struct SeedStream {
+    seed: [u8; 32],
+    counter: u64,
+    block: [u8; 32],
+    offset: usize,
+}
+
+impl SeedStream {
+    fn new(seed: &[u8; 32]) -> Self {
+        Self {
+            seed: *seed,
+            counter: 0,
+            block: [0; 32],
+            offset: 32,
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        if self.offset == self.block.len() {
+            let mut hasher = Sha256::new();
+            hasher.update(RING_ASSIGNMENT_DOMAIN);
+            hasher.update(self.seed);
+            hasher.update(self.counter.to_le_bytes());
+            self.block = hasher.finalize().into();
+            self.counter += 1;
+            self.offset = 0;
+        }
+        let value =
+            u64::from_le_bytes(self.block[self.offset..self.offset + 8].try_into().unwrap());
+        self.offset += 8;
+        value
+    }
+
+    /// Uniform in `0..bound`, values of the incomplete last multiple of `bound` are drawn again
+    fn below(&mut self, bound: u64) -> u64 {
+        let zone = u64::MAX - u64::MAX % bound;
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return value % bound;
+            }
+        }
+    }
+}
+
+/// Indexes of `encoded_keys` sorted by the keys
+fn sorted_order(encoded_keys: &[Vec<u8>]) -> Vec<usize> {
+    let mut order = (0..encoded_keys.len()).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| encoded_keys[a].cmp(&encoded_keys[b]));
+    order
+}
+
+fn shuffle(encoded_keys: &[Vec<u8>], seed: &[u8; 32]) -> Vec<usize> {
+    let mut order = sorted_order(encoded_keys);
+    let mut stream = SeedStream::new(seed);
+    for index in (1..order.len()).rev() {
+        order.swap(index, stream.below(index as u64 + 1) as usize);
+    }
+    order
+}
+
+fn encode_public_keys<S: RingSignatureScheme>(
+    scheme: &S,
+    public_keys: &[S::PublicKey],
+) -> anyhow::Result<Vec<Vec<u8>>> {
+    public_keys
+        .iter()
+        .map(|key| scheme.encode_public_key(key))
+        .collect()
+}
+
+/// Block whose hash seeds the shuffle of an election, whose config cell was first created in
+/// `config_block`
+pub fn ring_seed_block(config_block: u64) -> u64 {
+    config_block + RING_SEED_DELAY
+}
+
+/// Merkle root of `public_keys` sorted by their encoding, in rings of `group_size`, which the
+/// first config cell of an election carries to commit to its keys before the seed is known. It
+/// doesn't depend on the order of `public_keys`
+pub fn key_set_root<S: RingSignatureScheme>(
+    scheme: &S,
+    public_keys: &[S::PublicKey],
+    group_size: usize,
+) -> anyhow::Result<[u8; 32]> {
+    let sorted = sorted_order(&encode_public_keys(scheme, public_keys)?)
+        .into_iter()
+        .map(|index| public_keys[index].clone())
+        .collect::<Vec<_>>();
+    create_merkle_tree_with_root_hash(scheme, &sorted, group_size)?
+        .try_into()
+        .map_err(|_| anyhow!("Merkle root isn't a sha256 hash"))
+}
+
+/// Order of keys in the merkle tree of an election with the shuffle seeded by `seed`, the key at
+/// `i` is `public_keys[order[i]]`. It doesn't depend on the order of `public_keys`
+pub fn ring_order<S: RingSignatureScheme>(
+    scheme: &S,
+    public_keys: &[S::PublicKey],
+    seed: &[u8; 32],
+) -> anyhow::Result<Vec<usize>> {
+    Ok(shuffle(&encode_public_keys(scheme, public_keys)?, seed))
+}
+
+/// `public_keys` in the order of the merkle tree of an election with the shuffle seeded by
+/// `seed`, which are split into rings as usual
+pub fn assign_rings<S: RingSignatureScheme>(
+    scheme: &S,
+    public_keys: &[S::PublicKey],
+    seed: &[u8; 32],
+) -> anyhow::Result<Vec<S::PublicKey>> {
+    Ok(ring_order(scheme, public_keys, seed)?
+        .into_iter()
+        .map(|index| public_keys[index].clone())
+        .collect())
+}
+
+/// Check that `public_keys`, all keys of an election in the order of the merkle tree such as
+/// rebuilt from registry cells, are the keys committed to by `first_config`, the config cell
+/// which created the election, and are in the order of [`assign_rings`] with `seed`, the hash of
+/// the block [`ring_seed_block`]
+pub fn check_ring_assignment<S: RingSignatureScheme>(
+    scheme: &S,
+    public_keys: &[S::PublicKey],
+    first_config: &VoteConfig,
+    seed: &[u8; 32],
+) -> anyhow::Result<()> {
+    if key_set_root(scheme, public_keys, first_config.group_size as usize)?
+        != first_config.merkle_root
+    {
+        bail!("Keys aren't the ones committed to by the first config of the election");
+    }
+    let encoded_keys = encode_public_keys(scheme, public_keys)?;
+    let order = shuffle(&encoded_keys, seed);
+    if let Some(position) =
+        (0..order.len()).find(|&position| encoded_keys[order[position]] != encoded_keys[position])
+    {
+        bail!(
+            "Key at {} isn't the one assigned to it by the shuffle of the election",
+            position
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{seq::SliceRandom, thread_rng, Rng};
+
+    use super::{assign_rings, check_ring_assignment, key_set_root, ring_order};
+    use crate::{
+        ec_tools::LsagScheme,
+        ring_signature::RingSignatureScheme,
+        rsa_tools::{RsaKeySize, RsaScheme},
+        vote_config::VoteConfig,
+    };
+
+    /// Config which created an election of `public_keys` in rings of 3
+    fn first_config<S: RingSignatureScheme>(
+        scheme: &S,
+        public_keys: &[S::PublicKey],
+    ) -> VoteConfig {
+        VoteConfig {
+            network: [0; 32],
+            merkle_root: key_set_root(scheme, public_keys, 3).unwrap(),
+            user_count: public_keys.len() as u32,
+            leaf_count: public_keys.len().div_ceil(3) as u32,
+            group_size: 3,
+            signature_scheme: scheme.signature_scheme(),
+            voting_start: 0,
+            voting_end: 1,
+            admin_lock_hash: [0; 32],
+            key_image_registry_type_hash: [0; 32],
+            candidates: vec![],
+        }
+    }
+
+    #[test]
+    fn test_assign_rings() {
+        let mut rng = thread_rng();
+        let seed = rng.gen();
+        let public_keys = (0..40).map(|_| rng.gen()).collect::<Vec<[u8; 32]>>();
+        let config = first_config(&LsagScheme, &public_keys);
+        let assigned = assign_rings(&LsagScheme, &public_keys, &seed).unwrap();
+        let mut sorted = assigned.clone();
+        sorted.sort();
+        let mut expected = public_keys.clone();
+        expected.sort();
+        assert_eq!(sorted, expected);
+        check_ring_assignment(&LsagScheme, &assigned, &config, &seed).unwrap();
+
+        // Whoever uploads the keys can't change the assignment or the commitment by their order
+        let mut uploaded = public_keys.clone();
+        uploaded.shuffle(&mut rng);
+        assert_eq!(
+            assign_rings(&LsagScheme, &uploaded, &seed).unwrap(),
+            assigned
+        );
+        assert_eq!(
+            key_set_root(&LsagScheme, &uploaded, 3).unwrap(),
+            config.merkle_root
+        );
+        assert_ne!(
+            assign_rings(&LsagScheme, &public_keys, &rng.gen()).unwrap(),
+            assigned
+        );
+
+        // Nor move a key to another ring
+        let mut moved = assigned.clone();
+        moved.swap(3, 30);
+        assert!(check_ring_assignment(&LsagScheme, &moved, &config, &seed).is_err());
+        assert!(check_ring_assignment(&LsagScheme, &public_keys, &config, &seed).is_err());
+        assert!(check_ring_assignment(&LsagScheme, &assigned, &config, &rng.gen()).is_err());
+        // Nor add a key after the seed is known
+        let mut added = public_keys.clone();
+        added.push(rng.gen());
+        let added = assign_rings(&LsagScheme, &added, &seed).unwrap();
+        assert!(check_ring_assignment(&LsagScheme, &added, &config, &seed).is_err());
+
+        let scheme = RsaScheme(RsaKeySize::Rsa1024);
+        let public_keys = (0..5)
+            .map(|_| scheme.public_key(&scheme.generate_key(&mut rng).unwrap()))
+            .collect::<Vec<_>>();
+        let assigned = assign_rings(&scheme, &public_keys, &seed).unwrap();
+        check_ring_assignment(
+            &scheme,
+            &assigned,
+            &first_config(&scheme, &public_keys),
+            &seed,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_ring_order_known_answer() {
+        // Other implementations of the shuffle must agree on this order
+        let public_keys = (0..10u8).rev().map(|x| [x; 32]).collect::<Vec<_>>();
+        let order = ring_order(&LsagScheme, &public_keys, &[0; 32]).unwrap();
+        assert_eq!(order, [4, 8, 3, 7, 2, 1, 0, 6, 5, 9]);
+    }
+}
@@ -0,0 +1,89 @@
+//! Ring signature schemes of elections
+//!
+//! An election needs keys, signatures over a ring of public keys, key images which link all
+//! signatures of a private key in the election, and the leaf hash of a ring in the merkle tree of
+//! all public keys. Only LSAG images do, see [`crate::rsa_tools`] for why RSA is refused.
+//! Merkle trees, vote cells and witnesses are built on top of [`RingSignatureScheme`], so a new
+//! scheme only implements it, and gets a variant in [`SignatureScheme`] and `RingSignature`.
+use rand_core::CryptoRngCore;
+use vote_types::VoteError;
+
+use crate::{vote::RingSignature, vote_config::SignatureScheme};
+
+pub trait RingSignatureScheme {
+    type PrivateKey;
+    type PublicKey: Clone;
+    type Signature: Into<RingSignature>;
+    /// Key image as stored in vote cells, which is also what the key image registry is keyed by
+    type KeyImage: AsRef<[u8]>;
+
+    /// Scheme recorded in the config of elections using it
+    fn signature_scheme(&self) -> SignatureScheme;
+
+    fn generate_key(&self, rng: &mut impl CryptoRngCore) -> anyhow::Result<Self::PrivateKey>;
+
+    fn public_key(&self, key: &Self::PrivateKey) -> Self::PublicKey;
+
+    /// Public key as stored in public key registry cells, keys of a ring concatenated in this
+    /// encoding are what [`RingSignatureScheme::leaf_hash`] hashes
+    fn encode_public_key(&self, key: &Self::PublicKey) -> anyhow::Result<Vec<u8>>;
+
+    fn decode_public_key(&self, bytes: &[u8]) -> anyhow::Result<Self::PublicKey>;
+
+    /// Sign `message` with `key`, which is the one at `signer` of `ring`. The key image is of the
+    /// election `election_id`, which is the type hash of its config cell, so LSAG signatures of
+    /// the same key in other elections can't be linked, unlike RSA ones, see [`crate::rsa_tools`]
+    fn sign(
+        &self,
+        ring: &[Self::PublicKey],
+        key: &Self::PrivateKey,
+        signer: usize,
+        election_id: &[u8; 32],
+        message: &[u8],
+    ) -> anyhow::Result<Self::Signature> {
+        self.sign_with_rng(
+            ring,
+            key,
+            signer,
+            election_id,
+            message,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Same as [`RingSignatureScheme::sign`], with the randomness of the signature drawn from
+    /// `rng`, so signatures made with a seeded rng are reproducible
+    fn sign_with_rng(
+        &self,
+        ring: &[Self::PublicKey],
+        key: &Self::PrivateKey,
+        signer: usize,
+        election_id: &[u8; 32],
+        message: &[u8],
+        rng: &mut impl CryptoRngCore,
+    ) -> anyhow::Result<Self::Signature>;
+
+    /// Verify a signature over `ring` in the election `election_id`, in the same way as the
+    /// contract does
+    fn verify(
+        &self,
+        ring: &[Self::PublicKey],
+        election_id: &[u8; 32],
+        message: &[u8],
+        signature: &Self::Signature,
+    ) -> anyhow::Result<bool>;
+
+    /// Checks the contract makes of a signature before verifying it, which fail with errors of
+    /// their own rather than `BadSignature`
+    fn check_encoding(&self, signature: &Self::Signature) -> Result<(), VoteError>;
+
+    /// Ring carried by the signature, which is what witnesses prove to be in the merkle tree
+    fn ring(&self, signature: &Self::Signature) -> Vec<Self::PublicKey>;
+
+    /// Link tag of a signature. For LSAG it's the same for all signatures made by a private key
+    /// in an election, while an RSA signer can make another tag verify, see [`crate::rsa_tools`]
+    fn link_tag(&self, signature: &Self::Signature) -> anyhow::Result<Self::KeyImage>;
+
+    /// Leaf of a ring in the merkle tree
+    fn leaf_hash(&self, ring: &[Self::PublicKey]) -> anyhow::Result<[u8; 32]>;
+}
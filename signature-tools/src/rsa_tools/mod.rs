@@ -1,76 +1,268 @@
+//! RSA ring signatures with key images, as verified by the contract
+//!
+//! Elections with RSA keys are refused by the contract and by [`crate::vote::verify_vote`] with
+//! `UnsupportedSignatureScheme`. Verification only ties the key image to a prime `p` of the
+//! signer, as `I * q^e` vanishes modulo `n = p * q` for every multiple `I` of `p`, so each such
+//! multiple below the smallest modulus of the ring verifies and a key could vote once per
+//! multiple. Images don't hide the signer either: the gcd of an image with the modulus of the
+//! signer is `p`, while it's 1 with the other moduli, so anyone finds the voter in the ring and
+//! factors their key, in every election. Signing and verification are kept for the test vectors
+//! and the tests of the refusal.
 use std::io::Write;
 
-use anyhow::bail;
+use anyhow::{anyhow, bail, Context};
 
 use num_bigint_dig::RandBigInt;
-use rsa::BigUint;
+use rand_core::CryptoRngCore;
+pub use rsa::BigUint;
 pub use rsa::RsaPrivateKey;
 pub use rsa::RsaPublicKey;
 
 pub use rsa::traits::{PrivateKeyParts, PublicKeyParts};
 use sha2::Digest;
 use sha2::Sha256;
+use vote_types::VoteError;
 
 use crate::check_size_and_write;
+use crate::ring_signature::RingSignatureScheme;
 use crate::sha256_for_integer;
+use crate::vote_config::SignatureScheme;
 
-pub mod merkle_tree;
+/// Size of RSA moduli in an election, big integers are encoded in the size of the modulus
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RsaKeySize {
+    Rsa1024,
+    #[default]
+    Rsa2048,
+    Rsa3072,
+    Rsa4096,
+}
+
+impl RsaKeySize {
+    pub const ALL: [RsaKeySize; 4] = [
+        RsaKeySize::Rsa1024,
+        RsaKeySize::Rsa2048,
+        RsaKeySize::Rsa3072,
+        RsaKeySize::Rsa4096,
+    ];
+
+    pub fn from_bits(bits: u32) -> anyhow::Result<Self> {
+        match Self::ALL.into_iter().find(|size| size.bits() == bits) {
+            Some(size) => Ok(size),
+            None => bail!("Unsupported RSA key size: {} bits", bits),
+        }
+    }
+
+    /// Size of the modulus of the key, which must be a supported one
+    pub fn of_key(key: &impl PublicKeyParts) -> anyhow::Result<Self> {
+        Self::from_bits(key.n().bits() as u32)
+    }
 
-#[derive(Debug)]
+    pub fn bits(self) -> u32 {
+        match self {
+            RsaKeySize::Rsa1024 => 1024,
+            RsaKeySize::Rsa2048 => 2048,
+            RsaKeySize::Rsa3072 => 3072,
+            RsaKeySize::Rsa4096 => 4096,
+        }
+    }
+
+    pub fn bytes(self) -> usize {
+        self.bits() as usize / 8
+    }
+}
+
+/// Size of keys of a ring, all of them must be of the same supported size
+pub fn ring_key_size<T: PublicKeyParts>(keys: &[T]) -> anyhow::Result<RsaKeySize> {
+    let Some(first) = keys.first() else {
+        bail!("Empty ring");
+    };
+    let size = RsaKeySize::of_key(first)?;
+    if keys.iter().any(|key| key.n().bits() as u32 != size.bits()) {
+        bail!("Keys of a ring must be of the same size");
+    }
+    Ok(size)
+}
+
+pub fn create_pubkey_group_hash<T: PublicKeyParts>(keys: &[T]) -> anyhow::Result<Vec<u8>> {
+    let size = ring_key_size(keys)?.bytes();
+    let mut hasher = Sha256::new();
+    for entry in keys.iter() {
+        check_size_and_write(&mut hasher, entry.n(), size)
+            .with_context(|| anyhow!("Failed to write public key entry n"))?;
+        check_size_and_write(&mut hasher, entry.e(), 4)
+            .with_context(|| anyhow!("Failed to write public key entry e"))?;
+    }
+    let hash = hasher.finalize().to_vec();
+    Ok(hash)
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct RSASignaturePubKeyEnt {
     pub r: BigUint,
     pub e: BigUint,
     pub n: BigUint,
 }
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct RSASignature {
     pub c: BigUint,
     pub i: BigUint,
     pub r_and_pubkey: Vec<RSASignaturePubKeyEnt>,
 }
+impl PublicKeyParts for RSASignaturePubKeyEnt {
+    fn n(&self) -> &BigUint {
+        &self.n
+    }
+    fn e(&self) -> &BigUint {
+        &self.e
+    }
+}
+
 impl RSASignature {
+    /// Ring size and key size in bits as u32, then c, i, and r, e, n of every ring member,
+    /// integers are little endian in the size of the modulus, or 4 bytes for e
     pub fn to_bytes(&self) -> anyhow::Result<Vec<u8>> {
         let mut out_buf = vec![];
         let n = self.r_and_pubkey.len();
-        out_buf.write_all(&n.to_le_bytes())?;
+        let key_size = ring_key_size(&self.r_and_pubkey)?;
+        let size = key_size.bytes();
+        out_buf.write_all(&(n as u32).to_le_bytes())?;
+        out_buf.write_all(&key_size.bits().to_le_bytes())?;
         let mut check_size_and_write = |a: &BigUint, b: usize| -> anyhow::Result<()> {
             check_size_and_write(&mut out_buf, a, b)
         };
-        check_size_and_write(&self.c, 256)?;
-        check_size_and_write(&self.i, 256)?;
+        check_size_and_write(&self.c, size)?;
+        check_size_and_write(&self.i, size)?;
         for i in 0..n {
-            check_size_and_write(&self.r_and_pubkey[i].r, 256)?;
+            check_size_and_write(&self.r_and_pubkey[i].r, size)?;
             check_size_and_write(&self.r_and_pubkey[i].e, 4)?;
-            check_size_and_write(&self.r_and_pubkey[i].n, 256)?;
+            check_size_and_write(&self.r_and_pubkey[i].n, size)?;
         }
         Ok(out_buf)
     }
+
+    pub fn from_bytes(buf: &[u8]) -> anyhow::Result<Self> {
+        let Some((n, buf)) = buf.split_first_chunk::<4>() else {
+            bail!("Missing ring size");
+        };
+        let n = u32::from_le_bytes(*n) as usize;
+        let Some((bits, buf)) = buf.split_first_chunk::<4>() else {
+            bail!("Missing key size");
+        };
+        let size = RsaKeySize::from_bits(u32::from_le_bytes(*bits))?.bytes();
+        if buf.len() != size * 2 + n * (size + 4 + size) {
+            bail!("Bad length of signature with ring size {}", n);
+        }
+        let (c, buf) = buf.split_at(size);
+        let (i, buf) = buf.split_at(size);
+        Ok(Self {
+            c: BigUint::from_bytes_le(c),
+            i: BigUint::from_bytes_le(i),
+            r_and_pubkey: buf
+                .chunks(size + 4 + size)
+                .map(|item| RSASignaturePubKeyEnt {
+                    r: BigUint::from_bytes_le(&item[..size]),
+                    e: BigUint::from_bytes_le(&item[size..size + 4]),
+                    n: BigUint::from_bytes_le(&item[size + 4..]),
+                })
+                .collect(),
+        })
+    }
 }
 
+/// Sign `message` in the election `election_id`, which is the type hash of its config cell. The
+/// key image is a multiple of a prime of the signer, so it gives the signer away, see the module
+/// docs
 pub fn create_signature<T: PublicKeyParts>(
     all_keys: &[T],
     signer_private_key: &RsaPrivateKey,
     signer: usize,
+    election_id: &[u8; 32],
+    message: &[u8],
+) -> anyhow::Result<RSASignature> {
+    create_signature_with_rng(
+        all_keys,
+        signer_private_key,
+        signer,
+        election_id,
+        message,
+        &mut rand::thread_rng(),
+    )
+}
+
+/// Same as [`create_signature`], but random numbers of the signature are drawn from `rng`, so a
+/// seeded rng gives the same signature every time
+pub fn create_signature_with_rng<T: PublicKeyParts>(
+    all_keys: &[T],
+    signer_private_key: &RsaPrivateKey,
+    signer: usize,
+    election_id: &[u8; 32],
+    message: &[u8],
+    rng: &mut impl CryptoRngCore,
+) -> anyhow::Result<RSASignature> {
+    let image = key_image(signer_private_key, election_id, all_keys)?;
+    create_signature_with_image(
+        all_keys,
+        signer_private_key,
+        signer,
+        election_id,
+        message,
+        image,
+        rng,
+    )
+}
+
+/// Key image of a key in an election, which is the same in every signature it makes there over
+/// `ring`. The image must be below the smallest modulus of the ring, see [`is_canonical`], so it's
+/// reduced by the largest multiple of `p` below that modulus, which keeps it a multiple of `p`.
+/// Other multiples of `p` verify as well, so it doesn't stop double votes, see the module docs
+fn key_image<T: PublicKeyParts>(
+    key: &RsaPrivateKey,
+    election_id: &[u8; 32],
+    ring: &[T],
+) -> anyhow::Result<BigUint> {
+    let [p, _] = &key.primes()[..2] else {
+        bail!("Unexpected prime count");
+    };
+    let Some(smallest_n) = ring.iter().map(|key| key.n()).min() else {
+        bail!("Empty ring");
+    };
+    let bound = smallest_n / p * p;
+    if bound == BigUint::default() {
+        bail!("Ring has a modulus below a prime of the signer");
+    }
+    let image = sha256_for_integer(election_id, key.n()).modpow(key.d(), key.n()) * p % key.n();
+    Ok(image % bound)
+}
+
+/// Same as [`create_signature_with_rng`], but the signature carries `image` instead of the key
+/// image of the signer. Only meant for tests, such as of images which are congruent to the key
+/// image but not reduced, or other multiples of `p`, which verify and are why RSA is refused
+pub fn create_signature_with_image<T: PublicKeyParts>(
+    all_keys: &[T],
+    signer_private_key: &RsaPrivateKey,
+    signer: usize,
+    election_id: &[u8; 32],
     message: &[u8],
+    image: BigUint,
+    rng: &mut impl CryptoRngCore,
 ) -> anyhow::Result<RSASignature> {
     let one = 1u32.into();
-    let mut rng = rand::thread_rng();
+    let rng = rng.as_rngcore();
     let skey = signer_private_key;
     let [p, q] = &skey.primes()[..2] else {
         bail!("Unexpected prime count");
     };
+    let size = ring_key_size(all_keys)?.bytes();
     let n = all_keys.len();
     let mut r_arr = vec![BigUint::default(); n];
     let mut c_arr = vec![BigUint::default(); n];
     let a = rng.gen_biguint_range(&one, skey.n());
-    let h_val = sha256_for_integer(skey.n());
-    let image = h_val.modpow(skey.d(), skey.n()) * p.clone() % skey.n();
+    let h_val = sha256_for_integer(election_id, skey.n());
     let mut hasher = Sha256::new();
     hasher.update(message);
     for key in all_keys {
-        let n = key.n().to_bytes_le();
-        assert_eq!(n.len(), 2048 / 8);
-        hasher.update(n);
+        hasher.update(key.n().to_bytes_le());
         let mut e = key.e().to_bytes_le();
         e.resize(4, 0);
         hasher.update(e);
@@ -79,10 +271,10 @@ pub fn create_signature<T: PublicKeyParts>(
     let hash = |a: &BigUint, b: &BigUint| -> BigUint {
         let mut local_hasher = hasher.clone();
         let mut a_bytes = a.to_bytes_le();
-        a_bytes.resize(256, 0);
+        a_bytes.resize(size, 0);
         local_hasher.update(&a_bytes);
         let mut b_bytes = b.to_bytes_le();
-        b_bytes.resize(256, 0);
+        b_bytes.resize(size, 0);
         local_hasher.update(&b_bytes);
         BigUint::from_bytes_le(&local_hasher.finalize())
     };
@@ -96,7 +288,8 @@ pub fn create_signature<T: PublicKeyParts>(
         r_arr[i] = rng.gen_biguint_range(&one, all_keys[i].n());
         let crpe = c_arr[i].clone() * r_arr[i].clone().modpow(all_keys[i].e(), all_keys[i].n())
             % all_keys[i].n();
-        let ch_pi_mul_r = (c_arr[i].clone() * sha256_for_integer(all_keys[i].n()) + image.clone())
+        let ch_pi_mul_r = (c_arr[i].clone() * sha256_for_integer(election_id, all_keys[i].n())
+            + image.clone())
             * r_arr[i].modpow(all_keys[i].e(), all_keys[i].n())
             % all_keys[i].n();
         c_arr[(i + 1) % n] = hash(&crpe, &ch_pi_mul_r);
@@ -120,35 +313,295 @@ pub fn create_signature<T: PublicKeyParts>(
             .collect::<Vec<_>>(),
     })
 }
-pub fn encode_public_key_cell(keys: &[RsaPrivateKey]) -> Vec<u8> {
-    let mut buf = Vec::<u8>::new();
-    buf.write_all(&(keys.len() as u16).to_le_bytes()).unwrap();
-    for item in keys {
-        let num_buf = item.n().to_bytes_le();
-        assert_eq!(num_buf.len(), 256);
-        buf.write_all(&num_buf).unwrap();
+/// Whether `c` is a sha256 hash, every `r` is reduced by its modulus and the key image is below
+/// every modulus of the ring. Otherwise adding a modulus to `r` gives another valid signature,
+/// and adding the modulus of the signer to the image another key image. The modulus of the
+/// signer is hidden, so the image is checked against the smallest one, whichever key signed.
+/// Images below it still aren't unique, see the module docs
+pub fn is_canonical(signature: &RSASignature) -> bool {
+    signature.c.bits() <= 256
+        && signature
+            .r_and_pubkey
+            .iter()
+            .all(|item| item.r < item.n && signature.i < item.n)
+}
+
+/// Verify a signature made by `create_signature` over the ring `keys` in the election
+/// `election_id`, in the same way as the contract does, which rejects signatures that aren't
+/// [canonical](is_canonical)
+pub fn verify_signature<T: PublicKeyParts>(
+    keys: &[T],
+    election_id: &[u8; 32],
+    message: &[u8],
+    signature: &RSASignature,
+) -> anyhow::Result<bool> {
+    let ring = &signature.r_and_pubkey;
+    if ring.len() != keys.len() {
+        bail!(
+            "Signature is made over {} keys, but {} given",
+            ring.len(),
+            keys.len()
+        );
+    }
+    if ring
+        .iter()
+        .zip(keys)
+        .any(|(item, key)| &item.n != key.n() || &item.e != key.e())
+    {
+        return Ok(false);
+    }
+    let size = ring_key_size(keys)?.bytes();
+    let mut hasher = Sha256::new();
+    hasher.update(message);
+    for key in keys {
+        check_size_and_write(&mut hasher, key.n(), size)?;
+        check_size_and_write(&mut hasher, key.e(), 4)?;
+    }
+    let hash = |a: &BigUint, b: &BigUint| -> anyhow::Result<BigUint> {
+        let mut local_hasher = hasher.clone();
+        check_size_and_write(&mut local_hasher, a, size)?;
+        check_size_and_write(&mut local_hasher, b, size)?;
+        Ok(BigUint::from_bytes_le(&local_hasher.finalize()))
+    };
+    if !is_canonical(signature) {
+        return Ok(false);
     }
-    for item in keys {
-        let mut num_buf = item.e().to_bytes_le();
-        num_buf.resize(4, 0);
-        buf.write_all(&num_buf).unwrap();
+    let mut last_c = signature.c.clone();
+    for item in ring.iter() {
+        // Same as the contract, whose Montgomery arithmetic only works with odd moduli
+        if &item.n % 2u32 == BigUint::default() {
+            bail!("Even modulus");
+        }
+        let r_power_e = item.r.modpow(&item.e, &item.n);
+        let crpe = &last_c * &r_power_e % &item.n;
+        let ch_pi_mul_r =
+            (&last_c * sha256_for_integer(election_id, &item.n) % &item.n + &signature.i) % &item.n
+                * &r_power_e
+                % &item.n;
+        last_c = hash(&crpe, &ch_pi_mul_r)?;
     }
-    buf
+    Ok(last_c == signature.c)
 }
 
-pub struct PublicKeyIndexEntry {
-    pub hash: Vec<u8>,
-    pub index: u32,
+/// RSA ring signatures, with all keys of an election in the same size
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RsaScheme(pub RsaKeySize);
+
+impl RsaScheme {
+    fn check_ring<T: PublicKeyParts>(&self, ring: &[T]) -> anyhow::Result<()> {
+        if ring_key_size(ring)? != self.0 {
+            bail!("Keys must be of {} bits", self.0.bits());
+        }
+        Ok(())
+    }
 }
-pub fn encode_public_key_index_cell(entries: &[PublicKeyIndexEntry]) -> Vec<u8> {
-    let mut buf = Vec::<u8>::new();
-    buf.write_all(&(entries.len() as u16).to_le_bytes())
-        .unwrap();
-    for key_hash in entries.iter() {
-        buf.write_all(&key_hash.hash).unwrap();
+
+impl RingSignatureScheme for RsaScheme {
+    type PrivateKey = RsaPrivateKey;
+    type PublicKey = RsaPublicKey;
+    type Signature = RSASignature;
+    /// Little endian, padded to the size of keys
+    type KeyImage = Vec<u8>;
+
+    fn signature_scheme(&self) -> SignatureScheme {
+        SignatureScheme::Rsa(self.0)
+    }
+
+    fn generate_key(&self, rng: &mut impl CryptoRngCore) -> anyhow::Result<RsaPrivateKey> {
+        Ok(RsaPrivateKey::new(rng, self.0.bits() as usize)?)
+    }
+
+    fn public_key(&self, key: &RsaPrivateKey) -> RsaPublicKey {
+        key.to_public_key()
+    }
+
+    /// `n` in the size of keys followed by `e` in 4 bytes, both little endian
+    fn encode_public_key(&self, key: &RsaPublicKey) -> anyhow::Result<Vec<u8>> {
+        self.check_ring(std::slice::from_ref(key))?;
+        let mut buf = Vec::with_capacity(self.0.bytes() + 4);
+        check_size_and_write(&mut buf, key.n(), self.0.bytes())?;
+        check_size_and_write(&mut buf, key.e(), 4)?;
+        Ok(buf)
+    }
+
+    fn decode_public_key(&self, bytes: &[u8]) -> anyhow::Result<RsaPublicKey> {
+        if bytes.len() != self.0.bytes() + 4 {
+            bail!("Public key must be {} bytes", self.0.bytes() + 4);
+        }
+        let (n, e) = bytes.split_at(self.0.bytes());
+        let key = RsaPublicKey::new_unchecked(BigUint::from_bytes_le(n), BigUint::from_bytes_le(e));
+        self.check_ring(std::slice::from_ref(&key))?;
+        Ok(key)
+    }
+
+    fn sign_with_rng(
+        &self,
+        ring: &[RsaPublicKey],
+        key: &RsaPrivateKey,
+        signer: usize,
+        election_id: &[u8; 32],
+        message: &[u8],
+        rng: &mut impl CryptoRngCore,
+    ) -> anyhow::Result<RSASignature> {
+        self.check_ring(ring)?;
+        create_signature_with_rng(ring, key, signer, election_id, message, rng)
+    }
+
+    fn verify(
+        &self,
+        ring: &[RsaPublicKey],
+        election_id: &[u8; 32],
+        message: &[u8],
+        signature: &RSASignature,
+    ) -> anyhow::Result<bool> {
+        self.check_ring(ring)?;
+        verify_signature(ring, election_id, message, signature)
     }
-    for key_hash in entries.iter() {
-        buf.write_all(&key_hash.index.to_le_bytes()).unwrap();
+
+    /// Moduli must be odd, as the contract verifies with Montgomery multiplication, and
+    /// integers must be reduced, see [`is_canonical`]
+    fn check_encoding(&self, signature: &RSASignature) -> Result<(), VoteError> {
+        if signature
+            .r_and_pubkey
+            .iter()
+            .any(|item| item.n.to_bytes_le()[0] & 1 == 0)
+        {
+            return Err(VoteError::BadModulus);
+        }
+        if !is_canonical(signature) {
+            return Err(VoteError::NonCanonicalInteger);
+        }
+        Ok(())
+    }
+
+    /// Keys are not validated, same as the contract
+    fn ring(&self, signature: &RSASignature) -> Vec<RsaPublicKey> {
+        signature
+            .r_and_pubkey
+            .iter()
+            .map(|item| RsaPublicKey::new_unchecked(item.n.clone(), item.e.clone()))
+            .collect()
+    }
+
+    fn link_tag(&self, signature: &RSASignature) -> anyhow::Result<Vec<u8>> {
+        if signature.i.bits() > self.0.bits() as usize {
+            bail!("Key image doesn't fit in {} bits", self.0.bits());
+        }
+        let mut image = Vec::with_capacity(self.0.bytes());
+        check_size_and_write(&mut image, &signature.i, self.0.bytes())?;
+        Ok(image)
+    }
+
+    fn leaf_hash(&self, ring: &[RsaPublicKey]) -> anyhow::Result<[u8; 32]> {
+        self.check_ring(ring)?;
+        Ok(create_pubkey_group_hash(ring)?.try_into().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{thread_rng, SeedableRng};
+    use rand_chacha::ChaCha20Rng;
+    use rayon::iter::{IntoParallelIterator, ParallelIterator};
+    use rsa::{
+        traits::{PrivateKeyParts, PublicKeyParts},
+        BigUint, RsaPrivateKey,
+    };
+
+    use super::{
+        create_signature, create_signature_with_image, create_signature_with_rng, is_canonical,
+        key_image, verify_signature, RSASignature, RsaKeySize,
+    };
+
+    const ELECTION_ID: [u8; 32] = [1; 32];
+
+    #[test]
+    fn test_verify_signature() {
+        let keys = (0..4)
+            .into_par_iter()
+            .map(|_| RsaPrivateKey::new(&mut thread_rng(), 2048).unwrap())
+            .collect::<Vec<_>>();
+        let message = b"candidate";
+        let signature = create_signature(&keys, &keys[2], 2, &ELECTION_ID, message).unwrap();
+        assert!(verify_signature(&keys, &ELECTION_ID, message, &signature).unwrap());
+        assert!(!verify_signature(&keys, &ELECTION_ID, b"another", &signature).unwrap());
+        let mut other_order = keys.clone();
+        other_order.swap(0, 1);
+        assert!(!verify_signature(&other_order, &ELECTION_ID, message, &signature).unwrap());
+        assert!(verify_signature(&keys[1..], &ELECTION_ID, message, &signature).is_err());
+        // Key images differ between elections, but each is a multiple of a prime of the signer,
+        // so its gcd with the modulus of the signer gives the signer away in both
+        assert!(!verify_signature(&keys, &[2; 32], message, &signature).unwrap());
+        let other_election = create_signature(&keys, &keys[2], 2, &[2; 32], message).unwrap();
+        assert!(verify_signature(&keys, &[2; 32], message, &other_election).unwrap());
+        assert_ne!(other_election.i, signature.i);
+        assert_eq!(
+            other_election.i,
+            key_image(&keys[2], &[2; 32], &keys).unwrap()
+        );
+        let p = &keys[2].primes()[0];
+        assert_eq!(&signature.i % p, BigUint::default());
+        assert_eq!(&other_election.i % p, BigUint::default());
+        // Signatures made with the same seed are the same
+        let seeded = |seed| {
+            let mut rng = ChaCha20Rng::seed_from_u64(seed);
+            create_signature_with_rng(&keys, &keys[2], 2, &ELECTION_ID, message, &mut rng).unwrap()
+        };
+        assert_eq!(seeded(1), seeded(1));
+        assert_ne!(seeded(1), seeded(2));
+        assert!(verify_signature(&keys, &ELECTION_ID, message, &seeded(1)).unwrap());
+
+        let encoded = signature.to_bytes().unwrap();
+        assert_eq!(RSASignature::from_bytes(&encoded).unwrap(), signature);
+        assert!(RSASignature::from_bytes(&encoded[..encoded.len() - 1]).is_err());
+        let mut tampered = signature.clone();
+        tampered.r_and_pubkey[0].r += 1u32;
+        assert!(!verify_signature(&keys, &ELECTION_ID, message, &tampered).unwrap());
+        // The contract can't verify over even moduli
+        let mut even = signature.clone();
+        even.r_and_pubkey[0].n += 1u32;
+        assert!(verify_signature(&even.r_and_pubkey, &ELECTION_ID, message, &even).is_err());
+
+        // Integers which are not reduced are rejected, though they would verify otherwise
+        let mut r_plus_n = signature.clone();
+        let n = r_plus_n.r_and_pubkey[0].n.clone();
+        r_plus_n.r_and_pubkey[0].r += n;
+        assert!(!verify_signature(&keys, &ELECTION_ID, message, &r_plus_n).unwrap());
+        let mut large_c = signature.clone();
+        large_c.c += BigUint::from(1u32) << 256;
+        assert!(!verify_signature(&keys, &ELECTION_ID, message, &large_c).unwrap());
+        // No key can add its modulus to its image, including the one of the smallest modulus,
+        // whose sum may still be below the moduli of the others
+        for signer in [
+            (0..keys.len()).min_by_key(|&i| keys[i].n()).unwrap(),
+            (0..keys.len()).max_by_key(|&i| keys[i].n()).unwrap(),
+        ] {
+            let image = key_image(&keys[signer], &ELECTION_ID, &keys).unwrap() + keys[signer].n();
+            let non_reduced_image = create_signature_with_image(
+                &keys,
+                &keys[signer],
+                signer,
+                &ELECTION_ID,
+                message,
+                image,
+                &mut thread_rng(),
+            )
+            .unwrap();
+            assert!(!verify_signature(&keys, &ELECTION_ID, message, &non_reduced_image).unwrap());
+        }
+        // Images of every key are below the smallest modulus of the ring
+        for signer in 0..keys.len() {
+            let signature =
+                create_signature(&keys, &keys[signer], signer, &ELECTION_ID, message).unwrap();
+            assert!(is_canonical(&signature));
+            assert!(verify_signature(&keys, &ELECTION_ID, message, &signature).unwrap());
+        }
+
+        // Keys of a ring must be of the same supported size
+        assert_eq!(RsaKeySize::of_key(&keys[0]).unwrap(), RsaKeySize::Rsa2048);
+        assert!(RsaKeySize::from_bits(1000).is_err());
+        let mut mixed = keys.clone();
+        mixed[0] = RsaPrivateKey::new(&mut thread_rng(), 1024).unwrap();
+        assert!(create_signature(&mixed, &mixed[2], 2, &ELECTION_ID, message).is_err());
     }
-    buf
 }
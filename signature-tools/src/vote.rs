@@ -0,0 +1,924 @@
+//! Vote cell and witness
+//!
+//! Data of a vote cell is a `VoteCell` of `vote-types`, and `output_type` of the witness at the
+//! same index is a `VoteWitness`, whose signature is of the scheme of the election. Integers of
+//! RSA are little endian, padded to the size of RSA keys of the election, or 4 bytes for public
+//! exponents.
+//!
+//! [`verify_vote`] checks a vote in the same way as the contract does, so votes can be audited
+//! without trusting the code hash of the contract.
+//!
+//! The ring signature of a vote signs its [`ballot_message`], which binds the candidate id to the
+//! election, the network and the code hash of the contract.
+//!
+//! A witness may leave the keys of the ring out and reference a cell dep instead, a public key
+//! registry cell with the keys of the ring, which saves most of the witness size. Such witnesses
+//! are decoded with [`VoteWitness::decode_with_cell_deps`].
+use anyhow::{anyhow, bail};
+pub use vote_types::{ballot_message, VoteError};
+use vote_types::{
+    molecule::prelude::*,
+    ring_size,
+    schema::{
+        self, Byte32Vec, BytesReader, BytesVec, LsagRingSignature, RingSignatureUnion,
+        RingSignatureUnionReader, RsaRingSignature, Uint32Opt, Uint32Vec, VoteCellReader,
+        VoteWitnessReader,
+    },
+};
+
+use crate::{
+    check_size_and_write,
+    ec_tools::{LsagPublicKey, LsagScheme, LsagSignature},
+    merkle_tree::verify_merkle_proof,
+    public_key_registry::ring_public_keys,
+    ring_signature::RingSignatureScheme,
+    rsa_tools::{ring_key_size, RSASignature, RSASignaturePubKeyEnt, RsaScheme},
+    vote_config::{SignatureScheme, VoteConfig},
+    BigUint,
+};
+
+fn to_bytes(number: &BigUint, size: usize) -> anyhow::Result<Vec<u8>> {
+    if number.bits() > size * 8 {
+        bail!("Number doesn't fit in {} bytes", size);
+    }
+    let mut buf = Vec::with_capacity(size);
+    check_size_and_write(&mut buf, number, size)?;
+    Ok(buf)
+}
+
+fn to_array<const N: usize>(number: &BigUint) -> anyhow::Result<[u8; N]> {
+    Ok(to_bytes(number, N)?.try_into().unwrap())
+}
+
+fn from_bytes(bytes: BytesReader, size: usize, name: &str) -> anyhow::Result<BigUint> {
+    let bytes = bytes.raw_data();
+    if bytes.len() != size {
+        bail!("{} must be in {} bytes", name, size);
+    }
+    Ok(BigUint::from_bytes_le(bytes))
+}
+
+fn encode_integers<'a>(
+    numbers: impl Iterator<Item = &'a BigUint>,
+    size: usize,
+) -> anyhow::Result<BytesVec> {
+    Ok(BytesVec::new_builder()
+        .set(
+            numbers
+                .map(|x| Ok(to_bytes(x, size)?.as_slice().into()))
+                .collect::<anyhow::Result<_>>()?,
+        )
+        .build())
+}
+
+fn encode_byte32_vec(items: &[[u8; 32]]) -> Byte32Vec {
+    Byte32Vec::new_builder()
+        .set(items.iter().map(|&x| x.into()).collect())
+        .build()
+}
+
+/// Ring signature of a vote, of the scheme of the election
+#[derive(Debug, Clone, PartialEq)]
+pub enum RingSignature {
+    Rsa(RSASignature),
+    Lsag(LsagSignature),
+}
+
+impl From<RSASignature> for RingSignature {
+    fn from(value: RSASignature) -> Self {
+        RingSignature::Rsa(value)
+    }
+}
+
+impl From<LsagSignature> for RingSignature {
+    fn from(value: LsagSignature) -> Self {
+        RingSignature::Lsag(value)
+    }
+}
+
+impl RingSignature {
+    /// Key image as stored in vote cells, which is also what the key image registry is keyed by
+    pub fn image(&self) -> anyhow::Result<Vec<u8>> {
+        match self {
+            RingSignature::Rsa(signature) => {
+                RsaScheme(ring_key_size(&signature.r_and_pubkey)?).link_tag(signature)
+            }
+            RingSignature::Lsag(signature) => Ok(LsagScheme.link_tag(signature)?.to_vec()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VoteCell {
+    pub candidate_id: [u8; 4],
+    /// Key image of the signature, in the size of the scheme
+    pub image: Vec<u8>,
+}
+
+impl VoteCell {
+    /// Vote for `candidate_id`, with the image of `signature`
+    pub fn new(candidate_id: [u8; 4], signature: &RingSignature) -> anyhow::Result<Self> {
+        Ok(Self {
+            candidate_id,
+            image: signature.image()?,
+        })
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        schema::VoteCell::new_builder()
+            .candidate_id(self.candidate_id.into())
+            .image(self.image.as_slice().into())
+            .build()
+            .as_slice()
+            .to_vec()
+    }
+
+    pub fn decode(buf: &[u8], scheme: SignatureScheme) -> anyhow::Result<Self> {
+        let reader =
+            VoteCellReader::from_slice(buf).map_err(|e| anyhow!("Bad vote cell: {}", e))?;
+        let image = reader.image().raw_data();
+        if image.len() != scheme.image_size() {
+            bail!("Image must be in {} bytes", scheme.image_size());
+        }
+        Ok(Self {
+            candidate_id: reader.candidate_id().into(),
+            image: image.to_vec(),
+        })
+    }
+}
+
+/// Signature in a vote witness, whose key image is in the vote cell
+#[derive(Debug, Clone, PartialEq)]
+pub enum WitnessSignature {
+    Rsa {
+        c: BigUint,
+        r_and_pubkey: Vec<RSASignaturePubKeyEnt>,
+    },
+    Lsag {
+        c: [u8; 32],
+        r: Vec<[u8; 32]>,
+        public_keys: Vec<LsagPublicKey>,
+    },
+}
+
+impl WitnessSignature {
+    pub fn ring_size(&self) -> usize {
+        match self {
+            WitnessSignature::Rsa { r_and_pubkey, .. } => r_and_pubkey.len(),
+            WitnessSignature::Lsag { public_keys, .. } => public_keys.len(),
+        }
+    }
+
+    /// Keys of the ring are left out with `with_keys` false
+    fn encode(
+        &self,
+        scheme: SignatureScheme,
+        with_keys: bool,
+    ) -> anyhow::Result<schema::RingSignature> {
+        let signature = match (self, scheme) {
+            (WitnessSignature::Rsa { c, r_and_pubkey }, SignatureScheme::Rsa(key_size)) => {
+                let size = key_size.bytes();
+                let keys = if with_keys { &r_and_pubkey[..] } else { &[] };
+                RingSignatureUnion::RsaRingSignature(
+                    RsaRingSignature::new_builder()
+                        .c(to_bytes(c, size)?.as_slice().into())
+                        .r(encode_integers(r_and_pubkey.iter().map(|x| &x.r), size)?)
+                        .n(encode_integers(keys.iter().map(|x| &x.n), size)?)
+                        .e(Uint32Vec::new_builder()
+                            .set(
+                                keys.iter()
+                                    .map(|x| Ok(u32::from_le_bytes(to_array::<4>(&x.e)?).into()))
+                                    .collect::<anyhow::Result<_>>()?,
+                            )
+                            .build())
+                        .build(),
+                )
+            }
+            (WitnessSignature::Lsag { c, r, public_keys }, SignatureScheme::Lsag) => {
+                let keys = if with_keys { &public_keys[..] } else { &[] };
+                RingSignatureUnion::LsagRingSignature(
+                    LsagRingSignature::new_builder()
+                        .c((*c).into())
+                        .r(encode_byte32_vec(r))
+                        .public_keys(encode_byte32_vec(keys))
+                        .build(),
+                )
+            }
+            _ => bail!("Signature isn't of scheme {:?}", scheme),
+        };
+        Ok(schema::RingSignature::new_builder().set(signature).build())
+    }
+
+    /// Number of `r` and of keys in an encoded signature of `scheme`, or none if the signature
+    /// is of another scheme
+    fn counts(
+        reader: &RingSignatureUnionReader,
+        scheme: SignatureScheme,
+    ) -> Option<(usize, usize)> {
+        match (reader, scheme) {
+            (RingSignatureUnionReader::RsaRingSignature(reader), SignatureScheme::Rsa(_)) => {
+                Some((reader.r().len(), reader.n().len().max(reader.e().len())))
+            }
+            (RingSignatureUnionReader::LsagRingSignature(reader), SignatureScheme::Lsag) => {
+                Some((reader.r().len(), reader.public_keys().len()))
+            }
+            _ => None,
+        }
+    }
+
+    /// `ring_keys` are the keys of the ring in a registry cell, concatenated as in the cell, for
+    /// signatures which leave them out
+    fn decode(
+        reader: RingSignatureUnionReader,
+        scheme: SignatureScheme,
+        ring_keys: Option<&[u8]>,
+    ) -> anyhow::Result<Self> {
+        match (reader, scheme) {
+            (
+                RingSignatureUnionReader::RsaRingSignature(reader),
+                SignatureScheme::Rsa(key_size),
+            ) => {
+                let size = key_size.bytes();
+                let ring_size = reader.r().len();
+                let public_keys = match ring_keys {
+                    Some(keys) => keys
+                        .chunks(scheme.public_key_size())
+                        .map(|key| {
+                            (
+                                BigUint::from_bytes_le(&key[..size]),
+                                BigUint::from_bytes_le(&key[size..]),
+                            )
+                        })
+                        .collect::<Vec<_>>(),
+                    None => {
+                        if reader.n().len() != ring_size || reader.e().len() != ring_size {
+                            bail!("Mismatched ring size in vote witness");
+                        }
+                        reader
+                            .n()
+                            .iter()
+                            .zip(reader.e().iter())
+                            .map(|(n, e)| Ok((from_bytes(n, size, "n")?, u32::from(e).into())))
+                            .collect::<anyhow::Result<_>>()?
+                    }
+                };
+                let r_and_pubkey = reader
+                    .r()
+                    .iter()
+                    .zip(public_keys)
+                    .map(|(r, (n, e))| {
+                        Ok(RSASignaturePubKeyEnt {
+                            r: from_bytes(r, size, "r")?,
+                            e,
+                            n,
+                        })
+                    })
+                    .collect::<anyhow::Result<_>>()?;
+                Ok(WitnessSignature::Rsa {
+                    c: from_bytes(reader.c(), size, "c")?,
+                    r_and_pubkey,
+                })
+            }
+            (RingSignatureUnionReader::LsagRingSignature(reader), SignatureScheme::Lsag) => {
+                let public_keys = match ring_keys {
+                    Some(keys) => keys
+                        .chunks(scheme.public_key_size())
+                        .map(|key| key.try_into().unwrap())
+                        .collect(),
+                    None => {
+                        if reader.public_keys().len() != reader.r().len() {
+                            bail!("Mismatched ring size in vote witness");
+                        }
+                        reader.public_keys().iter().map(Into::into).collect()
+                    }
+                };
+                Ok(WitnessSignature::Lsag {
+                    c: reader.c().into(),
+                    r: reader.r().iter().map(Into::into).collect(),
+                    public_keys,
+                })
+            }
+            _ => bail!("Signature of vote witness isn't of scheme {:?}", scheme),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VoteWitness {
+    pub signature: WitnessSignature,
+    /// Index of the ring in the merkle tree
+    pub leaf_index: u32,
+    pub merkle_proof: Vec<u8>,
+    /// Index of a cell dep of the transaction, a public key registry cell with the keys of the
+    /// ring, which are then left out of the encoded witness
+    pub ring_cell_dep: Option<u32>,
+}
+
+impl VoteWitness {
+    pub fn new(signature: &RingSignature, leaf_index: u32, merkle_proof: Vec<u8>) -> Self {
+        let signature = match signature {
+            RingSignature::Rsa(signature) => WitnessSignature::Rsa {
+                c: signature.c.clone(),
+                r_and_pubkey: signature.r_and_pubkey.clone(),
+            },
+            RingSignature::Lsag(signature) => WitnessSignature::Lsag {
+                c: signature.c,
+                r: signature.r.clone(),
+                public_keys: signature.public_keys.clone(),
+            },
+        };
+        Self {
+            signature,
+            leaf_index,
+            merkle_proof,
+            ring_cell_dep: None,
+        }
+    }
+
+    /// Signature carried by this witness, with the image from the vote cell
+    pub fn signature(&self, image: &[u8]) -> anyhow::Result<RingSignature> {
+        Ok(match &self.signature {
+            WitnessSignature::Rsa { c, r_and_pubkey } => RingSignature::Rsa(RSASignature {
+                c: c.clone(),
+                i: BigUint::from_bytes_le(image),
+                r_and_pubkey: r_and_pubkey.clone(),
+            }),
+            WitnessSignature::Lsag { c, r, public_keys } => RingSignature::Lsag(LsagSignature {
+                c: *c,
+                image: image
+                    .try_into()
+                    .map_err(|_| anyhow!("Image must be in 32 bytes"))?,
+                r: r.clone(),
+                public_keys: public_keys.clone(),
+            }),
+        })
+    }
+
+    pub fn encode(&self, scheme: SignatureScheme) -> anyhow::Result<Vec<u8>> {
+        Ok(schema::VoteWitness::new_builder()
+            .signature(
+                self.signature
+                    .encode(scheme, self.ring_cell_dep.is_none())?,
+            )
+            .leaf_index(self.leaf_index.into())
+            .merkle_proof(self.merkle_proof.as_slice().into())
+            .ring_cell_dep(
+                Uint32Opt::new_builder()
+                    .set(self.ring_cell_dep.map(Into::into))
+                    .build(),
+            )
+            .build()
+            .as_slice()
+            .to_vec())
+    }
+
+    /// Decode a witness with the keys of the ring, use [`Self::decode_with_cell_deps`] for
+    /// witnesses which reference a registry cell instead
+    pub fn decode(buf: &[u8], scheme: SignatureScheme) -> anyhow::Result<Self> {
+        let reader =
+            VoteWitnessReader::from_slice(buf).map_err(|e| anyhow!("Bad vote witness: {}", e))?;
+        if let Some(index) = reader.ring_cell_dep().to_opt() {
+            bail!(
+                "Keys of the ring are in cell dep {}, which is needed to decode the witness",
+                u32::from(index)
+            );
+        }
+        Ok(Self {
+            signature: WitnessSignature::decode(reader.signature().to_enum(), scheme, None)?,
+            leaf_index: reader.leaf_index().into(),
+            merkle_proof: reader.merkle_proof().raw_data().to_vec(),
+            ring_cell_dep: None,
+        })
+    }
+
+    /// Decode a witness of a vote of the election with `config`, taking the keys of the ring
+    /// from the registry cell it references, if any. `load_cell_dep` loads data of the cell dep
+    /// at an index of the transaction of the vote. The ring is found in the cell in the same way
+    /// as the contract does, failing with the same errors
+    pub fn decode_with_cell_deps(
+        buf: &[u8],
+        config: &VoteConfig,
+        load_cell_dep: impl FnOnce(u32) -> anyhow::Result<Vec<u8>>,
+    ) -> anyhow::Result<Self> {
+        let scheme = config.signature_scheme;
+        let reader = VoteWitnessReader::from_slice(buf).map_err(|_| VoteError::BadVoteWitness)?;
+        let leaf_index = reader.leaf_index().into();
+        let ring_cell_dep = reader.ring_cell_dep().to_opt().map(u32::from);
+        let ring_cell = ring_cell_dep.map(load_cell_dep).transpose()?;
+        let signature = reader.signature().to_enum();
+        let ring_keys = match (&ring_cell, WitnessSignature::counts(&signature, scheme)) {
+            (Some(ring_cell), Some((ring_size, keys_in_witness))) => {
+                if keys_in_witness != 0 {
+                    return Err(VoteError::RingKeysInWitness.into());
+                }
+                check_ring_size(config, leaf_index, ring_size)?;
+                Some(ring_public_keys(ring_cell, config, leaf_index, ring_size)?)
+            }
+            (Some(_), None) => return Err(VoteError::SignatureSchemeMismatch.into()),
+            (None, _) => None,
+        };
+        Ok(Self {
+            signature: WitnessSignature::decode(signature, scheme, ring_keys)?,
+            leaf_index,
+            merkle_proof: reader.merkle_proof().raw_data().to_vec(),
+            ring_cell_dep,
+        })
+    }
+}
+
+/// Verify a vote against the election config, in the same order as the contract, failing with
+/// the error the contract exits with. `election_id` is the type hash of the config cell, which key
+/// images are scoped to, and `contract_code_hash` is the code hash of the type script of vote cells
+pub fn verify_vote(
+    election_id: &[u8; 32],
+    contract_code_hash: &[u8; 32],
+    config: &VoteConfig,
+    cell: &VoteCell,
+    witness: &VoteWitness,
+) -> Result<(), VoteError> {
+    // RSA key images neither stop double votes nor hide the voter, see `rsa_tools`
+    if let SignatureScheme::Rsa(_) = config.signature_scheme {
+        return Err(VoteError::UnsupportedSignatureScheme);
+    }
+    if cell.image.len() != config.signature_scheme.image_size() {
+        return Err(VoteError::BadImageSize);
+    }
+    if !config
+        .candidates
+        .iter()
+        .any(|candidate| candidate.id == cell.candidate_id)
+    {
+        return Err(VoteError::BadCandidateId);
+    }
+    let message = &ballot_message(
+        election_id,
+        &config.network,
+        contract_code_hash,
+        &cell.candidate_id,
+    );
+    match (witness.signature(&cell.image), config.signature_scheme) {
+        (Ok(RingSignature::Lsag(signature)), SignatureScheme::Lsag) => verify_ring_signature(
+            &LsagScheme,
+            election_id,
+            config,
+            witness,
+            message,
+            &signature,
+        ),
+        (Ok(_), _) => Err(VoteError::SignatureSchemeMismatch),
+        (Err(_), _) => Err(VoteError::BadImageSize),
+    }
+}
+
+/// The ring must be the whole ring at its leaf index
+fn check_ring_size(config: &VoteConfig, leaf_index: u32, size: usize) -> Result<(), VoteError> {
+    if size == 0 {
+        return Err(VoteError::EmptyRing);
+    }
+    if size > config.group_size as usize {
+        return Err(VoteError::RingTooLarge);
+    }
+    let expected_size = ring_size(
+        config.user_count as usize,
+        config.group_size as usize,
+        leaf_index as usize,
+    );
+    if size != expected_size {
+        return Err(VoteError::UnexpectedRingSize);
+    }
+    Ok(())
+}
+
+/// Verify that the ring of the signature is in the merkle tree, then the signature
+fn verify_ring_signature<S: RingSignatureScheme>(
+    scheme: &S,
+    election_id: &[u8; 32],
+    config: &VoteConfig,
+    witness: &VoteWitness,
+    message: &[u8],
+    signature: &S::Signature,
+) -> Result<(), VoteError> {
+    let ring = scheme.ring(signature);
+    check_ring_size(config, witness.leaf_index, ring.len())?;
+    let leaf_hash = scheme
+        .leaf_hash(&ring)
+        .map_err(|_| VoteError::BadIntegerSize)?;
+    match verify_merkle_proof(
+        &witness.merkle_proof,
+        &config.merkle_root,
+        witness.leaf_index as usize,
+        &leaf_hash,
+        config.leaf_count as usize,
+    ) {
+        Ok(true) => {}
+        Ok(false) => return Err(VoteError::BadMerkleProof),
+        Err(_) => return Err(VoteError::BadMerkleProofLength),
+    }
+    scheme.check_encoding(signature)?;
+    if !matches!(
+        scheme.verify(&ring, election_id, message, signature),
+        Ok(true)
+    ) {
+        return Err(VoteError::BadSignature);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{thread_rng, Rng};
+    use vote_types::ring_size;
+
+    use vote_types::{
+        molecule::prelude::*,
+        schema::{self, Uint32Opt},
+    };
+
+    use super::{
+        ballot_message, verify_vote, RingSignature, VoteCell, VoteError, VoteWitness,
+        WitnessSignature,
+    };
+    use crate::{
+        candidate::Candidate,
+        ec_tools::LsagScheme,
+        merkle_tree::{
+            create_merkle_tree_with_proof, create_merkle_tree_with_root_hash, ring_range,
+        },
+        ring_signature::RingSignatureScheme,
+        rsa_tools::{RSASignaturePubKeyEnt, RsaKeySize, RsaScheme},
+        vote_config::{SignatureScheme, VoteConfig},
+        BigUint,
+    };
+
+    #[test]
+    fn test_vote_encoding() {
+        let mut rng = thread_rng();
+        let mut rand_number = || BigUint::from_bytes_le(&rng.gen::<[u8; 32]>().repeat(8));
+        let scheme = SignatureScheme::Rsa(RsaKeySize::Rsa2048);
+        let cell = VoteCell {
+            candidate_id: [1, 2, 3, 4],
+            image: vec![7; 256],
+        };
+        let encoded = cell.encode();
+        assert_eq!(VoteCell::decode(&encoded, scheme).unwrap(), cell);
+        assert!(VoteCell::decode(&encoded, SignatureScheme::Rsa(RsaKeySize::Rsa3072)).is_err());
+        assert!(VoteCell::decode(&encoded, SignatureScheme::Lsag).is_err());
+
+        let witness = VoteWitness {
+            signature: WitnessSignature::Rsa {
+                c: rand_number(),
+                r_and_pubkey: (0..5)
+                    .map(|_| RSASignaturePubKeyEnt {
+                        r: rand_number(),
+                        e: 65537u32.into(),
+                        n: rand_number(),
+                    })
+                    .collect(),
+            },
+            leaf_index: 3,
+            merkle_proof: vec![7; 100],
+            ring_cell_dep: None,
+        };
+        let encoded = witness.encode(scheme).unwrap();
+        assert_eq!(VoteWitness::decode(&encoded, scheme).unwrap(), witness);
+        assert!(VoteWitness::decode(&encoded[..encoded.len() - 1], scheme).is_err());
+        assert!(VoteWitness::decode(&encoded, SignatureScheme::Rsa(RsaKeySize::Rsa1024)).is_err());
+        assert!(VoteWitness::decode(&encoded, SignatureScheme::Lsag).is_err());
+        assert!(witness.encode(SignatureScheme::Lsag).is_err());
+
+        let mut too_large = witness.clone();
+        if let WitnessSignature::Rsa { c, .. } = &mut too_large.signature {
+            *c = BigUint::from_bytes_le(&[1; 257]);
+        }
+        assert!(too_large.encode(scheme).is_err());
+
+        let witness = VoteWitness {
+            signature: WitnessSignature::Lsag {
+                c: rng.gen(),
+                r: vec![rng.gen(); 3],
+                public_keys: vec![rng.gen(); 3],
+            },
+            leaf_index: 1,
+            merkle_proof: vec![],
+            ring_cell_dep: None,
+        };
+        let encoded = witness.encode(SignatureScheme::Lsag).unwrap();
+        assert_eq!(
+            VoteWitness::decode(&encoded, SignatureScheme::Lsag).unwrap(),
+            witness
+        );
+        assert!(VoteWitness::decode(&encoded, scheme).is_err());
+    }
+
+    const GROUP_SIZE: usize = 3;
+    /// Type hash of the config cell of the election of [`signed_vote`]
+    const ELECTION_ID: [u8; 32] = [1; 32];
+    /// Code hash of the contract the votes of [`signed_vote`] are for
+    const CONTRACT_CODE_HASH: [u8; 32] = [2; 32];
+
+    /// Config of an election of 7 keys, and a vote signed by a key in `block`
+    fn signed_vote<S: RingSignatureScheme>(
+        scheme: &S,
+        block: usize,
+    ) -> (VoteConfig, VoteCell, VoteWitness) {
+        let keys = (0..7)
+            .map(|_| scheme.generate_key(&mut thread_rng()).unwrap())
+            .collect::<Vec<_>>();
+        let public_keys = keys
+            .iter()
+            .map(|x| scheme.public_key(x))
+            .collect::<Vec<_>>();
+        let config = VoteConfig {
+            network: [3; 32],
+            merkle_root: create_merkle_tree_with_root_hash(scheme, &public_keys, GROUP_SIZE)
+                .unwrap()
+                .try_into()
+                .unwrap(),
+            user_count: keys.len() as u32,
+            leaf_count: keys.len().div_ceil(GROUP_SIZE) as u32,
+            group_size: GROUP_SIZE as u32,
+            signature_scheme: scheme.signature_scheme(),
+            voting_start: 0,
+            voting_end: 1,
+            admin_lock_hash: [0; 32],
+            key_image_registry_type_hash: [0; 32],
+            candidates: vec![Candidate {
+                id: [1, 2, 3, 4],
+                description: String::from("candidate"),
+            }],
+        };
+        let range = ring_range(keys.len(), GROUP_SIZE, block);
+        let signer = &keys[range.start];
+        let ring = &public_keys[range];
+        let signature: RingSignature = scheme
+            .sign(
+                ring,
+                signer,
+                0,
+                &ELECTION_ID,
+                &ballot_message(
+                    &ELECTION_ID,
+                    &config.network,
+                    &CONTRACT_CODE_HASH,
+                    &[1, 2, 3, 4],
+                ),
+            )
+            .unwrap()
+            .into();
+        let proof = create_merkle_tree_with_proof(scheme, &public_keys, GROUP_SIZE, block)
+            .unwrap()
+            .proof;
+        (
+            config,
+            VoteCell::new([1, 2, 3, 4], &signature).unwrap(),
+            VoteWitness::new(&signature, block as u32, proof),
+        )
+    }
+
+    /// Checks rejections of tampered votes, the vote must be valid. A flipped bit of the key
+    /// image fails with `tampered_image`, since most compressed points don't decompress
+    fn check_rejections(
+        config: &VoteConfig,
+        cell: &VoteCell,
+        witness: &VoteWitness,
+        tampered_image: VoteError,
+    ) {
+        assert_eq!(
+            verify_vote(&ELECTION_ID, &CONTRACT_CODE_HASH, config, cell, witness),
+            Ok(())
+        );
+
+        let mut other_candidate = cell.clone();
+        other_candidate.candidate_id = [4, 3, 2, 1];
+        assert_eq!(
+            verify_vote(
+                &ELECTION_ID,
+                &CONTRACT_CODE_HASH,
+                config,
+                &other_candidate,
+                witness
+            ),
+            Err(VoteError::BadCandidateId)
+        );
+        // The ring at leaf 0 is larger than the last one, when that is of a single key
+        let ring_size_at = |leaf_index| {
+            ring_size(
+                config.user_count as usize,
+                config.group_size as usize,
+                leaf_index,
+            )
+        };
+        let other_block_error = if ring_size_at(0) == ring_size_at(witness.leaf_index as usize) {
+            VoteError::BadMerkleProof
+        } else {
+            VoteError::UnexpectedRingSize
+        };
+        let mut other_block = witness.clone();
+        other_block.leaf_index = 0;
+        assert_eq!(
+            verify_vote(
+                &ELECTION_ID,
+                &CONTRACT_CODE_HASH,
+                config,
+                cell,
+                &other_block
+            ),
+            Err(other_block_error)
+        );
+        // Key images and ballots are of the election
+        assert_eq!(
+            verify_vote(&[0; 32], &CONTRACT_CODE_HASH, config, cell, witness),
+            Err(VoteError::BadSignature)
+        );
+        // Ballots are of the contract and the network
+        assert_eq!(
+            verify_vote(&ELECTION_ID, &[0; 32], config, cell, witness),
+            Err(VoteError::BadSignature)
+        );
+        let other_network = VoteConfig {
+            network: [0; 32],
+            ..config.clone()
+        };
+        assert_eq!(
+            verify_vote(
+                &ELECTION_ID,
+                &CONTRACT_CODE_HASH,
+                &other_network,
+                cell,
+                witness
+            ),
+            Err(VoteError::BadSignature)
+        );
+        let mut other_image = cell.clone();
+        other_image.image[0] ^= 1;
+        assert_eq!(
+            verify_vote(
+                &ELECTION_ID,
+                &CONTRACT_CODE_HASH,
+                config,
+                &other_image,
+                witness
+            ),
+            Err(tampered_image)
+        );
+    }
+
+    #[test]
+    fn test_verify_vote() {
+        // Votes of RSA elections are refused, even with a valid signature
+        let (config, cell, witness) = signed_vote(&RsaScheme(RsaKeySize::Rsa1024), 1);
+        assert_eq!(
+            verify_vote(&ELECTION_ID, &CONTRACT_CODE_HASH, &config, &cell, &witness),
+            Err(VoteError::UnsupportedSignatureScheme)
+        );
+
+        let mut other_scheme = config.clone();
+        other_scheme.signature_scheme = SignatureScheme::Lsag;
+        assert_eq!(
+            verify_vote(
+                &ELECTION_ID,
+                &CONTRACT_CODE_HASH,
+                &other_scheme,
+                &cell,
+                &witness
+            ),
+            Err(VoteError::BadImageSize)
+        );
+    }
+
+    #[test]
+    fn test_verify_lsag_vote() {
+        // The last ring has a single member
+        let (config, cell, witness) = signed_vote(&LsagScheme, 2);
+        check_rejections(&config, &cell, &witness, VoteError::BadPoint);
+    }
+
+    /// Checks a vote with the keys of its ring in a registry cell instead of the witness, the
+    /// vote must be valid
+    fn check_ring_in_registry_cell(config: &VoteConfig, cell: &VoteCell, witness: &VoteWitness) {
+        let scheme = config.signature_scheme;
+        let ring_keys = match &witness.signature {
+            WitnessSignature::Rsa { .. } => unreachable!("Votes of RSA elections are refused"),
+            WitnessSignature::Lsag { public_keys, .. } => public_keys.concat(),
+        };
+        let registry_cell = |start: u32, public_keys: &[u8]| {
+            schema::PublicKeyRegistryCell::new_builder()
+                .start(start.into())
+                .public_keys(public_keys.into())
+                .build()
+                .as_slice()
+                .to_vec()
+        };
+        // The cell starts at the ring before, so the ring is found by its offset
+        let start = witness.leaf_index * GROUP_SIZE as u32 - GROUP_SIZE as u32;
+        let ring_cell = registry_cell(
+            start,
+            &[
+                vec![0; GROUP_SIZE * scheme.public_key_size()],
+                ring_keys.clone(),
+            ]
+            .concat(),
+        );
+        let decode = |encoded: &[u8], ring_cell: &[u8]| {
+            VoteWitness::decode_with_cell_deps(encoded, config, |index| {
+                assert_eq!(index, 2);
+                Ok(ring_cell.to_vec())
+            })
+        };
+        let error = |result: anyhow::Result<VoteWitness>| {
+            result.unwrap_err().downcast::<VoteError>().unwrap()
+        };
+
+        let referenced = VoteWitness {
+            ring_cell_dep: Some(2),
+            ..witness.clone()
+        };
+        let encoded = referenced.encode(scheme).unwrap();
+        let inline = witness.encode(scheme).unwrap();
+        // Every byte of the keys is saved, less the index of the cell dep
+        assert!(encoded.len() + ring_keys.len() <= inline.len() + 4);
+        assert_eq!(decode(&encoded, &ring_cell).unwrap(), referenced);
+        assert_eq!(
+            verify_vote(
+                &ELECTION_ID,
+                &CONTRACT_CODE_HASH,
+                config,
+                cell,
+                &decode(&encoded, &ring_cell).unwrap()
+            ),
+            Ok(())
+        );
+        // Witnesses with keys decode without cell deps
+        assert_eq!(
+            VoteWitness::decode_with_cell_deps(&inline, config, |_| unreachable!()).unwrap(),
+            *witness
+        );
+        assert!(VoteWitness::decode(&encoded, scheme).is_err());
+
+        assert_eq!(
+            error(decode(&encoded, &ring_cell[..ring_cell.len() - 1])),
+            VoteError::BadPublicKeyRegistry
+        );
+        for ring_cell in [
+            registry_cell(start + 1, &ring_keys),
+            registry_cell(0, &ring_keys),
+            registry_cell(start, &ring_keys[..ring_keys.len() - 1]),
+        ] {
+            assert_eq!(
+                error(decode(&encoded, &ring_cell)),
+                VoteError::RingNotInRegistry
+            );
+        }
+        // Other keys are found, but not in the merkle tree
+        let mut other_keys = ring_cell.clone();
+        let last = other_keys.len() - 1;
+        other_keys[last] ^= 1;
+        assert_eq!(
+            verify_vote(
+                &ELECTION_ID,
+                &CONTRACT_CODE_HASH,
+                config,
+                cell,
+                &decode(&encoded, &other_keys).unwrap()
+            ),
+            Err(VoteError::BadMerkleProof)
+        );
+        // Keys are either in the witness or in the cell
+        let both = schema::VoteWitness::from_slice(&inline)
+            .unwrap()
+            .as_builder()
+            .ring_cell_dep(Uint32Opt::new_builder().set(Some(2u32.into())).build())
+            .build();
+        assert_eq!(
+            error(decode(both.as_slice(), &ring_cell)),
+            VoteError::RingKeysInWitness
+        );
+    }
+
+    #[test]
+    fn test_ring_in_registry_cell() {
+        let (config, cell, witness) = signed_vote(&LsagScheme, 2);
+        check_ring_in_registry_cell(&config, &cell, &witness);
+    }
+
+    #[test]
+    fn test_vectors() {
+        for vector in test_vectors::load() {
+            let config = VoteConfig::decode(&vector.config).unwrap();
+            let cell = VoteCell::decode(&vector.vote_cell, config.signature_scheme).unwrap();
+            let witness = VoteWitness::decode(&vector.witness, config.signature_scheme).unwrap();
+            assert_eq!(
+                verify_vote(
+                    &vector.election_id,
+                    &vector.contract_code_hash,
+                    &config,
+                    &cell,
+                    &witness
+                ),
+                vector.error.map_or(Ok(()), Err),
+                "{}",
+                vector.name
+            );
+        }
+    }
+}
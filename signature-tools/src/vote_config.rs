@@ -0,0 +1,251 @@
+//! Vote config cell
+//!
+//! Everything about an election lives in one cell, typed by the built-in type id script so
+//! it can't be forged. Votes commit to the type hash of this cell in their script args, and the
+//! contract finds the cell among cell deps by that hash.
+//!
+//! Data of the cell is a `VoteConfig` of `vote-types`.
+use anyhow::{anyhow, bail};
+use vote_types::{
+    leaf_count,
+    molecule::prelude::*,
+    schema::{self, CandidateVec, VoteConfigReader},
+    SIGNATURE_SCHEME_LSAG, SIGNATURE_SCHEME_RSA,
+};
+
+use crate::{
+    candidate::{decode_candidates, Candidate},
+    rsa_tools::RsaKeySize,
+};
+
+pub const VOTE_CONFIG_VERSION: u8 = 2;
+/// First byte of script args of vote cells, followed by type hash of the config cell
+pub const SCRIPT_ROLE_VOTE: u8 = 0;
+/// First byte of script args of key image registry cells, followed by the type id
+pub const SCRIPT_ROLE_KEY_IMAGE_REGISTRY: u8 = 1;
+/// First byte of script args of public key registry cells, followed by type hash of the config
+/// cell
+pub const SCRIPT_ROLE_PUBLIC_KEY_REGISTRY: u8 = 2;
+
+/// Ring signature scheme of an election, keys of all users are of this scheme
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureScheme {
+    /// Refused by the contract with `UnsupportedSignatureScheme`, see [`crate::rsa_tools`]
+    Rsa(RsaKeySize),
+    /// LSAG over ristretto255, see [`crate::ec_tools`]
+    Lsag,
+}
+
+/// LSAG, since contracts refuse RSA elections
+impl Default for SignatureScheme {
+    fn default() -> Self {
+        SignatureScheme::Lsag
+    }
+}
+
+impl SignatureScheme {
+    /// `signature_scheme` of the config, which is also the item id of the signature in witnesses
+    pub fn id(self) -> u8 {
+        match self {
+            SignatureScheme::Rsa(_) => SIGNATURE_SCHEME_RSA,
+            SignatureScheme::Lsag => SIGNATURE_SCHEME_LSAG,
+        }
+    }
+
+    /// Size of key images in vote cells
+    pub fn image_size(self) -> usize {
+        match self {
+            SignatureScheme::Rsa(key_size) => key_size.bytes(),
+            SignatureScheme::Lsag => 32,
+        }
+    }
+
+    /// Size of public keys in registry cells, an RSA key carries its exponent in 4 bytes
+    pub fn public_key_size(self) -> usize {
+        match self {
+            SignatureScheme::Rsa(key_size) => key_size.bytes() + 4,
+            SignatureScheme::Lsag => 32,
+        }
+    }
+
+    /// `rsa_key_bits` of the config, which is 0 for LSAG
+    pub fn rsa_key_bits(self) -> u32 {
+        match self {
+            SignatureScheme::Rsa(key_size) => key_size.bits(),
+            SignatureScheme::Lsag => 0,
+        }
+    }
+
+    /// Scheme of `signature_scheme` and `rsa_key_bits` of a config
+    pub fn decode(id: u8, rsa_key_bits: u32) -> anyhow::Result<Self> {
+        match (id, rsa_key_bits) {
+            (SIGNATURE_SCHEME_RSA, bits) => Ok(SignatureScheme::Rsa(RsaKeySize::from_bits(bits)?)),
+            (SIGNATURE_SCHEME_LSAG, 0) => Ok(SignatureScheme::Lsag),
+            _ => bail!(
+                "Unsupported signature scheme {} with RSA key bits {}",
+                id,
+                rsa_key_bits
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VoteConfig {
+    /// Genesis block hash of the chain of the election, which votes sign
+    pub network: [u8; 32],
+    pub merkle_root: [u8; 32],
+    pub user_count: u32,
+    pub leaf_count: u32,
+    /// Size of a ring, users are split into groups of this size in the merkle tree
+    pub group_size: u32,
+    pub signature_scheme: SignatureScheme,
+    /// Votes are accepted in blocks `[voting_start, voting_end)`
+    pub voting_start: u64,
+    pub voting_end: u64,
+    /// Lock hash of the config cell, which is owned by the administrator
+    pub admin_lock_hash: [u8; 32],
+    pub key_image_registry_type_hash: [u8; 32],
+    pub candidates: Vec<Candidate>,
+}
+
+impl VoteConfig {
+    pub fn encode(&self) -> Vec<u8> {
+        schema::VoteConfig::new_builder()
+            .version(Byte::new(VOTE_CONFIG_VERSION))
+            .network(self.network.into())
+            .merkle_root(self.merkle_root.into())
+            .user_count(self.user_count.into())
+            .leaf_count(self.leaf_count.into())
+            .group_size(self.group_size.into())
+            .signature_scheme(Byte::new(self.signature_scheme.id()))
+            .rsa_key_bits(self.signature_scheme.rsa_key_bits().into())
+            .voting_start(self.voting_start.into())
+            .voting_end(self.voting_end.into())
+            .admin_lock_hash(self.admin_lock_hash.into())
+            .key_image_registry_type_hash(self.key_image_registry_type_hash.into())
+            .candidates(
+                CandidateVec::new_builder()
+                    .set(self.candidates.iter().map(Candidate::to_schema).collect())
+                    .build(),
+            )
+            .build()
+            .as_slice()
+            .to_vec()
+    }
+
+    pub fn decode(buf: &[u8]) -> anyhow::Result<Self> {
+        let reader =
+            VoteConfigReader::from_slice(buf).map_err(|e| anyhow!("Bad vote config: {}", e))?;
+        let version = reader.version().as_slice()[0];
+        if version != VOTE_CONFIG_VERSION {
+            bail!("Unsupported vote config version: {}", version);
+        }
+        let user_count = u32::from(reader.user_count()) as usize;
+        let group_size = u32::from(reader.group_size()) as usize;
+        // Same as the contract, rings are the partition of all keys by the group size
+        if group_size == 0
+            || u32::from(reader.leaf_count()) as usize != leaf_count(user_count, group_size)
+        {
+            bail!("Leaf count doesn't match user count and group size");
+        }
+        Ok(Self {
+            network: reader.network().into(),
+            merkle_root: reader.merkle_root().into(),
+            user_count: reader.user_count().into(),
+            leaf_count: reader.leaf_count().into(),
+            group_size: reader.group_size().into(),
+            signature_scheme: SignatureScheme::decode(
+                reader.signature_scheme().as_slice()[0],
+                reader.rsa_key_bits().into(),
+            )?,
+            voting_start: reader.voting_start().into(),
+            voting_end: reader.voting_end().into(),
+            admin_lock_hash: reader.admin_lock_hash().into(),
+            key_image_registry_type_hash: reader.key_image_registry_type_hash().into(),
+            candidates: decode_candidates(reader.candidates())?,
+        })
+    }
+}
+
+/// Script args of vote cells belonging to the election with the given config
+pub fn vote_script_args(config_type_hash: &[u8; 32]) -> Vec<u8> {
+    let mut args = vec![SCRIPT_ROLE_VOTE];
+    args.extend_from_slice(config_type_hash);
+    args
+}
+
+/// Script args of the key image registry cell with the given type id
+pub fn key_image_registry_script_args(type_id: &[u8; 32]) -> Vec<u8> {
+    let mut args = vec![SCRIPT_ROLE_KEY_IMAGE_REGISTRY];
+    args.extend_from_slice(type_id);
+    args
+}
+
+/// Script args of public key registry cells of the election with the given config
+pub fn public_key_registry_script_args(config_type_hash: &[u8; 32]) -> Vec<u8> {
+    let mut args = vec![SCRIPT_ROLE_PUBLIC_KEY_REGISTRY];
+    args.extend_from_slice(config_type_hash);
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{thread_rng, Rng};
+
+    use super::{SignatureScheme, VoteConfig};
+    use crate::{candidate::Candidate, rsa_tools::RsaKeySize};
+
+    #[test]
+    fn test_vote_config_encoding() {
+        let mut rng = thread_rng();
+        let config = VoteConfig {
+            network: rng.gen(),
+            merkle_root: rng.gen(),
+            user_count: 100,
+            leaf_count: 7,
+            group_size: 15,
+            signature_scheme: SignatureScheme::Rsa(RsaKeySize::Rsa3072),
+            voting_start: 1000,
+            voting_end: 2000,
+            admin_lock_hash: rng.gen(),
+            key_image_registry_type_hash: rng.gen(),
+            candidates: (0..10)
+                .map(|idx| Candidate {
+                    id: rng.gen(),
+                    description: format!("候选人 {}", idx),
+                })
+                .collect(),
+        };
+        let encoded = config.encode();
+        assert_eq!(VoteConfig::decode(&encoded).unwrap(), config);
+        assert!(VoteConfig::decode(&encoded[..encoded.len() - 1]).is_err());
+        let mut bad_version = encoded.clone();
+        // Version is the first field, right after the header of the table
+        bad_version[4 * 14] = 0;
+        assert!(VoteConfig::decode(&bad_version).is_err());
+        // Offsets of `signature_scheme` and `rsa_key_bits`, the 7th and 8th fields, are in the
+        // header
+        let field_offset = |index: usize| {
+            u32::from_le_bytes(encoded[4 * index..4 * (index + 1)].try_into().unwrap()) as usize
+        };
+        let (scheme_offset, key_bits_offset) = (field_offset(7), field_offset(8));
+        let mut bad_key_size = encoded.clone();
+        bad_key_size[key_bits_offset..key_bits_offset + 4].copy_from_slice(&1000u32.to_le_bytes());
+        assert!(VoteConfig::decode(&bad_key_size).is_err());
+        let mut bad_scheme = encoded.clone();
+        bad_scheme[scheme_offset] = 2;
+        assert!(VoteConfig::decode(&bad_scheme).is_err());
+
+        let lsag_config = VoteConfig {
+            signature_scheme: SignatureScheme::Lsag,
+            ..config
+        };
+        let encoded = lsag_config.encode();
+        assert_eq!(VoteConfig::decode(&encoded).unwrap(), lsag_config);
+        // RSA key size must be zero for LSAG
+        let mut bad_key_size = encoded.clone();
+        bad_key_size[key_bits_offset..key_bits_offset + 4].copy_from_slice(&2048u32.to_le_bytes());
+        assert!(VoteConfig::decode(&bad_key_size).is_err());
+    }
+}
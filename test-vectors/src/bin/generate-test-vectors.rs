@@ -0,0 +1,468 @@
+//! Print `vectors.json`, which is the same every run since keys and signatures are drawn from a
+//! rng with a fixed seed
+use std::ops::Range;
+
+use ckb_types::{bytes::Bytes, core::ScriptHashType, h256, packed::Script, prelude::*, H256};
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use signature_tools::{
+    candidate::Candidate,
+    ec_tools::LsagScheme,
+    merkle_tree::{create_merkle_tree_with_proof, create_merkle_tree_with_root_hash},
+    ring_signature::RingSignatureScheme,
+    rsa_tools::{create_signature_with_image, PrivateKeyParts, RsaKeySize, RsaScheme},
+    vote::{ballot_message, RingSignature, VoteCell, VoteWitness, WitnessSignature},
+    vote_config::VoteConfig,
+};
+use test_vectors::{TestVector, TestVectors, VoteError, VERSION};
+
+const SEED: [u8; 32] = *b"ckb-vote known answer test seed!";
+/// Type id of the config cell of every election of the vectors
+const CONFIG_TYPE_ID: [u8; 32] = *b"ckb-vote known answer election!!";
+/// Type id of the config cell of another election with the same keys
+const OTHER_CONFIG_TYPE_ID: [u8; 32] = *b"ckb-vote another known election!";
+/// Type id of the cell of the contract, whose type hash is the code hash of vote cells
+const CONTRACT_TYPE_ID: [u8; 32] = *b"ckb-vote known answer contract!!";
+/// Genesis block hash in the config of every election of the vectors
+const NETWORK: [u8; 32] = *b"ckb-vote known answer network!!!";
+const TYPE_ID_CODE_HASH: H256 =
+    h256!("0x00000000000000000000000000000000000000000000000000545950455f4944");
+
+/// Type hash of a cell typed by the built-in type id script, which is the election id of a config
+/// cell, or the code hash of vote cells for the cell of the contract
+fn type_id_hash(type_id: &[u8; 32]) -> [u8; 32] {
+    Script::new_builder()
+        .code_hash(TYPE_ID_CODE_HASH.pack())
+        .hash_type(ScriptHashType::Type.into())
+        .args(Bytes::copy_from_slice(type_id).pack())
+        .build()
+        .calc_script_hash()
+        .unpack()
+}
+
+/// Where a vote is cast, which its ballot message binds the candidate to
+#[derive(Clone, Copy)]
+struct Ballot {
+    config_type_id: [u8; 32],
+    network: [u8; 32],
+    contract_type_id: [u8; 32],
+}
+
+/// Ballot of every election of the vectors
+const BALLOT: Ballot = Ballot {
+    config_type_id: CONFIG_TYPE_ID,
+    network: NETWORK,
+    contract_type_id: CONTRACT_TYPE_ID,
+};
+
+impl Ballot {
+    fn election_id(&self) -> [u8; 32] {
+        type_id_hash(&self.config_type_id)
+    }
+
+    fn message(&self, candidate_id: &[u8; 4]) -> Vec<u8> {
+        ballot_message(
+            &self.election_id(),
+            &self.network,
+            &type_id_hash(&self.contract_type_id),
+            candidate_id,
+        )
+        .to_vec()
+    }
+}
+
+const KEY_COUNT: usize = 7;
+/// Rings of 3, 3 and 1 keys
+const GROUP_SIZE: usize = 3;
+const CANDIDATE: [u8; 4] = *b"yes!";
+const OTHER_CANDIDATE: [u8; 4] = *b"no!!";
+const UNKNOWN_CANDIDATE: [u8; 4] = *b"????";
+
+struct Election<S: RingSignatureScheme> {
+    name: &'static str,
+    scheme: S,
+    keys: Vec<S::PrivateKey>,
+    public_keys: Vec<S::PublicKey>,
+    config: VoteConfig,
+    rng: ChaCha20Rng,
+}
+
+impl<S: RingSignatureScheme> Election<S> {
+    /// Keys and signatures of an election are drawn from stream `stream` of the rng
+    fn new(name: &'static str, scheme: S, stream: u64) -> Self {
+        let mut rng = ChaCha20Rng::from_seed(SEED);
+        rng.set_stream(stream);
+        let keys = (0..KEY_COUNT)
+            .map(|_| scheme.generate_key(&mut rng).unwrap())
+            .collect::<Vec<_>>();
+        let public_keys = keys
+            .iter()
+            .map(|key| scheme.public_key(key))
+            .collect::<Vec<_>>();
+        let config = VoteConfig {
+            network: NETWORK,
+            merkle_root: create_merkle_tree_with_root_hash(&scheme, &public_keys, GROUP_SIZE)
+                .unwrap()
+                .try_into()
+                .unwrap(),
+            user_count: KEY_COUNT as u32,
+            leaf_count: KEY_COUNT.div_ceil(GROUP_SIZE) as u32,
+            group_size: GROUP_SIZE as u32,
+            signature_scheme: scheme.signature_scheme(),
+            voting_start: 0,
+            voting_end: u64::MAX,
+            admin_lock_hash: [0; 32],
+            key_image_registry_type_hash: [0; 32],
+            candidates: [CANDIDATE, OTHER_CANDIDATE]
+                .into_iter()
+                .map(|id| Candidate {
+                    id,
+                    description: String::from_utf8(id.to_vec()).unwrap(),
+                })
+                .collect(),
+        };
+        Self {
+            name,
+            scheme,
+            keys,
+            public_keys,
+            config,
+            rng,
+        }
+    }
+
+    /// Vote of the key at `signer`, signed over the keys in `ring`, with the merkle proof of
+    /// the leaf at `leaf_index`
+    fn vote(
+        &mut self,
+        signer: usize,
+        ring: Range<usize>,
+        leaf_index: usize,
+        candidate_id: [u8; 4],
+    ) -> (VoteCell, VoteWitness) {
+        self.vote_for(BALLOT, signer, ring, leaf_index, candidate_id)
+    }
+
+    /// Same as [`Election::vote`], signed for `ballot`
+    fn vote_for(
+        &mut self,
+        ballot: Ballot,
+        signer: usize,
+        ring: Range<usize>,
+        leaf_index: usize,
+        candidate_id: [u8; 4],
+    ) -> (VoteCell, VoteWitness) {
+        let signature: RingSignature = self
+            .scheme
+            .sign_with_rng(
+                &self.public_keys[ring.clone()],
+                &self.keys[signer],
+                signer - ring.start,
+                &ballot.election_id(),
+                &ballot.message(&candidate_id),
+                &mut self.rng,
+            )
+            .unwrap()
+            .into();
+        let proof =
+            create_merkle_tree_with_proof(&self.scheme, &self.public_keys, GROUP_SIZE, leaf_index)
+                .unwrap()
+                .proof;
+        (
+            VoteCell::new(candidate_id, &signature).unwrap(),
+            VoteWitness::new(&signature, leaf_index as u32, proof),
+        )
+    }
+
+    fn vector(
+        &self,
+        name: &str,
+        config: &VoteConfig,
+        (cell, witness): &(VoteCell, VoteWitness),
+        error: Option<VoteError>,
+    ) -> TestVector {
+        TestVector {
+            name: format!("{}: {}", self.name, name),
+            election_id: BALLOT.election_id(),
+            config_type_id: CONFIG_TYPE_ID,
+            contract_code_hash: type_id_hash(&CONTRACT_TYPE_ID),
+            contract_type_id: CONTRACT_TYPE_ID,
+            config: config.encode(),
+            vote_cell: cell.encode(),
+            witness: witness.encode(config.signature_scheme).unwrap(),
+            error,
+            exit_code: error.map_or(0, VoteError::code),
+        }
+    }
+
+    /// Vectors which apply to every scheme
+    fn vectors(&mut self) -> Vec<TestVector> {
+        let config = self.config.clone();
+        let vote = self.vote(1, 0..3, 0, CANDIDATE);
+        let last_ring_vote = self.vote(6, 6..7, 2, CANDIDATE);
+        let unknown_candidate_vote = self.vote(1, 0..3, 0, UNKNOWN_CANDIDATE);
+        let missing_key_vote = self.vote(1, 0..2, 0, CANDIDATE);
+        let other_election_vote = self.vote_for(
+            Ballot {
+                config_type_id: OTHER_CONFIG_TYPE_ID,
+                ..BALLOT
+            },
+            1,
+            0..3,
+            0,
+            CANDIDATE,
+        );
+        let other_network_vote = self.vote_for(
+            Ballot {
+                network: [0; 32],
+                ..BALLOT
+            },
+            1,
+            0..3,
+            0,
+            CANDIDATE,
+        );
+        let other_contract_vote = self.vote_for(
+            Ballot {
+                contract_type_id: [0; 32],
+                ..BALLOT
+            },
+            1,
+            0..3,
+            0,
+            CANDIDATE,
+        );
+        let other_election_image_vote = (
+            VoteCell {
+                image: other_election_vote.0.image.clone(),
+                ..vote.0.clone()
+            },
+            vote.1.clone(),
+        );
+        let other_image_vote = (
+            VoteCell {
+                image: self.vote(2, 0..3, 0, CANDIDATE).0.image,
+                ..vote.0.clone()
+            },
+            vote.1.clone(),
+        );
+        let with_witness = |change: fn(&mut VoteWitness)| {
+            let mut witness = vote.1.clone();
+            change(&mut witness);
+            (vote.0.clone(), witness)
+        };
+        let with_cell = |change: fn(&mut VoteCell)| {
+            let mut cell = vote.0.clone();
+            change(&mut cell);
+            (cell, vote.1.clone())
+        };
+        let small_groups = VoteConfig {
+            group_size: GROUP_SIZE as u32 - 1,
+            leaf_count: KEY_COUNT.div_ceil(GROUP_SIZE - 1) as u32,
+            ..config.clone()
+        };
+        use VoteError::*;
+        vec![
+            self.vector("valid", &config, &vote, None),
+            self.vector(
+                "valid in the last ring, of a single key",
+                &config,
+                &last_ring_vote,
+                None,
+            ),
+            self.vector(
+                "tampered c",
+                &config,
+                &with_witness(|witness| tamper_c(&mut witness.signature)),
+                Some(BadSignature),
+            ),
+            self.vector(
+                "tampered r",
+                &config,
+                &with_witness(|witness| tamper_r(&mut witness.signature)),
+                Some(BadSignature),
+            ),
+            self.vector(
+                "key image of another key",
+                &config,
+                &other_image_vote,
+                Some(BadSignature),
+            ),
+            self.vector(
+                "vote of the same key in another election",
+                &config,
+                &other_election_vote,
+                Some(BadSignature),
+            ),
+            self.vector(
+                "key image of the same key in another election",
+                &config,
+                &other_election_image_vote,
+                Some(BadSignature),
+            ),
+            self.vector(
+                "vote signed for another network",
+                &config,
+                &other_network_vote,
+                Some(BadSignature),
+            ),
+            self.vector(
+                "vote signed for another deployment of the contract",
+                &config,
+                &other_contract_vote,
+                Some(BadSignature),
+            ),
+            self.vector(
+                "signature of another candidate",
+                &config,
+                &with_cell(|cell| cell.candidate_id = OTHER_CANDIDATE),
+                Some(BadSignature),
+            ),
+            self.vector(
+                "unknown candidate",
+                &config,
+                &unknown_candidate_vote,
+                Some(BadCandidateId),
+            ),
+            self.vector(
+                "empty ring",
+                &config,
+                &with_witness(|witness| clear_ring(&mut witness.signature)),
+                Some(EmptyRing),
+            ),
+            self.vector(
+                "ring larger than the group size",
+                &small_groups,
+                &vote,
+                Some(RingTooLarge),
+            ),
+            self.vector(
+                "ring missing a key",
+                &config,
+                &missing_key_vote,
+                Some(UnexpectedRingSize),
+            ),
+            self.vector(
+                "tampered merkle proof",
+                &config,
+                &with_witness(|witness| witness.merkle_proof[0] ^= 1),
+                Some(BadMerkleProof),
+            ),
+            self.vector(
+                "wrong leaf index",
+                &config,
+                &with_witness(|witness| witness.leaf_index = 1),
+                Some(BadMerkleProof),
+            ),
+            self.vector(
+                "leaf index with bits above the height of the tree",
+                &config,
+                &with_witness(|witness| witness.leaf_index += 1 << 8),
+                Some(UnexpectedRingSize),
+            ),
+        ]
+    }
+}
+
+impl Election<RsaScheme> {
+    /// Votes of RSA elections, which are refused whatever the signature. A key image is only
+    /// checked to be a multiple of a prime `p` of the signer, so images `p` and `2p` both
+    /// verify, which would let a key vote twice
+    fn refused_vectors(&mut self) -> Vec<TestVector> {
+        let config = self.config.clone();
+        let vote = self.vote(1, 0..3, 0, CANDIDATE);
+        let p = self.keys[1].primes()[0].clone();
+        let [p_image, double_p_image] = [p.clone(), p * 2u32].map(|image| {
+            let signature = create_signature_with_image(
+                &self.public_keys[0..3],
+                &self.keys[1],
+                1,
+                &BALLOT.election_id(),
+                &BALLOT.message(&CANDIDATE),
+                image,
+                &mut self.rng,
+            )
+            .unwrap()
+            .into();
+            (
+                VoteCell::new(CANDIDATE, &signature).unwrap(),
+                VoteWitness::new(&signature, 0, vote.1.merkle_proof.clone()),
+            )
+        });
+        use VoteError::*;
+        vec![
+            self.vector(
+                "valid signature",
+                &config,
+                &vote,
+                Some(UnsupportedSignatureScheme),
+            ),
+            self.vector(
+                "key image p, a prime of the signer",
+                &config,
+                &p_image,
+                Some(UnsupportedSignatureScheme),
+            ),
+            self.vector(
+                "key image 2p",
+                &config,
+                &double_p_image,
+                Some(UnsupportedSignatureScheme),
+            ),
+        ]
+    }
+}
+
+fn tamper_c(signature: &mut WitnessSignature) {
+    match signature {
+        WitnessSignature::Rsa { c, .. } => *c += 1u32,
+        WitnessSignature::Lsag { c, .. } => c[0] ^= 1,
+    }
+}
+
+fn tamper_r(signature: &mut WitnessSignature) {
+    match signature {
+        WitnessSignature::Rsa { r_and_pubkey, .. } => r_and_pubkey[0].r += 1u32,
+        WitnessSignature::Lsag { r, .. } => r[0][0] ^= 1,
+    }
+}
+
+fn clear_ring(signature: &mut WitnessSignature) {
+    match signature {
+        WitnessSignature::Rsa { r_and_pubkey, .. } => r_and_pubkey.clear(),
+        WitnessSignature::Lsag { r, public_keys, .. } => {
+            r.clear();
+            public_keys.clear();
+        }
+    }
+}
+
+fn main() {
+    let mut rsa = Election::new("RSA 1024", RsaScheme(RsaKeySize::Rsa1024), 0);
+    let mut lsag = Election::new("LSAG", LsagScheme, 1);
+    let mut vectors = rsa.refused_vectors();
+    vectors.extend(lsag.vectors());
+    let (mut cell, witness) = lsag.vote(1, 0..3, 0, CANDIDATE);
+    cell.image = vec![0xff; 32];
+    vectors.push(lsag.vector(
+        "key image which is not a point",
+        &lsag.config,
+        &(cell, witness),
+        Some(VoteError::BadPoint),
+    ));
+    let (cell, mut witness) = lsag.vote(1, 0..3, 0, CANDIDATE);
+    if let WitnessSignature::Lsag { r, .. } = &mut witness.signature {
+        r[0] = [0xff; 32];
+    }
+    vectors.push(lsag.vector(
+        "non-canonical r",
+        &lsag.config,
+        &(cell, witness),
+        Some(VoteError::NonCanonicalInteger),
+    ));
+    let vectors = TestVectors {
+        version: VERSION,
+        vectors,
+    };
+    println!("{}", serde_json::to_string_pretty(&vectors).unwrap());
+}
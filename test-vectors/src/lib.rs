@@ -0,0 +1,59 @@
+//! Known answer vectors of votes, shared by every implementation of vote verification
+//!
+//! A vector is the config of an election, a vote cell, and the `VoteWitness` in `output_type` of
+//! its witness, with the error the contract must reject the vote with, or none if the vote is
+//! valid. Vectors are generated from a fixed seed by `generate-test-vectors`, run
+//! `make test-vectors` to regenerate `vectors.json`.
+use serde::{Deserialize, Serialize};
+pub use vote_types::VoteError;
+
+/// Version of the format of `vectors.json`, which is bumped on incompatible changes
+pub const VERSION: u32 = 4;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestVector {
+    pub name: String,
+    /// Type hash of the config cell, which key images are scoped to
+    #[serde(with = "hex")]
+    pub election_id: [u8; 32],
+    /// Args of the type id script of the config cell, whose script hash is `election_id`, so
+    /// harnesses running transactions deploy the config cell with it
+    #[serde(with = "hex")]
+    pub config_type_id: [u8; 32],
+    /// Code hash of the type script of vote cells, which votes sign
+    #[serde(with = "hex")]
+    pub contract_code_hash: [u8; 32],
+    /// Args of the type id script of the cell of the contract, whose script hash is
+    /// `contract_code_hash`, so harnesses running transactions deploy the contract with it
+    #[serde(with = "hex")]
+    pub contract_type_id: [u8; 32],
+    /// `VoteConfig`, whose admin lock hash, key image registry type hash and voting period are
+    /// placeholders, which harnesses running transactions replace with their own
+    #[serde(with = "hex")]
+    pub config: Vec<u8>,
+    #[serde(with = "hex")]
+    pub vote_cell: Vec<u8>,
+    #[serde(with = "hex")]
+    pub witness: Vec<u8>,
+    /// Named after the variant of `VoteError`
+    pub error: Option<VoteError>,
+    /// Exit code of the contract, 0 for valid votes
+    pub exit_code: i8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestVectors {
+    pub version: u32,
+    pub vectors: Vec<TestVector>,
+}
+
+/// Vectors of `vectors.json`
+pub fn load() -> Vec<TestVector> {
+    let vectors: TestVectors =
+        serde_json::from_str(include_str!("../vectors.json")).expect("Bad vectors.json");
+    assert_eq!(
+        vectors.version, VERSION,
+        "Unsupported version of vectors.json"
+    );
+    vectors.vectors
+}
@@ -6,33 +6,41 @@ use std::{
 use anyhow::{anyhow, bail, Context};
 use ckb_jsonrpc_types::JsonBytes;
 use ckb_sdk::{
-    rpc::{ckb_indexer::Order, ResponseFormatGetter},
-    traits::{CellQueryOptions, PrimaryScriptType},
+    constants::TYPE_ID_CODE_HASH,
+    rpc::{
+        ckb_indexer::{CellType, Order, SearchMode, Tx, TxWithCell},
+        ResponseFormatGetter,
+    },
+    traits::{CellQueryOptions, PrimaryScriptType, ValueRangeOption},
     CkbRpcClient,
 };
 use ckb_types::{
     core::ScriptHashType,
     packed::Byte,
-    prelude::{hex_string, Builder},
+    prelude::{hex_string, Builder, Pack, Unpack},
 };
 use ckb_types::{
-    packed::{Byte32, Script},
+    packed::{Byte32, Script, WitnessArgs},
     prelude::Entity,
     H256,
 };
 use clap::Parser;
-use frozenset::{Freeze, FrozenMap};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use signature_tools::{
+    ec_tools::LsagScheme,
+    public_key_registry::rebuild_rings,
+    ring_assignment::{check_ring_assignment, ring_seed_block},
+    ring_signature::RingSignatureScheme,
+    vote::{verify_vote, VoteCell, VoteError, VoteWitness},
+    vote_config::{public_key_registry_script_args, vote_script_args, SignatureScheme, VoteConfig},
+};
 #[derive(Parser, Debug)]
 struct Args {
-    /// Cell containing information of all candidatex, in hex format
+    /// Tx hash of vote config cell, in hex format, which identifies the election
     #[arg(short = 'c')]
-    candidate_cell_tx: String,
-    // Tx hash of public key index cell, in hex format
-    #[arg(short = 'm')]
-    merkle_tree_root_cell_tx: String,
+    config_cell_tx: String,
 
-    /// Script hash for vote cells
+    /// Code hash of the vote contract, votes found with it are still verified again here
     #[arg(long = "tx", short = 't')]
     signature_verify_type_script_hash: String,
     // URL of ckb node
@@ -40,54 +48,222 @@ struct Args {
     rpc_url: String,
 }
 
-fn parse_candidate_cell(buf: &[u8]) -> anyhow::Result<HashMap<[u8; 4], String>> {
-    let mut result = HashMap::new();
-    let n = u16::from_le_bytes([buf[0], buf[1]]) as usize;
-    for i in 0..n {
-        let start = 2 + i * 104;
-        let c = &buf[start..start + 104];
-        let id = [c[0], c[1], c[2], c[3]];
-        let mut last_idx = 4 + 100 - 1;
-        while last_idx >= 4 && c[last_idx] == 0 {
-            last_idx -= 1;
-        }
-        let desc = String::from_utf8(c[4..last_idx + 1].to_vec())
-            .with_context(|| anyhow!("Bad utf8 bytes for candidate index {}", i))?
-            .to_string();
-        result.insert(id, desc);
-    }
-    Ok(result)
-}
-
 struct VoteValidator {
-    // pub_key_cells: FrozenSet<PublicKeyCellEntry>,
-    candidate: FrozenMap<[u8; 4], String>,
-    merkle_tree_root_cell_tx: (H256, u32),
+    config: VoteConfig,
+    /// Type hash of the config cell, which key images are scoped to
+    config_type_hash: [u8; 32],
+    /// Code hash of the vote contract, which votes sign
+    contract_code_hash: [u8; 32],
 }
 
 impl VoteValidator {
-    pub fn validate_tx(&self, tx: &ckb_jsonrpc_types::Transaction) -> anyhow::Result<()> {
-        let cell_dep_2 = tx.cell_deps.get(1).ok_or_else(|| {
-            anyhow!("Missing second celldep, which should be merkle tree root cell")
-        })?;
-        if self.merkle_tree_root_cell_tx.0 != cell_dep_2.out_point.tx_hash
-            || self.merkle_tree_root_cell_tx.1 != cell_dep_2.out_point.index.value()
-        {
-            bail!("Bad merkle tree root cell");
-        }
+    /// Verify the vote at `index` in the same way as the contract does, the registry cell with
+    /// the keys of the ring is fetched with `client` if the witness references one
+    pub fn validate_tx(
+        &self,
+        client: &CkbRpcClient,
+        tx: &ckb_jsonrpc_types::Transaction,
+        index: usize,
+    ) -> anyhow::Result<VoteTarget> {
         let vote_cell_data = &tx
             .outputs_data
-            .get(0)
-            .ok_or_else(|| anyhow!("Missing output data 0"))?
+            .get(index)
+            .ok_or_else(|| anyhow!("Missing output data {}", index))?
+            .as_bytes();
+        let vote_cell = VoteCell::decode(vote_cell_data, self.config.signature_scheme)?;
+        let witness_data = tx
+            .witnesses
+            .get(index)
+            .ok_or_else(|| anyhow!("Missing witness {}", index))?
             .as_bytes();
-        let candidate_id: [u8; 4] = vote_cell_data[0..4].try_into().unwrap();
-        if !self.candidate.contains_key(&candidate_id) {
-            bail!("Invalid candidate id: {:?}", candidate_id);
+        let witness_args =
+            WitnessArgs::from_slice(witness_data).map_err(|_| VoteError::BadWitnessArgs)?;
+        let witness = VoteWitness::decode_with_cell_deps(
+            &witness_args
+                .output_type()
+                .to_opt()
+                .ok_or(VoteError::MissingVoteWitness)?
+                .raw_data(),
+            &self.config,
+            |dep_index| {
+                let out_point = &tx
+                    .cell_deps
+                    .get(dep_index as usize)
+                    .ok_or(VoteError::IndexOutOfBound)?
+                    .out_point;
+                let dep_tx = client
+                    .get_transaction(out_point.tx_hash.clone())?
+                    .and_then(|tx| tx.transaction)
+                    .ok_or_else(|| anyhow!("Missing transaction of cell dep {}", dep_index))?
+                    .get_value()?
+                    .inner;
+                Ok(dep_tx
+                    .outputs_data
+                    .get(out_point.index.value() as usize)
+                    .ok_or(VoteError::IndexOutOfBound)?
+                    .as_bytes()
+                    .to_vec())
+            },
+        )?;
+        verify_vote(
+            &self.config_type_hash,
+            &self.contract_code_hash,
+            &self.config,
+            &vote_cell,
+            &witness,
+        )?;
+
+        Ok(VoteTarget {
+            candidate_id: vote_cell.candidate_id,
+            image: vote_cell.image,
+        })
+    }
+}
+
+/// The built-in type id script lets the administrator replace the config cell at any time, and
+/// the contract verifies votes against whichever config is live. Votes are counted against the
+/// config created by the transaction at `(block number, index in the block)`, so the count is
+/// refused if that config was consumed before the end of voting
+fn check_config_unchanged(
+    client: &CkbRpcClient,
+    config_type: &Script,
+    (config_block, config_tx_index): (u64, u32),
+    voting_end: u64,
+) -> anyhow::Result<()> {
+    let mut query = CellQueryOptions::new(config_type.clone(), PrimaryScriptType::Type);
+    query.script_search_mode = Some(SearchMode::Exact);
+    query.block_range = Some(ValueRangeOption::new(config_block, voting_end));
+    let mut last_cursor: Option<JsonBytes> = None;
+    loop {
+        let page = client
+            .get_transactions(query.clone().into(), Order::Asc, 100.into(), last_cursor)
+            .with_context(|| anyhow!("Failed to get transactions of the config cell"))?;
+        if page.objects.is_empty() {
+            return Ok(());
+        }
+        // Only one cell has the type id, so any input of it after the transaction creating the
+        // config, which consumed the previous config if any, replaces or destroys the config
+        if let Some(Tx::Ungrouped(spent)) = page.objects.iter().find(|item| {
+            matches!(
+                item,
+                Tx::Ungrouped(TxWithCell {
+                    io_type: CellType::Input,
+                    block_number,
+                    tx_index,
+                    ..
+                }) if (block_number.value(), tx_index.value()) > (config_block, config_tx_index)
+            )
+        }) {
+            bail!(
+                "Config cell was consumed by {:#x} at block {}, before the end of voting at {}",
+                spent.tx_hash,
+                spent.block_number.value(),
+                voting_end
+            );
         }
+        last_cursor = Some(page.last_cursor);
+    }
+}
 
-        Ok(())
+/// Data of the live public key registry cells of the election
+fn public_key_registry_cells(
+    client: &CkbRpcClient,
+    contract_code_hash: &H256,
+    config_type_hash: &[u8; 32],
+) -> anyhow::Result<Vec<Vec<u8>>> {
+    let mut query = CellQueryOptions::new(
+        Script::new_builder()
+            .code_hash(Byte32::from_slice(contract_code_hash.as_bytes())?)
+            .args(public_key_registry_script_args(config_type_hash).pack())
+            .hash_type(Byte::new(ScriptHashType::Data1 as u8))
+            .build(),
+        PrimaryScriptType::Type,
+    );
+    query.script_search_mode = Some(SearchMode::Exact);
+    query.with_data = Some(true);
+    let mut cells = vec![];
+    let mut last_cursor: Option<JsonBytes> = None;
+    loop {
+        let page = client
+            .get_cells(query.clone().into(), Order::Asc, 100.into(), last_cursor)
+            .with_context(|| anyhow!("Failed to get public key registry cells"))?;
+        if page.objects.is_empty() {
+            break;
+        }
+        cells.extend(
+            page.objects
+                .into_iter()
+                .filter_map(|cell| cell.output_data)
+                .map(|data| data.into_bytes().to_vec()),
+        );
+        last_cursor = Some(page.last_cursor);
     }
+    Ok(cells)
+}
+
+/// Config of the transaction which created the config cell typed by `config_type`, which commits
+/// to the keys of the election, and the block of that transaction
+fn first_config(client: &CkbRpcClient, config_type: &Script) -> anyhow::Result<(VoteConfig, u64)> {
+    let mut query = CellQueryOptions::new(config_type.clone(), PrimaryScriptType::Type);
+    query.script_search_mode = Some(SearchMode::Exact);
+    let page = client
+        .get_transactions(query.into(), Order::Asc, 1.into(), None)
+        .with_context(|| anyhow!("Failed to get transactions of the config cell"))?;
+    // The type id is only created once, so the first transaction with it creates the cell
+    let Some(Tx::Ungrouped(TxWithCell {
+        tx_hash,
+        block_number,
+        io_index,
+        io_type: CellType::Output,
+        ..
+    })) = page.objects.first()
+    else {
+        bail!("Transaction creating the config cell not found");
+    };
+    let tx = client
+        .get_transaction(tx_hash.clone())
+        .with_context(|| anyhow!("Unable to get the first config cell tx"))?
+        .and_then(|response| response.transaction)
+        .ok_or_else(|| anyhow!("Transaction body not found"))?
+        .get_value()?
+        .inner;
+    let config = VoteConfig::decode(
+        tx.outputs_data
+            .get(io_index.value() as usize)
+            .ok_or_else(|| anyhow!("Missing output data of the first config cell"))?
+            .as_bytes(),
+    )
+    .with_context(|| anyhow!("Failed to decode the first config cell"))?;
+    Ok((config, block_number.value()))
+}
+
+/// Hash of the block seeding the shuffle of an election whose config cell was created in
+/// `config_block`
+fn ring_seed(client: &CkbRpcClient, config_block: u64) -> anyhow::Result<[u8; 32]> {
+    let block = ring_seed_block(config_block);
+    let header = client
+        .get_header_by_number(block.into())
+        .with_context(|| anyhow!("Failed to get block {}", block))?
+        .ok_or_else(|| anyhow!("Block {} seeding the rings isn't mined yet", block))?;
+    Ok(header.hash.0)
+}
+
+/// Rebuild all keys of the election from its registry cells, and check that they are the keys
+/// committed to by `first_config`, assigned to rings by the shuffle seeded with `seed`
+fn audit_rings<S: RingSignatureScheme>(
+    scheme: &S,
+    config: &VoteConfig,
+    first_config: &VoteConfig,
+    seed: &[u8; 32],
+    cells: &[Vec<u8>],
+) -> anyhow::Result<()> {
+    let public_keys = rebuild_rings(scheme, config, cells.iter().map(Vec::as_slice))?
+        .into_iter()
+        .flat_map(|ring| ring.public_keys)
+        .collect::<Vec<_>>();
+    check_ring_assignment(scheme, &public_keys, first_config, seed)
 }
+
 #[derive(Debug)]
 
 struct VoteTarget {
@@ -95,6 +271,13 @@ struct VoteTarget {
     image: Vec<u8>,
 }
 
+/// Outcome of the vote in output `index` of a transaction
+struct VerifiedVote {
+    tx_hash: H256,
+    index: usize,
+    result: anyhow::Result<VoteTarget>,
+}
+
 fn main() -> anyhow::Result<()> {
     flexi_logger::Logger::try_with_env_or_str("info")
         .with_context(|| anyhow!("Failed to initialize logger"))?
@@ -102,87 +285,131 @@ fn main() -> anyhow::Result<()> {
         .with_context(|| anyhow!("Failed to start logger"))?;
     let args = Args::parse();
     let client = CkbRpcClient::new(&args.rpc_url);
-    let merkle_tree_root_hash: [u8; 32] = {
-        let tx = client
+    let (config, config_type, config_type_hash, config_block) = {
+        let response = client
             .get_transaction(
-                H256::from_str(&args.merkle_tree_root_cell_tx[2..])
-                    .with_context(|| anyhow!("Failed to parse public key index cell tx"))?,
+                H256::from_str(&args.config_cell_tx[2..])
+                    .with_context(|| anyhow!("Failed to parse config cell tx"))?,
             )
-            .with_context(|| anyhow!("Unable to get public index cell tx"))?
-            .ok_or_else(|| anyhow!("Invalid public key index cell tx"))?;
-        let data = tx
+            .with_context(|| anyhow!("Unable to get config cell tx"))?
+            .ok_or_else(|| anyhow!("Invalid config cell tx"))?;
+        let config_block = response
+            .tx_status
+            .block_number
+            .zip(response.tx_status.tx_index)
+            .map(|(block, index)| (block.value(), index.value()))
+            .ok_or_else(|| anyhow!("Config cell tx is not committed"))?;
+        let tx = response
             .transaction
             .ok_or_else(|| anyhow!("Transaction body not found"))?
             .get_value()?
-            .inner
-            .outputs_data[0]
-            .as_bytes()
-            .to_vec();
-        data[0..32].try_into().unwrap()
-    };
-    let candidates = {
-        let tx = client
-            .get_transaction(
-                H256::from_str(&args.candidate_cell_tx[2..])
-                    .with_context(|| anyhow!("Failed to parse candidate cell tx"))?,
-            )
-            .with_context(|| anyhow!("Unable to get public index cell tx"))?
-            .ok_or_else(|| anyhow!("Invalid public key index cell tx"))?;
-        parse_candidate_cell(
-            tx.transaction
-                .ok_or_else(|| anyhow!("Transaction body not found"))?
-                .get_value()?
-                .inner
-                .outputs_data[0]
-                .as_bytes(),
-        )?
+            .inner;
+        // The config cell is the one typed by the built-in type id script
+        let (index, type_script) = tx
+            .outputs
+            .iter()
+            .enumerate()
+            .find_map(|(index, output)| {
+                output
+                    .type_
+                    .clone()
+                    .filter(|script| script.code_hash == TYPE_ID_CODE_HASH)
+                    .map(|script| (index, script))
+            })
+            .ok_or_else(|| anyhow!("Config cell not found in tx"))?;
+        let type_script = Script::from(type_script);
+        let config_type_hash: [u8; 32] = type_script.calc_script_hash().unpack();
+        (
+            VoteConfig::decode(tx.outputs_data[index].as_bytes())
+                .with_context(|| anyhow!("Failed to decode config cell"))?,
+            type_script,
+            config_type_hash,
+            config_block,
+        )
     };
+    // The contract refuses votes of RSA elections, whose key images neither stop double votes
+    // nor hide the voter, so there is nothing to count
+    if let SignatureScheme::Rsa(_) = config.signature_scheme {
+        bail!("RSA elections are not supported");
+    }
+    let (voting_start, voting_end) = (config.voting_start, config.voting_end);
+    check_config_unchanged(&client, &config_type, config_block, voting_end)
+        .with_context(|| anyhow!("Votes of a replaced config are not counted"))?;
+    let candidates = config
+        .candidates
+        .iter()
+        .map(|candidate| (candidate.id, candidate.description.clone()))
+        .collect::<HashMap<_, _>>();
 
-    log::debug!("merkle_tree_root_hash= {:?}", merkle_tree_root_hash);
+    // Vote cells of this election carry the type hash of the config cell in their args
+    log::info!("election id = {}", hex_string(&config_type_hash));
+    log::debug!("merkle_tree_root_hash= {:?}", config.merkle_root);
+    log::info!("voting period = [{}, {})", voting_start, voting_end);
     log::debug!("candidates = {:?}", candidates);
+    let script_hash_bytes = H256::from_str(&args.signature_verify_type_script_hash[2..])
+        .with_context(|| anyhow!("Failed to parse signature verify type script hash"))?;
+
+    // The contract only knows the merkle root, so whether the administrator picked who shares a
+    // ring is checked here, against the keys of the registry cells. Votes over rings chosen by
+    // the administrator are not counted
+    let registry_cells = public_key_registry_cells(&client, &script_hash_bytes, &config_type_hash)?;
+    let (first_config, first_config_block) = first_config(&client, &config_type)?;
+    let seed = ring_seed(&client, first_config_block)?;
+    audit_rings(&LsagScheme, &config, &first_config, &seed, &registry_cells)
+        .with_context(|| anyhow!("Rings failed the audit, votes are not counted"))?;
+    log::info!("rings are assigned by the shuffle of the election");
+
     type VoteCounter = HashMap<[u8; 4], usize>;
     let result = {
-        let script_hash_bytes = H256::from_str(&args.signature_verify_type_script_hash[2..])
-            .with_context(|| anyhow!("Failed to parse signature verify type script hash"))?;
         let tx_validator = VoteValidator {
-            candidate: candidates.clone().freeze(),
-            merkle_tree_root_cell_tx: (
-                H256::from_str(&args.merkle_tree_root_cell_tx[2..]).unwrap(),
-                0,
-            ),
+            config: config.clone(),
+            config_type_hash,
+            contract_code_hash: script_hash_bytes.0,
         };
         let mut last_cursor: Option<JsonBytes> = None;
         let batch_size = 500;
         let mut used_image = HashSet::<Vec<u8>>::new();
 
         let mut counter = VoteCounter::new();
+        let (mut accepted, mut rejected) = (0usize, 0usize);
 
         loop {
             log::info!("Start a batch..");
+            let mut query = CellQueryOptions::new(
+                Script::new_builder()
+                    .code_hash(Byte32::from_slice(script_hash_bytes.as_bytes())?)
+                    .args(vote_script_args(&config_type_hash).pack())
+                    .hash_type(Byte::new(ScriptHashType::Data1 as u8))
+                    .build(),
+                PrimaryScriptType::Type,
+            );
+            // Key image registry cells share the code hash, but have different args
+            query.script_search_mode = Some(SearchMode::Exact);
+            // The contract only ensures that votes are not too early, late votes are ignored here
+            query.block_range = Some(ValueRangeOption::new(voting_start, voting_end));
             let current_batch = client
-                .get_transactions(
-                    CellQueryOptions::new(
-                        Script::new_builder()
-                            .code_hash(Byte32::from_slice(script_hash_bytes.as_bytes())?)
-                            .args(Default::default())
-                            .hash_type(Byte::new(ScriptHashType::Data1 as u8))
-                            .build(),
-                        PrimaryScriptType::Type,
-                    )
-                    .into(),
-                    Order::Asc,
-                    batch_size.into(),
-                    last_cursor,
-                )
+                .get_transactions(query.into(), Order::Asc, batch_size.into(), last_cursor)
                 .with_context(|| anyhow!("Failed to get transaction batch"))?;
             log::info!("Got {} records", current_batch.objects.len());
 
-            let initial_verified = current_batch
+            // Votes are fully verified in parallel, so a wrong contract code hash can't sneak
+            // bad votes in
+            let verified = current_batch
                 .objects
                 .into_par_iter()
-                .map(|item| -> anyhow::Result<Option<VoteTarget>> {
+                .map(|item| -> anyhow::Result<Option<VerifiedVote>> {
+                    // A transaction may carry many votes, each of them is a record here
+                    let index = match &item {
+                        Tx::Ungrouped(TxWithCell {
+                            io_type: CellType::Output,
+                            io_index,
+                            ..
+                        }) => io_index.value() as usize,
+                        _ => return Ok(None),
+                    };
+                    let tx_hash = item.tx_hash();
                     let tx = client
-                        .get_transaction(item.tx_hash())
+                        .get_transaction(tx_hash.clone())
                         .with_context(|| anyhow!("Failed to get transaction"))?
                         .ok_or_else(|| anyhow!("Transaction is empty!"))?
                         .transaction
@@ -190,40 +417,52 @@ fn main() -> anyhow::Result<()> {
                         .get_value()?
                         .inner;
 
-                    if let Err(e) = tx_validator
-                        .validate_tx(&tx)
-                        .with_context(|| anyhow!("Failed to verify tx"))
-                    {
-                        log::debug!("Bad tx encountered: {:?}", e);
-                        return Ok(None);
-                    }
-                    let vote_cell = tx
-                        .outputs_data
-                        .get(0)
-                        .ok_or_else(|| anyhow!("Missing output data 0"))?
-                        .as_bytes();
-                    Ok(Some(VoteTarget {
-                        candidate_id: [vote_cell[0], vote_cell[1], vote_cell[2], vote_cell[3]],
-                        image: vote_cell[4..4 + 256].to_vec(),
+                    Ok(Some(VerifiedVote {
+                        tx_hash,
+                        index,
+                        result: tx_validator.validate_tx(&client, &tx, index),
                     }))
                 })
                 .collect::<Vec<_>>();
-            if initial_verified.is_empty() {
+            if verified.is_empty() {
                 break;
             }
             last_cursor = Some(current_batch.last_cursor);
-            log::debug!("Initial verified: {:?}", initial_verified);
-            for item in initial_verified.into_iter() {
-                if let Some(item) = item? {
-                    if used_image.contains(&item.image) {
-                        log::warn!("Duplicated image: {}", hex_string(&item.image));
-                        continue;
+            for item in verified.into_iter() {
+                let Some(VerifiedVote {
+                    tx_hash,
+                    index,
+                    result,
+                }) = item?
+                else {
+                    continue;
+                };
+                let outcome = result.and_then(|target| {
+                    if used_image.contains(&target.image) {
+                        bail!("Duplicated image: {}", hex_string(&target.image));
+                    }
+                    Ok(target)
+                });
+                match outcome {
+                    Ok(target) => {
+                        log::info!(
+                            "Vote {:#x}:{} accepted, candidate {:?}",
+                            tx_hash,
+                            index,
+                            target.candidate_id
+                        );
+                        used_image.insert(target.image);
+                        *counter.entry(target.candidate_id).or_insert(0) += 1;
+                        accepted += 1;
+                    }
+                    Err(e) => {
+                        log::warn!("Vote {:#x}:{} rejected: {:#}", tx_hash, index, e);
+                        rejected += 1;
                     }
-                    used_image.insert(item.image);
-                    *counter.entry(item.candidate_id).or_insert(0) += 1;
                 }
             }
         }
+        log::info!("{} votes accepted, {} rejected", accepted, rejected);
         counter
     };
     log::debug!("vote result = {:?}", result);
@@ -0,0 +1,239 @@
+//! Exit codes of the contract
+//!
+//! Codes are stable. A code keeps its meaning once released, and codes of removed errors are
+//! never reused: 53 and 54 were cell formats of an older design and 58 a merkle root of another
+//! size, which the schema rules out, none of them were ever returned. 74, 80 and 85 are checks of
+//! RSA votes, which are no longer returned since configs of RSA elections are refused.
+use core::fmt;
+
+/// Why the contract rejected a transaction, `code` is the exit code of the script
+#[repr(i8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VoteError {
+    /// A syscall read past the last cell, witness or header of a source
+    IndexOutOfBound = 1,
+    /// A syscall read a field which the cell doesn't have
+    ItemMissing = 2,
+    /// A syscall read into a buffer shorter than the data
+    LengthNotEnough = 3,
+    /// A syscall read data which isn't of the expected molecule type
+    Encoding = 4,
+    /// The ring signature doesn't verify
+    BadSignature = 51,
+    /// The candidate of the vote cell isn't a candidate of the election
+    BadCandidateId = 52,
+    /// The witness of a vote cell has no `output_type`
+    MissingVoteWitness = 55,
+    /// `output_type` of the witness isn't a `VoteWitness`
+    BadVoteWitness = 56,
+    /// The ring of the signature isn't the leaf at the index of the witness in the merkle tree
+    BadMerkleProof = 57,
+    /// A syscall failed in a way ckb-std doesn't know
+    Unknown = 59,
+    /// The key image registry of the election isn't consumed and recreated once
+    MissingKeyImageRegistry = 60,
+    /// The key image registry cell is malformed, or isn't kept spendable by anyone
+    BadKeyImageRegistry = 61,
+    /// The proof of the key image registry update is missing or doesn't verify
+    BadKeyImageProof = 62,
+    /// A key image was already in the registry, or is used twice in the transaction
+    KeyImageAlreadyUsed = 63,
+    /// A new key image registry isn't typed by the type id of its creation
+    InvalidTypeId = 64,
+    /// The voting period of the config ends before it starts
+    BadVotingPeriod = 65,
+    /// The transaction can't prove to be committed after the voting period starts
+    VotingNotStarted = 66,
+    /// The transaction is committed after the voting period ends
+    VotingEnded = 67,
+    /// Script args are not a role byte followed by a 32 byte hash
+    BadScriptArgs = 68,
+    /// No cell dep has the type hash of the config in the args of the vote
+    MissingVoteConfig = 69,
+    /// The config cell isn't a `VoteConfig`, or isn't owned by its admin lock
+    BadVoteConfig = 70,
+    UnsupportedVoteConfigVersion = 71,
+    /// The ring is larger than the group size of the election
+    RingTooLarge = 72,
+    /// The vote cell isn't a `VoteCell`
+    BadVoteCell = 73,
+    /// RSA keys of the config are of a size the contract doesn't verify
+    UnsupportedKeySize = 74,
+    /// The signature scheme of the config is unknown, or RSA, whose key images neither stop
+    /// double votes nor hide the voter
+    UnsupportedSignatureScheme = 75,
+    /// The witness of a vote cell isn't a `WitnessArgs`
+    BadWitnessArgs = 76,
+    /// The signature of the witness is of another scheme than the election
+    SignatureSchemeMismatch = 77,
+    /// Items of the signature, such as `r`, `n` and `e`, are not all of the same count
+    RingSizeMismatch = 78,
+    /// The signature has no keys
+    EmptyRing = 79,
+    /// An RSA integer isn't of the key size of the election
+    BadIntegerSize = 80,
+    /// The key image of the vote cell isn't of the size of the scheme
+    BadImageSize = 81,
+    /// The merkle proof isn't a whole number of hashes
+    BadMerkleProofLength = 82,
+    /// An integer or scalar isn't reduced by its modulus
+    NonCanonicalInteger = 83,
+    /// A key image or public key of LSAG isn't a point of ristretto255
+    BadPoint = 84,
+    /// An RSA modulus of the ring is even
+    BadModulus = 85,
+    /// The ring isn't of the size of the ring at its leaf index, see [`crate::ring_size`]
+    UnexpectedRingSize = 86,
+    /// Public key registry cells are created or destroyed without an input of the admin lock of
+    /// the election
+    UnauthorizedPublicKeyRegistry = 87,
+    /// A public key registry cell isn't a `PublicKeyRegistryCell` of keys of the scheme of the
+    /// election
+    BadPublicKeyRegistry = 88,
+    /// The ring at the leaf index of the witness isn't in the registry cell it references
+    RingNotInRegistry = 89,
+    /// The witness references a registry cell for the keys of the ring, but the signature has
+    /// keys as well
+    RingKeysInWitness = 90,
+    /// The type script of the vote has another code hash or hash type than the key image registry
+    /// of its election, whose type script only inserts images of votes of its own
+    KeyImageRegistryScriptMismatch = 91,
+}
+
+impl VoteError {
+    /// Every error, in the order of codes
+    pub const ALL: [VoteError; 42] = [
+        VoteError::IndexOutOfBound,
+        VoteError::ItemMissing,
+        VoteError::LengthNotEnough,
+        VoteError::Encoding,
+        VoteError::BadSignature,
+        VoteError::BadCandidateId,
+        VoteError::MissingVoteWitness,
+        VoteError::BadVoteWitness,
+        VoteError::BadMerkleProof,
+        VoteError::Unknown,
+        VoteError::MissingKeyImageRegistry,
+        VoteError::BadKeyImageRegistry,
+        VoteError::BadKeyImageProof,
+        VoteError::KeyImageAlreadyUsed,
+        VoteError::InvalidTypeId,
+        VoteError::BadVotingPeriod,
+        VoteError::VotingNotStarted,
+        VoteError::VotingEnded,
+        VoteError::BadScriptArgs,
+        VoteError::MissingVoteConfig,
+        VoteError::BadVoteConfig,
+        VoteError::UnsupportedVoteConfigVersion,
+        VoteError::RingTooLarge,
+        VoteError::BadVoteCell,
+        VoteError::UnsupportedKeySize,
+        VoteError::UnsupportedSignatureScheme,
+        VoteError::BadWitnessArgs,
+        VoteError::SignatureSchemeMismatch,
+        VoteError::RingSizeMismatch,
+        VoteError::EmptyRing,
+        VoteError::BadIntegerSize,
+        VoteError::BadImageSize,
+        VoteError::BadMerkleProofLength,
+        VoteError::NonCanonicalInteger,
+        VoteError::BadPoint,
+        VoteError::BadModulus,
+        VoteError::UnexpectedRingSize,
+        VoteError::UnauthorizedPublicKeyRegistry,
+        VoteError::BadPublicKeyRegistry,
+        VoteError::RingNotInRegistry,
+        VoteError::RingKeysInWitness,
+        VoteError::KeyImageRegistryScriptMismatch,
+    ];
+
+    /// Exit code of the contract
+    pub const fn code(self) -> i8 {
+        self as i8
+    }
+
+    pub fn from_code(code: i8) -> Option<Self> {
+        Self::ALL.into_iter().find(|error| error.code() == code)
+    }
+
+    /// Human readable reason, for tools showing why a vote was rejected
+    pub const fn reason(self) -> &'static str {
+        match self {
+            VoteError::IndexOutOfBound => "a cell, witness or header is missing",
+            VoteError::ItemMissing => "a cell field is missing",
+            VoteError::LengthNotEnough => "data is longer than expected",
+            VoteError::Encoding => "data is malformed",
+            VoteError::BadSignature => "the ring signature doesn't verify",
+            VoteError::BadCandidateId => "unknown candidate",
+            VoteError::MissingVoteWitness => "the vote has no witness in output_type",
+            VoteError::BadVoteWitness => "the vote witness is malformed",
+            VoteError::BadMerkleProof => "the ring is not in the merkle tree",
+            VoteError::Unknown => "unknown syscall error",
+            VoteError::MissingKeyImageRegistry => "the key image registry isn't updated",
+            VoteError::BadKeyImageRegistry => "the key image registry cell is malformed",
+            VoteError::BadKeyImageProof => "the key image registry update doesn't verify",
+            VoteError::KeyImageAlreadyUsed => "the key has already voted",
+            VoteError::InvalidTypeId => "the key image registry has a bad type id",
+            VoteError::BadVotingPeriod => "the voting period is empty",
+            VoteError::VotingNotStarted => "voting hasn't started",
+            VoteError::VotingEnded => "voting has ended",
+            VoteError::BadScriptArgs => "script args are malformed",
+            VoteError::MissingVoteConfig => "the vote config cell is not in cell deps",
+            VoteError::BadVoteConfig => "the vote config cell is malformed",
+            VoteError::UnsupportedVoteConfigVersion => "unsupported vote config version",
+            VoteError::RingTooLarge => "the ring is larger than the group size",
+            VoteError::BadVoteCell => "the vote cell is malformed",
+            VoteError::UnsupportedKeySize => "unsupported RSA key size",
+            VoteError::UnsupportedSignatureScheme => "unsupported signature scheme",
+            VoteError::BadWitnessArgs => "the witness is not a WitnessArgs",
+            VoteError::SignatureSchemeMismatch => "the signature is of another scheme",
+            VoteError::RingSizeMismatch => "items of the signature differ in count",
+            VoteError::EmptyRing => "the ring is empty",
+            VoteError::BadIntegerSize => "an RSA integer is not of the key size",
+            VoteError::BadImageSize => "the key image is not of the size of the scheme",
+            VoteError::BadMerkleProofLength => "the merkle proof is not a whole number of hashes",
+            VoteError::NonCanonicalInteger => "an integer is not reduced by its modulus",
+            VoteError::BadPoint => "a key image or public key is not a curve point",
+            VoteError::BadModulus => "an RSA modulus is even",
+            VoteError::UnexpectedRingSize => "the ring is not of the size of its leaf",
+            VoteError::UnauthorizedPublicKeyRegistry => {
+                "the public key registry isn't changed by the administrator"
+            }
+            VoteError::BadPublicKeyRegistry => "the public key registry cell is malformed",
+            VoteError::RingNotInRegistry => "the ring is not in the public key registry cell",
+            VoteError::RingKeysInWitness => "the ring has keys in both the witness and a cell dep",
+            VoteError::KeyImageRegistryScriptMismatch => {
+                "the vote script isn't the one of the key image registry"
+            }
+        }
+    }
+}
+
+impl fmt::Display for VoteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (error code {})", self.reason(), self.code())
+    }
+}
+
+impl core::error::Error for VoteError {}
+
+impl From<VoteError> for i8 {
+    fn from(error: VoteError) -> Self {
+        error.code()
+    }
+}
+
+#[cfg(feature = "ckb-std")]
+impl From<ckb_std::error::SysError> for VoteError {
+    fn from(error: ckb_std::error::SysError) -> Self {
+        use ckb_std::error::SysError;
+        match error {
+            SysError::IndexOutOfBound => VoteError::IndexOutOfBound,
+            SysError::ItemMissing => VoteError::ItemMissing,
+            SysError::LengthNotEnough(_) => VoteError::LengthNotEnough,
+            SysError::Encoding => VoteError::Encoding,
+            SysError::Unknown(_) => VoteError::Unknown,
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! Types shared by the contract and off-chain tools, usable in `no_std`
+//!
+//! `schema` is generated from `schemas/vote.mol`, conversions for its primitive types are here.
+#![no_std]
+
+extern crate alloc;
+
+pub use molecule;
+
+mod error;
+#[allow(clippy::all)]
+pub mod schema;
+
+pub use error::VoteError;
+
+use molecule::{bytes::Bytes, prelude::*};
+use schema::*;
+
+/// `signature_scheme` of `VoteConfig`, same as the item id of `RingSignature`
+pub const SIGNATURE_SCHEME_RSA: u8 = 0;
+pub const SIGNATURE_SCHEME_LSAG: u8 = 1;
+
+/// Domain of hashing the election id and an RSA modulus into the base of key images, which is
+/// also the hash each ring member multiplies the challenge by
+pub const RSA_IMAGE_BASE_DOMAIN: &[u8] = b"ckb-vote rsa image base";
+/// Domain of hashing the election id and a public key onto ristretto255, for key images of LSAG
+pub const LSAG_HASH_TO_POINT_DOMAIN: &[u8] = b"ckb-vote lsag hash to point";
+/// Domain of challenges of LSAG, followed by the ring and the message
+pub const LSAG_CHALLENGE_DOMAIN: &[u8] = b"ckb-vote lsag challenge";
+/// Domain of the randomness of the shuffle assigning keys to rings, followed by the seed and a
+/// counter. Only tools use it, the contract never sees all keys
+pub const RING_ASSIGNMENT_DOMAIN: &[u8] = b"ckb-vote ring assignment";
+/// Blocks from the one creating the config cell of an election to the one whose hash seeds the
+/// shuffle assigning keys to rings
+pub const RING_SEED_DELAY: u64 = 10;
+/// Domain of the message signed by a vote, followed by [`BALLOT_VERSION`]
+pub const BALLOT_DOMAIN: &[u8] = b"ckb-vote ballot";
+/// Version of the layout of the message signed by a vote, bumped when the layout changes
+pub const BALLOT_VERSION: u8 = 1;
+/// Size of [`ballot_message`]
+pub const BALLOT_MESSAGE_SIZE: usize = BALLOT_DOMAIN.len() + 1 + 32 * 3 + 4;
+
+/// Message signed by the ring signature of a vote: the domain and version, then the type hash of
+/// the config cell, the network of `VoteConfig`, the code hash of the type script of vote cells
+/// and the candidate id. A ballot therefore can't be replayed in another election, on another
+/// chain or with another deployment of the contract
+pub fn ballot_message(
+    election_id: &[u8; 32],
+    network: &[u8; 32],
+    contract_code_hash: &[u8; 32],
+    candidate_id: &[u8; 4],
+) -> [u8; BALLOT_MESSAGE_SIZE] {
+    let mut message = [0; BALLOT_MESSAGE_SIZE];
+    let parts: [&[u8]; 6] = [
+        BALLOT_DOMAIN,
+        &[BALLOT_VERSION],
+        election_id,
+        network,
+        contract_code_hash,
+        candidate_id,
+    ];
+    let mut offset = 0;
+    for part in parts {
+        message[offset..offset + part.len()].copy_from_slice(part);
+        offset += part.len();
+    }
+    message
+}
+
+/// Count of rings, which are the leaves of the merkle tree, of `user_count` keys split into rings
+/// of `group_size`. `group_size` must not be 0
+pub fn leaf_count(user_count: usize, group_size: usize) -> usize {
+    user_count.div_ceil(group_size)
+}
+
+/// Size of the ring at `leaf_index` of the merkle tree. Keys are shuffled by a public seed, then
+/// split in the shuffled order into rings of `group_size` keys, the last ring has the rest, and
+/// there is no ring after it
+pub fn ring_size(user_count: usize, group_size: usize, leaf_index: usize) -> usize {
+    leaf_index
+        .checked_mul(group_size)
+        .map_or(0, |start| user_count.saturating_sub(start).min(group_size))
+}
+
+macro_rules! impl_array_conversion {
+    ($entity:ident, $reader:ident, $size:literal) => {
+        impl From<[u8; $size]> for $entity {
+            fn from(value: [u8; $size]) -> Self {
+                Self::new_unchecked(Bytes::from(value.to_vec()))
+            }
+        }
+        impl From<$reader<'_>> for [u8; $size] {
+            fn from(value: $reader<'_>) -> Self {
+                value.raw_data().try_into().unwrap()
+            }
+        }
+    };
+}
+
+impl_array_conversion!(Byte4, Byte4Reader, 4);
+impl_array_conversion!(Byte32, Byte32Reader, 32);
+impl_array_conversion!(Byte100, Byte100Reader, 100);
+
+macro_rules! impl_integer_conversion {
+    ($entity:ident, $reader:ident, $type:ty) => {
+        impl From<$type> for $entity {
+            fn from(value: $type) -> Self {
+                Self::new_unchecked(Bytes::from(value.to_le_bytes().to_vec()))
+            }
+        }
+        impl From<$reader<'_>> for $type {
+            fn from(value: $reader<'_>) -> Self {
+                <$type>::from_le_bytes(value.raw_data().try_into().unwrap())
+            }
+        }
+    };
+}
+
+impl_integer_conversion!(Uint32, Uint32Reader, u32);
+impl_integer_conversion!(Uint64, Uint64Reader, u64);
+
+impl From<&[u8]> for schema::Bytes {
+    fn from(value: &[u8]) -> Self {
+        Self::new_builder()
+            .set(value.iter().copied().map(Byte::new).collect())
+            .build()
+    }
+}